@@ -3,7 +3,7 @@
 ///! Test evaluating expressions
 extern crate test;
 
-use rhai::{Array, Engine, Map, INT};
+use rhai::{Array, Engine, Map, OptimizationLevel, Scope, INT};
 use test::Bencher;
 
 #[bench]
@@ -38,3 +38,31 @@ fn bench_engine_register_fn(bench: &mut Bencher) {
         engine.register_fn("hello", hello);
     });
 }
+
+#[bench]
+fn bench_engine_call_fn_str_param(bench: &mut Bencher) {
+    let mut engine = Engine::new();
+    engine.set_optimization_level(OptimizationLevel::None);
+    engine.register_fn("measure", |s: &str| s.len() as INT);
+
+    let mut scope = Scope::new();
+    scope.push("text", "x".repeat(10_000));
+
+    let ast = engine.compile_expression("measure(text)").unwrap();
+
+    bench.iter(|| engine.eval_ast_with_scope::<INT>(&mut scope, &ast).unwrap());
+}
+
+#[bench]
+fn bench_engine_call_fn_string_param(bench: &mut Bencher) {
+    let mut engine = Engine::new();
+    engine.set_optimization_level(OptimizationLevel::None);
+    engine.register_fn("measure", |s: String| s.len() as INT);
+
+    let mut scope = Scope::new();
+    scope.push("text", "x".repeat(10_000));
+
+    let ast = engine.compile_expression("measure(text)").unwrap();
+
+    bench.iter(|| engine.eval_ast_with_scope::<INT>(&mut scope, &ast).unwrap());
+}
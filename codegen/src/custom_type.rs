@@ -0,0 +1,122 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, spanned::Spanned, Data, DeriveInput, Fields};
+
+use crate::attrs::{parse_attr_items, AttrItem};
+
+/// Name of the helper attribute for `#[derive(CustomType)]`.
+const ATTR_NAME: &str = "rhai";
+
+/// Generate an implementation of `rhai::CustomType` for a struct with named fields.
+pub fn derive_custom_type_impl(input: DeriveInput) -> TokenStream {
+    generate(&input).unwrap_or_else(|err| err.to_compile_error())
+}
+
+fn generate(input: &DeriveInput) -> syn::Result<TokenStream> {
+    if let Some(attr) = input.attrs.iter().find(|a| a.path.is_ident(ATTR_NAME)) {
+        return Err(syn::Error::new(
+            attr.span(),
+            format!("'{ATTR_NAME}' attributes are only allowed on fields"),
+        ));
+    }
+
+    let fields: Vec<_> = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => fields.named.iter().collect(),
+            Fields::Unit => Vec::new(),
+            Fields::Unnamed(ref fields) => {
+                return Err(syn::Error::new(
+                    fields.span(),
+                    "`CustomType` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        Data::Enum(ref data) => {
+            return Err(syn::Error::new(
+                data.enum_token.span(),
+                "`CustomType` can only be derived for structs",
+            ))
+        }
+        Data::Union(ref data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "`CustomType` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut accessors = Vec::new();
+
+    for field in fields {
+        let (skip, readonly) = parse_field_attrs(&field.attrs)?;
+
+        // Only public fields are exposed
+        if skip || !matches!(field.vis, syn::Visibility::Public(..)) {
+            continue;
+        }
+
+        let ident = field.ident.as_ref().expect("named field");
+        let prop = ident.unraw().to_string();
+        let ty = &field.ty;
+
+        accessors.push(if readonly {
+            quote! {
+                builder.with_get(#prop, |obj: &mut Self| -> #ty { obj.#ident.clone() });
+            }
+        } else {
+            quote! {
+                builder.with_get_set(
+                    #prop,
+                    |obj: &mut Self| -> #ty { obj.#ident.clone() },
+                    |obj: &mut Self, value: #ty| { obj.#ident = value; },
+                );
+            }
+        });
+    }
+
+    let name = &input.ident;
+    let display_name = name.to_string();
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[allow(deprecated)]
+        impl #impl_generics rhai::CustomType for #name #type_generics #where_clause {
+            fn build(mut builder: rhai::TypeBuilder<Self>) {
+                builder.with_name(#display_name);
+                #(#accessors)*
+            }
+        }
+    })
+}
+
+/// Parse the `#[rhai(...)]` attributes on a field, returning the `skip` and `readonly` flags.
+fn parse_field_attrs(attrs: &[syn::Attribute]) -> syn::Result<(bool, bool)> {
+    let mut skip = None;
+    let mut readonly = None;
+
+    for attr in attrs.iter().filter(|a| a.path.is_ident(ATTR_NAME)) {
+        for AttrItem { key, value, span } in attr.parse_args_with(parse_attr_items)?.items {
+            match (key.to_string().as_ref(), value) {
+                ("skip", Some(s)) | ("readonly", Some(s)) => {
+                    return Err(syn::Error::new(s.span(), "extraneous value"))
+                }
+                ("skip", None) => skip = Some(span),
+                ("readonly", None) => readonly = Some(span),
+                (attr, ..) => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("unknown attribute '{attr}'"),
+                    ))
+                }
+            }
+        }
+    }
+
+    match (skip, readonly) {
+        (Some(..), Some(span)) => Err(syn::Error::new(
+            span,
+            "a skipped field cannot also be 'readonly'",
+        )),
+        _ => Ok((skip.is_some(), readonly.is_some())),
+    }
+}
@@ -91,6 +91,7 @@ use quote::quote;
 use syn::{parse_macro_input, spanned::Spanned};
 
 mod attrs;
+mod custom_type;
 mod function;
 mod module;
 mod register;
@@ -407,3 +408,55 @@ pub fn set_exported_global_fn(args: proc_macro::TokenStream) -> proc_macro::Toke
         Err(e) => e.to_compile_error().into(),
     }
 }
+
+/// Macro to implement the `CustomType` trait for a struct, registering a pretty-print name (the
+/// struct's name) plus a getter and setter for each public field.
+///
+/// Fields that are not public are not exposed. Field attributes:
+///
+/// * `#[rhai(skip)]`: do not expose the field.
+/// * `#[rhai(readonly)]`: register only a getter for the field.
+///
+/// Field types must implement `Clone`.
+///
+/// Not available under `no_object` (property getters and setters are required).
+///
+/// # Usage
+///
+/// ```
+/// # use rhai::{Engine, EvalAltResult, INT};
+/// use rhai::CustomType;
+///
+/// #[derive(Debug, Clone, CustomType)]
+/// pub struct Point {
+///     pub x: INT,
+///     pub y: INT,
+///     #[rhai(readonly)]
+///     pub id: INT,
+///     #[rhai(skip)]
+///     pub cache: INT,
+/// }
+///
+/// # fn main() -> Result<(), Box<EvalAltResult>> {
+/// let mut engine = Engine::new();
+///
+/// engine.build_type::<Point>();
+///
+/// let p = Point { x: 1, y: 2, id: 42, cache: 0 };
+///
+/// let result = engine.call_fn::<INT>(
+///     &mut rhai::Scope::new(),
+///     &engine.compile("fn sum(p) { p.x += 10; p.x + p.y + p.id }")?,
+///     "sum",
+///     (p,),
+/// )?;
+///
+/// assert_eq!(result, 55);
+/// # Ok(())
+/// # }
+/// ```
+#[proc_macro_derive(CustomType, attributes(rhai))]
+pub fn derive_custom_type(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    proc_macro::TokenStream::from(custom_type::derive_custom_type_impl(input))
+}
@@ -16,6 +16,12 @@ use crate::func::register::Mut;
 ///
 /// This API is volatile and may change in the future.
 ///
+/// # Deriving
+///
+/// For plain data structs, `#[derive(CustomType)]` implements this trait by registering the name
+/// of the struct plus a getter and setter for each public field. Fields can be marked with
+/// `#[rhai(skip)]` (not exposed) or `#[rhai(readonly)]` (getter only).
+///
 /// # Example
 ///
 /// ```
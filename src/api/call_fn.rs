@@ -1,16 +1,144 @@
 //! Module that defines the `call_fn` API of [`Engine`].
 #![cfg(not(feature = "no_function"))]
 
+use crate::ast::ScriptFnDef;
 use crate::eval::{Caches, GlobalRuntimeState};
 use crate::types::dynamic::Variant;
 use crate::{
-    reify, Dynamic, Engine, FuncArgs, Position, RhaiResult, RhaiResultOf, Scope, StaticVec, AST,
-    ERR,
+    reify, Dynamic, Engine, FuncArgs, Position, RhaiResult, RhaiResultOf, Scope, Shared, StaticVec,
+    AST, ERR,
 };
 use std::any::{type_name, TypeId};
+use std::fmt;
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 
+/// A call to a script-defined function prepared via [`Engine::prepare_call`].
+///
+/// Not available under `no_function`.
+///
+/// The target function is looked up only once, and the function resolution caches are kept
+/// intact across calls, making this suitable for calling the same function repeatedly (e.g. once
+/// per frame in a game loop).
+pub struct PreparedCall<'a> {
+    /// The [`Engine`] to call the function with.
+    engine: &'a Engine,
+    /// The [`AST`] containing the function.
+    ast: &'a AST,
+    /// The function definition.
+    fn_def: Shared<ScriptFnDef>,
+    /// The [`GlobalRuntimeState`] kept across calls.
+    global: GlobalRuntimeState<'a>,
+    /// The [`Caches`] kept across calls.
+    caches: Caches<'a>,
+}
+
+impl fmt::Debug for PreparedCall<'_> {
+    #[cold]
+    #[inline(never)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreparedCall")
+            .field("name", &self.fn_def.name)
+            .field("num_params", &self.fn_def.params.len())
+            .finish()
+    }
+}
+
+impl<'a> PreparedCall<'a> {
+    /// Name of the function to call.
+    #[inline(always)]
+    #[must_use]
+    pub fn fn_name(&self) -> &str {
+        &self.fn_def.name
+    }
+    /// Number of parameters of the function to call.
+    #[inline(always)]
+    #[must_use]
+    pub fn num_params(&self) -> usize {
+        self.fn_def.params.len()
+    }
+    /// Call the function with multiple [`Dynamic`] arguments, casting the result to a specific type.
+    ///
+    /// # Arguments
+    ///
+    /// All the arguments are _consumed_, meaning that they're replaced by `()`.
+    /// The storage can be reused for the next call by assigning new values to it.
+    #[inline]
+    pub fn call<T: Variant + Clone>(
+        &mut self,
+        scope: &mut Scope,
+        arg_values: &mut [Dynamic],
+    ) -> RhaiResultOf<T> {
+        let result = self.call_raw(scope, arg_values)?;
+
+        // Bail out early if the return type needs no cast
+        if TypeId::of::<T>() == TypeId::of::<Dynamic>() {
+            return Ok(reify!(result => T));
+        }
+        if TypeId::of::<T>() == TypeId::of::<()>() {
+            return Ok(reify!(() => T));
+        }
+
+        // Cast return type
+        let typ = self.engine.map_type_name(result.type_name());
+
+        result.try_cast().ok_or_else(|| {
+            let t = self.engine.map_type_name(type_name::<T>()).into();
+            ERR::ErrorMismatchOutputType(t, typ.into(), Position::NONE).into()
+        })
+    }
+    /// Call the function with multiple [`Dynamic`] arguments.
+    ///
+    /// The [`Scope`] is rewound after the call.
+    ///
+    /// # Arguments
+    ///
+    /// All the arguments are _consumed_, meaning that they're replaced by `()`.
+    /// The storage can be reused for the next call by assigning new values to it.
+    pub fn call_raw(&mut self, scope: &mut Scope, arg_values: &mut [Dynamic]) -> RhaiResult {
+        let lib = &[self.ast.as_ref()];
+        let mut args: StaticVec<_> = arg_values.iter_mut().collect();
+
+        if args.len() != self.fn_def.params.len() {
+            return Err(ERR::ErrorFunctionNotFound(
+                crate::func::gen_fn_call_signature(self.engine, &self.fn_def.name, &args),
+                Position::NONE,
+            )
+            .into());
+        }
+
+        // Check for data race.
+        #[cfg(not(feature = "no_closure"))]
+        crate::func::call::ensure_no_data_race(&self.fn_def.name, &args, false)?;
+
+        // Each call has its own operations budget
+        self.global.num_operations = 0;
+
+        let result = self.engine.call_script_fn(
+            scope,
+            &mut self.global,
+            &mut self.caches,
+            lib,
+            &mut None,
+            &self.fn_def,
+            &mut args,
+            true,
+            Position::NONE,
+            0,
+        )?;
+
+        #[cfg(feature = "debugging")]
+        if self.engine.debugger.is_some() {
+            self.global.debugger.status = crate::eval::DebuggerStatus::Terminate;
+            let node = &crate::ast::Stmt::Noop(Position::NONE);
+            self.engine
+                .run_debugger(scope, &mut self.global, lib, &mut None, node, 0)?;
+        }
+
+        Ok(result)
+    }
+}
+
 impl Engine {
     /// Call a script function defined in an [`AST`] with multiple arguments.
     ///
@@ -21,6 +149,12 @@ impl Engine {
     /// This is usually desired. If not, a specialized [`AST`] can be prepared that contains only
     /// function definitions without any body script via [`AST::clear_statements`].
     ///
+    /// # Private Functions
+    ///
+    /// Functions marked `private` can also be called. The access level only restricts calls to
+    /// functions in _imported_ modules, so the private helper functions of a module script can be
+    /// tested by compiling that script into an [`AST`] and calling them directly.
+    ///
     /// # Example
     ///
     /// ```
@@ -83,6 +217,78 @@ impl Engine {
             ERR::ErrorMismatchOutputType(t, typ.into(), Position::NONE).into()
         })
     }
+    /// Prepare a call to a script function defined in an [`AST`] with a particular number of
+    /// parameters, for repeated calling.
+    ///
+    /// Not available under `no_function`.
+    ///
+    /// Unlike [`call_fn`][Engine::call_fn], the [`AST`] is _not_ evaluated before calling the
+    /// function. Use [`run_ast_with_scope`][Engine::run_ast_with_scope] beforehand if the script
+    /// needs to set up variables or load modules.
+    ///
+    /// An error is returned if no function with the specified name and number of parameters
+    /// exists in the [`AST`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// # #[cfg(not(feature = "no_function"))]
+    /// # {
+    /// use rhai::{Engine, Scope, Dynamic};
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let ast = engine.compile("fn step(x, dt) { x + dt * 2 }")?;
+    ///
+    /// let mut scope = Scope::new();
+    /// let mut call = engine.prepare_call(&ast, "step", 2)?;
+    ///
+    /// // Argument storage is allocated once and reused for every call
+    /// let mut args = [Dynamic::UNIT, Dynamic::UNIT];
+    /// let mut x = 0_i64;
+    ///
+    /// for _ in 0..10 {
+    ///     args[0] = x.into();
+    ///     args[1] = 1_i64.into();
+    ///     x = call.call::<i64>(&mut scope, &mut args)?;
+    /// }
+    ///
+    /// assert_eq!(x, 20);
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn prepare_call<'a>(
+        &'a self,
+        ast: &'a AST,
+        name: impl AsRef<str>,
+        num_params: usize,
+    ) -> RhaiResultOf<PreparedCall<'a>> {
+        let name = name.as_ref();
+
+        let fn_def = ast
+            .shared_lib()
+            .get_script_fn(name, num_params)
+            .cloned()
+            .ok_or_else(|| ERR::ErrorFunctionNotFound(name.into(), Position::NONE))?;
+
+        #[allow(unused_mut)]
+        let mut global = GlobalRuntimeState::new(self);
+
+        #[cfg(not(feature = "no_module"))]
+        {
+            global.embedded_module_resolver = ast.resolver().cloned();
+        }
+
+        Ok(PreparedCall {
+            engine: self,
+            ast,
+            fn_def,
+            global,
+            caches: Caches::new(),
+        })
+    }
     /// Call a script function defined in an [`AST`] with multiple [`Dynamic`] arguments.
     ///
     /// The following options are available:
@@ -93,6 +299,8 @@ impl Engine {
     ///
     /// Not available under `no_function`.
     ///
+    /// Like [`call_fn`][Engine::call_fn], functions marked `private` can also be called.
+    ///
     /// # WARNING - Low Level API
     ///
     /// This function is very low level.
@@ -252,6 +460,9 @@ impl Engine {
 
         let orig_scope_len = scope.len();
 
+        let orig_ast_metadata =
+            std::mem::replace(&mut global.ast_metadata, ast.shared_metadata().cloned());
+
         #[cfg(not(feature = "no_module"))]
         let orig_embedded_module_resolver = std::mem::replace(
             &mut global.embedded_module_resolver,
@@ -293,6 +504,8 @@ impl Engine {
             }
         });
 
+        global.ast_metadata = orig_ast_metadata;
+
         #[cfg(not(feature = "no_module"))]
         {
             global.embedded_module_resolver = orig_embedded_module_resolver;
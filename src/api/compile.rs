@@ -1,7 +1,7 @@
 //! Module that defines the public compilation API of [`Engine`].
 
 use crate::parser::{ParseResult, ParseState};
-use crate::{Engine, OptimizationLevel, Scope, AST};
+use crate::{Engine, ExpressionOptions, OptimizationLevel, Scope, AST};
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 
@@ -203,6 +203,65 @@ impl Engine {
     ) -> ParseResult<AST> {
         self.compile_with_scope_and_optimization_level(scope, scripts, self.optimization_level)
     }
+    /// Compile a list of named sources, each a `(name, code)` pair, as one logical script into an
+    /// [`AST`], which can be used later for evaluation.
+    ///
+    /// Unlike [`compile_scripts_with_scope`][Engine::compile_scripts_with_scope], each source
+    /// starts on a new line, so a source must be a complete sequence of statements.
+    ///
+    /// The [`AST`] carries a [`SourceMap`][crate::SourceMap], available via
+    /// [`AST::source_map`], to map positions in runtime errors back to `(name, position)`.
+    ///
+    /// # Errors
+    ///
+    /// A [`ParseError`][crate::ParseError] only carries the position within the combined script,
+    /// not the name of the source. To find the source, build the same map with
+    /// [`SourceMap::new`][crate::SourceMap::new] and resolve the position, as in the example below.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// # #[cfg(not(feature = "no_position"))]
+    /// # {
+    /// use rhai::{Engine, Position, SourceMap};
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let sources = [("header", "let x = 40;"), ("body", "x + 2")];
+    ///
+    /// let ast = engine.compile_sources(&sources)?;
+    /// assert_eq!(engine.eval_ast::<i64>(&ast)?, 42);
+    ///
+    /// let sources = [("header", "let x = 40;"), ("body", "let y = ;")];
+    ///
+    /// let err = engine.compile_sources(&sources).expect_err("should error");
+    /// let (name, pos) = SourceMap::new(&sources).resolve(err.position()).unwrap();
+    ///
+    /// assert_eq!(name, "body");
+    /// assert_eq!(pos.line(), Some(1));
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compile_sources(&self, sources: &[(&str, &str)]) -> ParseResult<AST> {
+        let mut scripts = crate::StaticVec::<&str>::new();
+
+        for (i, &(.., code)) in sources.iter().enumerate() {
+            if i > 0 {
+                scripts.push("\n");
+            }
+            scripts.push(code);
+        }
+
+        let mut ast = self.compile_with_scope_and_optimization_level(
+            &Scope::new(),
+            scripts,
+            self.optimization_level,
+        )?;
+        ast.set_source_map(crate::SourceMap::new(sources));
+        Ok(ast)
+    }
     /// Join a list of strings and compile into an [`AST`] using own scope at a specific optimization level.
     ///
     /// ## Constants Propagation
@@ -221,8 +280,11 @@ impl Engine {
             scripts.as_ref(),
             self.token_mapper.as_ref().map(<_>::as_ref),
         );
-        let mut state = ParseState::new(self, scope, Default::default(), tokenizer_control);
-        let mut _ast = self.parse(&mut stream.peekable(), &mut state, optimization_level)?;
+        let interned_strings = self.copy_interned_strings();
+        let mut state = ParseState::new(self, scope, interned_strings, tokenizer_control);
+        let result = self.parse(&mut stream.peekable(), &mut state, optimization_level);
+        self.restore_interned_strings(state.take_interned_strings());
+        let mut _ast = result?;
         #[cfg(feature = "metadata")]
         _ast.set_doc(state.tokenizer_control.borrow().global_comments.join("\n"));
         Ok(_ast)
@@ -283,18 +345,69 @@ impl Engine {
     /// # Ok(())
     /// # }
     /// ```
-    #[inline]
+    #[inline(always)]
     pub fn compile_expression_with_scope(
         &self,
         scope: &Scope,
         script: impl AsRef<str>,
+    ) -> ParseResult<AST> {
+        self.compile_expression_with_options(scope, script, ExpressionOptions::empty())
+    }
+    /// Compile a string containing an expression into an [`AST`] using own scope,
+    /// allowing the statements in [`ExpressionOptions`] before the final expression.
+    ///
+    /// Statements must be terminated by semicolons, and the script must end with an expression,
+    /// which is the result of the [`AST`].
+    /// Constructs not allowed by the options are rejected just like in
+    /// [`compile_expression_with_scope`][Engine::compile_expression_with_scope].
+    ///
+    /// The options only apply to the top level of the script. The body of a loop allowed by
+    /// [`ExpressionOptions::LOOPS`] is parsed as a normal statements block.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, ExpressionOptions, Scope};
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let ast = engine.compile_expression_with_options(
+    ///     &Scope::new(),
+    ///     "let x = 40; x + 2",
+    ///     ExpressionOptions::VARIABLES,
+    /// )?;
+    ///
+    /// assert_eq!(engine.eval_ast::<i64>(&ast)?, 42);
+    ///
+    /// // Loops are not allowed
+    /// assert!(engine
+    ///     .compile_expression_with_options(
+    ///         &Scope::new(),
+    ///         "let x = 0; while x < 42 { x += 1; } x",
+    ///         ExpressionOptions::VARIABLES,
+    ///     )
+    ///     .is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn compile_expression_with_options(
+        &self,
+        scope: &Scope,
+        script: impl AsRef<str>,
+        options: ExpressionOptions,
     ) -> ParseResult<AST> {
         let scripts = [script];
         let (stream, tokenizer_control) =
             self.lex_raw(&scripts, self.token_mapper.as_ref().map(<_>::as_ref));
 
         let mut peekable = stream.peekable();
-        let mut state = ParseState::new(self, scope, Default::default(), tokenizer_control);
-        self.parse_global_expr(&mut peekable, &mut state, self.optimization_level)
+        let interned_strings = self.copy_interned_strings();
+        let mut state = ParseState::new(self, scope, interned_strings, tokenizer_control);
+        let result =
+            self.parse_global_expr(&mut peekable, &mut state, options, self.optimization_level);
+        self.restore_interned_strings(state.take_interned_strings());
+        result
     }
 }
@@ -69,6 +69,11 @@ impl<'a> From<&'a Expr> for Expression<'a> {
 impl Expression<'_> {
     /// Evaluate this [expression tree][Expression] within an [evaluation context][`EvalContext`].
     ///
+    /// If this expression is a statement block (see [`is_block`][Expression::is_block]), the
+    /// value of its last statement is returned. See
+    /// [`EvalContext::eval_expression_tree`] for how changes to the [`Scope`][crate::Scope] are
+    /// contained.
+    ///
     /// # WARNING - Low Level API
     ///
     /// This function is very low level.  It evaluates an expression from an [`AST`][crate::AST].
@@ -98,6 +103,12 @@ impl Expression<'_> {
         #[allow(deprecated)]
         context.eval_expression_tree_raw(self, rewind_scope)
     }
+    /// Is this expression a statement block, e.g. the input matched by `$block$`?
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_block(&self) -> bool {
+        matches!(self.0, Expr::Stmt(..))
+    }
     /// Get the value of this expression if it is a variable name or a string constant.
     ///
     /// Returns [`None`] also if the constant is not of the specified type.
@@ -1,10 +1,11 @@
 //! Module that defines the public evaluation API of [`Engine`].
 
-use crate::eval::{Caches, GlobalRuntimeState};
+use crate::eval::{Caches, EvalStats, GlobalRuntimeState};
 use crate::parser::ParseState;
 use crate::types::dynamic::Variant;
 use crate::{
-    Dynamic, Engine, OptimizationLevel, Position, RhaiResult, RhaiResultOf, Scope, AST, ERR,
+    Dynamic, Engine, ExpressionOptions, OptimizationLevel, Position, RhaiResult, RhaiResultOf,
+    Scope, AST, ERR,
 };
 use std::any::type_name;
 #[cfg(feature = "no_std")]
@@ -71,6 +72,55 @@ impl Engine {
         )?;
         self.eval_ast_with_scope(scope, &ast)
     }
+    /// Evaluate a string as a script, returning the result value or an error together with
+    /// [statistics][EvalStats] of the evaluation.
+    ///
+    /// Collecting statistics has a small cost, so it is opt-in via this method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// # #[cfg(not(feature = "no_function"))]
+    /// # {
+    /// use rhai::Engine;
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let (result, stats) = engine.eval_with_stats::<i64>(
+    ///     "
+    ///         fn fib(n) { if n < 2 { n } else { fib(n - 1) + fib(n - 2) } }
+    ///         let x = 10;
+    ///         fib(x)
+    ///     ",
+    /// );
+    ///
+    /// assert_eq!(result?, 55);
+    /// assert_eq!(stats.max_call_depth, 10);
+    /// assert!(stats.fn_calls > 100);
+    /// assert_eq!(stats.peak_scope_size, 1);
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn eval_with_stats<T: Variant + Clone>(
+        &self,
+        script: &str,
+    ) -> (RhaiResultOf<T>, EvalStats) {
+        let scope = &mut Scope::new();
+        let global = &mut GlobalRuntimeState::new(self);
+        global.stats = Some(EvalStats::default());
+
+        let result = self
+            .compile_with_scope_and_optimization_level(scope, &[script], self.optimization_level)
+            .map_err(Into::into)
+            .and_then(|ast| self.eval_ast_with_global::<T>(scope, global, &ast));
+
+        let mut stats = global.stats.unwrap_or_default();
+        stats.operations = global.num_operations;
+
+        (result, stats)
+    }
     /// Evaluate a string containing an expression, returning the result value or an error.
     ///
     /// # Example
@@ -122,6 +172,7 @@ impl Engine {
         let ast = self.parse_global_expr(
             &mut stream.peekable(),
             &mut state,
+            ExpressionOptions::empty(),
             #[cfg(not(feature = "no_optimize"))]
             OptimizationLevel::None,
             #[cfg(feature = "no_optimize")]
@@ -183,9 +234,77 @@ impl Engine {
         &self,
         scope: &mut Scope,
         ast: &AST,
+    ) -> RhaiResultOf<T> {
+        self.eval_ast_with_global(scope, &mut GlobalRuntimeState::new(self), ast)
+    }
+    /// Evaluate an [`AST`] with own scope and a set of additional [modules][crate::Module] that
+    /// are available only during this evaluation, returning the result value or an error.
+    ///
+    /// Each module is made available under its name as if it were imported via an `import`
+    /// statement at the start of the script, so its functions and variables can be accessed with
+    /// the `name::` namespace. The [`Engine`] itself is not modified.
+    ///
+    /// Not available under `no_module`.
+    ///
+    /// # Precedence
+    ///
+    /// When a namespace is resolved, these modules take precedence over static modules with the
+    /// same name registered via [`register_static_module`][Engine::register_static_module].
+    /// Modules imported within the script via `import` statements take precedence over these
+    /// modules. If the same name appears more than once in `modules`, the last one wins.
+    ///
+    /// # Strict Variables Mode
+    ///
+    /// These modules are not known when the script is compiled, so under
+    /// [strict variables mode][Engine::set_strict_variables] the script fails to compile when it
+    /// refers to them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Module, Scope, Shared, INT};
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let mut module = Module::new();
+    /// module.set_native_fn("tenant_id", || Ok(42 as INT));
+    /// let module = Shared::new(module);
+    ///
+    /// let ast = engine.compile("tenant::tenant_id() + 1")?;
+    ///
+    /// let result = engine.eval_with_modules::<INT>(&mut Scope::new(), &ast, &[("tenant", module)])?;
+    ///
+    /// assert_eq!(result, 43);
+    ///
+    /// // The module is not available outside of the evaluation
+    /// assert!(engine.eval_ast::<INT>(&ast).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_module"))]
+    pub fn eval_with_modules<T: Variant + Clone>(
+        &self,
+        scope: &mut Scope,
+        ast: &AST,
+        modules: &[(&str, crate::Shared<crate::Module>)],
     ) -> RhaiResultOf<T> {
         let global = &mut GlobalRuntimeState::new(self);
 
+        for (name, module) in modules {
+            global.push_import(*name, module.clone());
+        }
+
+        self.eval_ast_with_global(scope, global, ast)
+    }
+    /// Evaluate an [`AST`] with own scope and [`GlobalRuntimeState`], returning the result value
+    /// or an error.
+    fn eval_ast_with_global<T: Variant + Clone>(
+        &self,
+        scope: &mut Scope,
+        global: &mut GlobalRuntimeState,
+        ast: &AST,
+    ) -> RhaiResultOf<T> {
         let result = self.eval_ast_with_scope_raw(scope, global, ast, 0)?;
 
         #[cfg(feature = "debugging")]
@@ -217,6 +336,7 @@ impl Engine {
     ) -> RhaiResult {
         let mut caches = Caches::new();
         global.source = ast.source_raw().clone();
+        global.ast_metadata = ast.shared_metadata().cloned();
 
         #[cfg(not(feature = "no_module"))]
         let orig_embedded_module_resolver = std::mem::replace(
@@ -1,10 +1,86 @@
 //! Module that defines public event handlers for [`Engine`].
 
 use crate::func::SendSync;
-use crate::{Dynamic, Engine, EvalContext, Position, RhaiResultOf};
+use crate::{Dynamic, Engine, EvalContext, Position, RhaiResult, RhaiResultOf};
+use std::fmt;
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 
+/// Level of a message logged by a script via the `log` function.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
+pub enum LogLevel {
+    /// Very detailed messages.
+    Trace,
+    /// Debugging messages.
+    Debug,
+    /// Informational messages.
+    Info,
+    /// Warnings.
+    Warn,
+    /// Errors.
+    Error,
+}
+
+impl LogLevel {
+    /// Get the [`LogLevel`] of a name (`"trace"`, `"debug"`, `"info"`, `"warn"` or `"error"`),
+    /// ignoring case.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        [
+            Self::Trace,
+            Self::Debug,
+            Self::Info,
+            Self::Warn,
+            Self::Error,
+        ]
+        .iter()
+        .copied()
+        .find(|level| level.as_str().eq_ignore_ascii_case(name))
+    }
+    /// Get the name of the [`LogLevel`].
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Trace => "trace",
+            Self::Debug => "debug",
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Action to take after reporting script evaluation progress via
+/// [`Engine::on_progress_action`].
+///
+/// Not available under `unchecked`.
+#[cfg(not(feature = "unchecked"))]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ProgressAction {
+    /// Continue running the script.
+    Continue,
+    /// Terminate the script with the specified value as the termination token
+    /// (see [`EvalAltResult::ErrorTerminated`][crate::EvalAltResult::ErrorTerminated]).
+    Abort(Dynamic),
+}
+
+#[cfg(not(feature = "unchecked"))]
+impl From<Option<Dynamic>> for ProgressAction {
+    #[inline(always)]
+    fn from(value: Option<Dynamic>) -> Self {
+        value.map_or(Self::Continue, Self::Abort)
+    }
+}
+
 /// Information on a variable definition.
 #[non_exhaustive]
 pub struct VarDefInfo<'a> {
@@ -139,6 +215,121 @@ impl Engine {
         self.def_var_filter = Some(Box::new(callback));
         self
     }
+    /// Provide a callback that will be invoked before each function call, script-defined or
+    /// native Rust, and which may replace the call with a value.
+    ///
+    /// # Callback Function Signature
+    ///
+    /// `Fn(name: &str, args: &[Dynamic]) -> Option<Dynamic>`
+    ///
+    /// where:
+    /// * `name`: name of the function being called.
+    /// * `args`: copies of the arguments of the call.
+    ///
+    /// ## Return value
+    ///
+    /// * `None`: continue with the normal function call.
+    /// * `Some(Dynamic)`: skip the call and use this value as its result.
+    ///
+    /// ## Notes
+    ///
+    /// `name` is the name seen _after_ method-call style resolution, i.e. `x.foo(y)` and `foo(x, y)`
+    /// both present as `foo` with arguments `x` and `y`. For namespace-qualified calls, it is the
+    /// name without the namespace.
+    ///
+    /// The callback runs before the function is resolved, so it can mock out functions that do not
+    /// exist in the [`Engine`].
+    ///
+    /// Operators are intercepted only when they resolve to function calls, which includes custom
+    /// operators and operator overloads. Built-in operators on standard types (e.g. `1 + 2`) are
+    /// evaluated directly and do not trigger the callback. Special functions handled by the
+    /// [`Engine`] itself (e.g. `type_of`, `Fn`, `eval`) are never intercepted.
+    ///
+    /// Property getters/setters and indexers (e.g. `x.prop` or `x[0]`) are not intercepted either.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::Engine;
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// // Mock out a function.
+    /// engine.on_fn_call(|name, args| match name {
+    ///     "http_get" => Some(format!("mocked: {}", args[0]).into()),
+    ///     _ => None,
+    /// });
+    ///
+    /// let result = engine.eval::<String>(r#"http_get("https://example.com")"#)?;
+    ///
+    /// assert_eq!(result, "mocked: https://example.com");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn on_fn_call(
+        &mut self,
+        callback: impl Fn(&str, &[Dynamic]) -> Option<Dynamic> + SendSync + 'static,
+    ) -> &mut Self {
+        self.fn_call_interceptor = Some(Box::new(callback));
+        self
+    }
+    /// Register a callback for handling calls to functions that are not found.
+    ///
+    /// # Callback Function Signature
+    ///
+    /// `Fn(name: &str, args: &mut [&mut Dynamic]) -> Option<RhaiResult>`
+    ///
+    /// where:
+    /// * `name`: name of the function being called.
+    /// * `args`: arguments of the call. For method calls, the first argument is the object
+    ///   itself, which the callback may modify.
+    ///
+    /// ## Return value
+    ///
+    /// * `None`: the function is not handled, raise [`ErrorFunctionNotFound`][crate::EvalAltResult::ErrorFunctionNotFound].
+    /// * `Some(Ok(Dynamic))`: use this value as the result of the call.
+    /// * `Some(Err(error))`: raise this error.
+    ///
+    /// ## Notes
+    ///
+    /// The callback runs only after normal function resolution has failed, so it can serve as a
+    /// dispatcher for dynamically-named functions without registering each one.
+    ///
+    /// Only function names that are valid identifiers are handled; operators, property getters and
+    /// setters, and indexers never reach the callback. Namespace-qualified calls are not handled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, INT};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// // Dispatch all `cmd_*` functions.
+    /// engine.on_missing_fn(|name, args| {
+    ///     name.strip_prefix("cmd_")
+    ///         .map(|cmd| Ok(format!("{cmd} with {} argument(s)", args.len()).into()))
+    /// });
+    ///
+    /// let result = engine.eval::<String>("cmd_foo(1, 2)")?;
+    ///
+    /// assert_eq!(result, "foo with 2 argument(s)");
+    ///
+    /// assert!(engine.eval::<INT>("foo(1, 2)").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn on_missing_fn(
+        &mut self,
+        callback: impl Fn(&str, &mut [&mut Dynamic]) -> Option<RhaiResult> + SendSync + 'static,
+    ) -> &mut Self {
+        self.missing_fn_handler = Some(Box::new(callback));
+        self
+    }
     /// _(internals)_ Register a callback that will be invoked during parsing to remap certain tokens.
     /// Exported under the `internals` feature only.
     ///
@@ -205,6 +396,63 @@ impl Engine {
         self.token_mapper = Some(Box::new(callback));
         self
     }
+    /// Register a callback for parsing number literals.
+    ///
+    /// The callback is consulted with the text of each number literal before the default number
+    /// parser. Returning [`None`] falls back to the default parser.
+    ///
+    /// # Callback Function Signature
+    ///
+    /// `Fn(text: &str) -> Option<Dynamic>`
+    ///
+    /// where:
+    /// * `text`: text of the number literal, with `_` separators removed and a leading `-` if
+    ///   negated
+    ///
+    /// The returned value must be an integer, a floating-point number or a `Decimal`.
+    /// Any other type is a parse error.
+    ///
+    /// ## Number literals
+    ///
+    /// The callback is only consulted on text that is scanned as a number. In particular, it does
+    /// not see the range operator in `1..5` or method calls such as `3.to_string()`.
+    ///
+    /// When a callback is registered, a comma directly between two digits (e.g. `3,14`) is also
+    /// offered to the callback as part of a number literal. If the callback returns [`None`], the
+    /// text is split at the commas into separate numbers, so `[1,2,3]` is still an array of three
+    /// items when the callback rejects `1,2,3`. Separate items with a space (e.g. `f(3, 14)`)
+    /// where the callback would accept the combined text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// # #[cfg(not(feature = "no_float"))]
+    /// # {
+    /// use rhai::{Engine, FLOAT, INT};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// // Parse numbers with a comma as the decimal separator.
+    /// engine.on_parse_number(|text| match text.replace(',', ".").parse::<FLOAT>() {
+    ///     Ok(x) if text.contains(',') => Some(x.into()),
+    ///     _ => None,
+    /// });
+    ///
+    /// assert_eq!(engine.eval::<FLOAT>("3,25 * 2")?, 6.5);
+    /// assert_eq!(engine.eval::<INT>("let x = 40; x + 2")?, 42);
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn on_parse_number(
+        &mut self,
+        callback: impl Fn(&str) -> Option<Dynamic> + SendSync + 'static,
+    ) -> &mut Self {
+        self.number_parser = Some(Box::new(callback));
+        self
+    }
     /// Register a callback for script evaluation progress.
     ///
     /// Not available under `unchecked`.
@@ -255,6 +503,55 @@ impl Engine {
     pub fn on_progress(
         &mut self,
         callback: impl Fn(u64) -> Option<Dynamic> + SendSync + 'static,
+    ) -> &mut Self {
+        self.progress = Some(Box::new(move |ops| callback(ops).into()));
+        self
+    }
+    /// Register a callback for script evaluation progress which returns a [`ProgressAction`].
+    ///
+    /// This is the same as [`on_progress`][Engine::on_progress] except for the return type of the
+    /// callback. Registering one replaces the other.
+    ///
+    /// Not available under `unchecked`.
+    ///
+    /// # Callback Function Signature
+    ///
+    /// `Fn(counter: u64) -> ProgressAction`
+    ///
+    /// ## Return value
+    ///
+    /// * [`ProgressAction::Continue`]: continue running the script.
+    /// * [`ProgressAction::Abort`]: terminate the script with the specified exception value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Engine, EvalAltResult, ProgressAction};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.on_progress_action(|ops| {
+    ///     if ops > 1000 {
+    ///         ProgressAction::Abort("Over 1,000 operations!".into())
+    ///     } else {
+    ///         ProgressAction::Continue
+    ///     }
+    /// });
+    ///
+    /// let err = engine.run("for x in 0..5000 {}").expect_err("should error");
+    ///
+    /// match *err {
+    ///     EvalAltResult::ErrorTerminated(token, ..) => {
+    ///         assert_eq!(token.into_string().unwrap(), "Over 1,000 operations!")
+    ///     }
+    ///     _ => panic!("should be terminated"),
+    /// }
+    /// ```
+    #[cfg(not(feature = "unchecked"))]
+    #[inline(always)]
+    pub fn on_progress_action(
+        &mut self,
+        callback: impl Fn(u64) -> ProgressAction + SendSync + 'static,
     ) -> &mut Self {
         self.progress = Some(Box::new(callback));
         self
@@ -338,9 +635,57 @@ impl Engine {
         self.debug = Box::new(callback);
         self
     }
+    /// Override default action of `log` (print to stdout using [`println!`])
+    ///
+    /// Scripts call `log(level, message)`, where `level` is one of `"trace"`, `"debug"`, `"info"`,
+    /// `"warn"` or `"error"` (case-insensitive) and `message` is converted into a string like
+    /// `print`. An invalid level raises an error.
+    ///
+    /// # Callback Function Signature
+    ///
+    /// `Fn(level: LogLevel, text: &str)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// # use std::sync::RwLock;
+    /// # use std::sync::Arc;
+    /// use rhai::{Engine, LogLevel};
+    ///
+    /// let result = Arc::new(RwLock::new(Vec::new()));
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// // Override action of 'log' function
+    /// let logger = result.clone();
+    /// engine.on_log(move |level, s| logger.write().unwrap().push((level, s.to_string())));
+    ///
+    /// engine.run(r#"log("warn", 40 + 2);"#)?;
+    ///
+    /// assert_eq!(*result.read().unwrap(), [(LogLevel::Warn, "42".to_string())]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn on_log(&mut self, callback: impl Fn(LogLevel, &str) + SendSync + 'static) -> &mut Self {
+        self.log = Box::new(callback);
+        self
+    }
     /// _(debugging)_ Register a callback for debugging.
     /// Exported under the `debugging` feature only.
     ///
+    /// # Custom State
+    ///
+    /// The `init` function returns the initial custom state of the [`Debugger`][crate::debugger::Debugger]
+    /// for each evaluation session. Any data (e.g. breakpoint metadata or watch expressions) can
+    /// be kept in it, such as in an object map or a custom type.
+    ///
+    /// Inside the callback, the custom state is available via
+    /// `context.global_runtime_state_mut().debugger`, using the
+    /// [`state`][crate::debugger::Debugger::state], [`state_mut`][crate::debugger::Debugger::state_mut]
+    /// and [`set_state`][crate::debugger::Debugger::set_state] methods.
+    ///
     /// # WARNING - Unstable API
     ///
     /// This API is volatile and may change in the future.
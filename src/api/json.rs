@@ -3,7 +3,10 @@
 
 use crate::parser::ParseState;
 use crate::tokenizer::Token;
-use crate::{Engine, LexError, Map, OptimizationLevel, RhaiResultOf, Scope};
+use crate::{
+    Dynamic, Engine, ExpressionOptions, ImmutableString, LexError, Map, OptimizationLevel,
+    RhaiResultOf, Scope,
+};
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 
@@ -122,6 +125,7 @@ impl Engine {
         let ast = self.parse_global_expr(
             &mut stream.peekable(),
             &mut state,
+            ExpressionOptions::empty(),
             #[cfg(not(feature = "no_optimize"))]
             OptimizationLevel::None,
             #[cfg(feature = "no_optimize")]
@@ -179,3 +183,161 @@ pub fn format_map_as_json(map: &Map) -> String {
 
     result
 }
+
+/// Return the JSON representation of an [object map][Map], with `indent` spaces per nesting level.
+///
+/// Not available under `no_object`.
+///
+/// Keys are always emitted in sorted order, so the output is stable.
+/// An `indent` of zero produces compact output on a single line.
+///
+/// Unlike [`format_map_as_json`], arrays and object maps are serialized recursively, and strings
+/// are escaped according to JSON rules, so the result is always valid JSON.
+///
+/// Values not supported by JSON are passed to `fallback`, which either returns the text to
+/// serialize as a JSON string, or an error that aborts the serialization.
+pub(crate) fn format_map_as_json_pretty(
+    map: &Map,
+    indent: usize,
+    fallback: &dyn Fn(&Dynamic) -> RhaiResultOf<ImmutableString>,
+) -> RhaiResultOf<String> {
+    let mut result = String::new();
+    write_json_map(&mut result, map, indent, 0, fallback)?;
+    Ok(result)
+}
+
+/// Start a new line at the specified nesting level, if `indent` is not zero.
+fn write_json_newline(buf: &mut String, indent: usize, level: usize) {
+    if indent > 0 {
+        buf.push('\n');
+        buf.extend(std::iter::repeat(' ').take(indent * level));
+    }
+}
+
+/// Write a JSON string literal.
+fn write_json_string(buf: &mut String, s: &str) {
+    use std::fmt::Write;
+
+    buf.push('"');
+
+    for ch in s.chars() {
+        match ch {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            c if c.is_control() => write!(buf, "\\u{:04x}", c as u32).unwrap(),
+            c => buf.push(c),
+        }
+    }
+
+    buf.push('"');
+}
+
+/// Write an [object map][Map] as a JSON object.
+fn write_json_map(
+    buf: &mut String,
+    map: &Map,
+    indent: usize,
+    level: usize,
+    fallback: &dyn Fn(&Dynamic) -> RhaiResultOf<ImmutableString>,
+) -> RhaiResultOf<()> {
+    if map.is_empty() {
+        buf.push_str("{}");
+        return Ok(());
+    }
+
+    buf.push('{');
+
+    for (i, (key, value)) in map.iter().enumerate() {
+        if i > 0 {
+            buf.push(',');
+        }
+        write_json_newline(buf, indent, level + 1);
+        write_json_string(buf, key);
+        buf.push(':');
+        if indent > 0 {
+            buf.push(' ');
+        }
+        write_json_value(buf, value, indent, level + 1, fallback)?;
+    }
+
+    write_json_newline(buf, indent, level);
+    buf.push('}');
+
+    Ok(())
+}
+
+/// Write a [`Dynamic`] value as JSON.
+fn write_json_value(
+    buf: &mut String,
+    value: &Dynamic,
+    indent: usize,
+    level: usize,
+    fallback: &dyn Fn(&Dynamic) -> RhaiResultOf<ImmutableString>,
+) -> RhaiResultOf<()> {
+    use std::fmt::Write;
+
+    if value.is::<()>() {
+        buf.push_str("null");
+        return Ok(());
+    }
+    if let Ok(b) = value.as_bool() {
+        write!(buf, "{b}").unwrap();
+        return Ok(());
+    }
+    if let Ok(n) = value.as_int() {
+        write!(buf, "{n}").unwrap();
+        return Ok(());
+    }
+    #[cfg(not(feature = "no_float"))]
+    if let Ok(f) = value.as_float() {
+        if f.is_finite() {
+            write!(buf, "{f:?}").unwrap();
+            return Ok(());
+        }
+    }
+    #[cfg(feature = "decimal")]
+    if let Ok(d) = value.as_decimal() {
+        write!(buf, "{d}").unwrap();
+        return Ok(());
+    }
+    if let Ok(c) = value.as_char() {
+        write_json_string(buf, c.encode_utf8(&mut [0; 4]));
+        return Ok(());
+    }
+    if let Some(s) = value.read_lock::<ImmutableString>() {
+        write_json_string(buf, &s);
+        return Ok(());
+    }
+    #[cfg(not(feature = "no_index"))]
+    if let Some(array) = value.read_lock::<crate::Array>() {
+        if array.is_empty() {
+            buf.push_str("[]");
+            return Ok(());
+        }
+
+        buf.push('[');
+
+        for (i, item) in array.iter().enumerate() {
+            if i > 0 {
+                buf.push(',');
+            }
+            write_json_newline(buf, indent, level + 1);
+            write_json_value(buf, item, indent, level + 1, fallback)?;
+        }
+
+        write_json_newline(buf, indent, level);
+        buf.push(']');
+
+        return Ok(());
+    }
+    if let Some(map) = value.read_lock::<Map>() {
+        return write_json_map(buf, &map, indent, level, fallback);
+    }
+
+    write_json_string(buf, &fallback(value)?);
+
+    Ok(())
+}
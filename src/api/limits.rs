@@ -19,6 +19,14 @@ pub struct Limits {
     /// Not available under `no_function`.
     #[cfg(not(feature = "no_function"))]
     pub max_call_stack_depth: usize,
+    /// Maximum depth of nested closure calls, counted separately from other function calls.
+    ///
+    /// Set to zero to count closure calls toward
+    /// [`max_call_stack_depth`][Limits::max_call_stack_depth] instead.
+    ///
+    /// Not available under `no_function`.
+    #[cfg(not(feature = "no_function"))]
+    pub max_closure_depth: usize,
     /// Maximum depth of statements/expressions at global level.
     pub max_expr_depth: Option<NonZeroUsize>,
     /// Maximum depth of statements/expressions in functions.
@@ -35,6 +43,8 @@ pub struct Limits {
     /// Not available under `no_module`.
     #[cfg(not(feature = "no_module"))]
     pub max_modules: usize,
+    /// Maximum number of variables (including constants) allowed in a [`Scope`][crate::Scope].
+    pub max_variables: Option<NonZeroUsize>,
     /// Maximum length of a [string][crate::ImmutableString].
     pub max_string_size: Option<NonZeroUsize>,
     /// Maximum length of an [array][crate::Array].
@@ -58,12 +68,15 @@ impl Limits {
         Self {
             #[cfg(not(feature = "no_function"))]
             max_call_stack_depth: default_limits::MAX_CALL_STACK_DEPTH,
+            #[cfg(not(feature = "no_function"))]
+            max_closure_depth: 0,
             max_expr_depth: NonZeroUsize::new(default_limits::MAX_EXPR_DEPTH),
             #[cfg(not(feature = "no_function"))]
             max_function_expr_depth: NonZeroUsize::new(default_limits::MAX_FUNCTION_EXPR_DEPTH),
             max_operations: None,
             #[cfg(not(feature = "no_module"))]
             max_modules: usize::MAX,
+            max_variables: None,
             max_string_size: None,
             #[cfg(not(feature = "no_index"))]
             max_array_size: None,
@@ -100,6 +113,35 @@ impl Engine {
     pub const fn max_call_levels(&self) -> usize {
         self.limits.max_call_stack_depth
     }
+    /// Set the maximum depth of nested closure calls allowed for a script, counted separately from
+    /// other function calls (0 to count closure calls as normal function calls, the default).
+    ///
+    /// When set, call levels taken up by calling closures (including native functions, such as
+    /// `map`, that call them) do not count toward [`max_call_levels`][Engine::max_call_levels].
+    /// This allows deep data traversal via closures while keeping recursion of normal functions
+    /// tight.
+    ///
+    /// Exceeding this limit raises
+    /// [`ErrorClosureStackOverflow`][crate::EvalAltResult::ErrorClosureStackOverflow] instead of
+    /// [`ErrorStackOverflow`][crate::EvalAltResult::ErrorStackOverflow].
+    ///
+    /// Not available under `unchecked` or `no_function`.
+    #[cfg(not(feature = "no_function"))]
+    #[inline(always)]
+    pub fn set_max_closure_depth(&mut self, depth: usize) -> &mut Self {
+        self.limits.max_closure_depth = depth;
+        self
+    }
+    /// The maximum depth of nested closure calls allowed for a script (0 if closure calls are
+    /// counted as normal function calls).
+    ///
+    /// Not available under `unchecked` or `no_function`.
+    #[cfg(not(feature = "no_function"))]
+    #[inline(always)]
+    #[must_use]
+    pub const fn max_closure_depth(&self) -> usize {
+        self.limits.max_closure_depth
+    }
     /// Set the maximum number of operations allowed for a script to run to avoid
     /// consuming too much resources (0 for unlimited).
     ///
@@ -139,6 +181,32 @@ impl Engine {
     pub const fn max_modules(&self) -> usize {
         self.limits.max_modules
     }
+    /// Set the maximum number of variables allowed in a [`Scope`][crate::Scope] (0 for unlimited).
+    ///
+    /// A `let` or `const` statement that would add a new variable to a [`Scope`][crate::Scope]
+    /// already holding this number of entries raises an error.
+    ///
+    /// All live entries count toward the limit, including constants, variables provided via an
+    /// external [`Scope`][crate::Scope] and function parameters.
+    ///
+    /// Not available under `unchecked`.
+    #[inline(always)]
+    pub fn set_max_variables(&mut self, variables: usize) -> &mut Self {
+        self.limits.max_variables = NonZeroUsize::new(variables);
+        self
+    }
+    /// The maximum number of variables allowed in a [`Scope`][crate::Scope] (0 for unlimited).
+    ///
+    /// Not available under `unchecked`.
+    #[inline]
+    #[must_use]
+    pub const fn max_variables(&self) -> usize {
+        if let Some(n) = self.limits.max_variables {
+            n.get()
+        } else {
+            0
+        }
+    }
     /// Set the depth limits for expressions (0 for unlimited).
     ///
     /// Not available under `unchecked`.
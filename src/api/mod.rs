@@ -30,9 +30,14 @@ pub mod deprecated;
 
 pub mod build_type;
 
+pub mod type_ops;
+
+pub mod module_builder;
+
 #[cfg(feature = "metadata")]
 pub mod definitions;
 
+use crate::types::dynamic::Tag;
 use crate::{Dynamic, Engine, Identifier};
 
 #[cfg(not(feature = "no_custom_syntax"))]
@@ -135,6 +140,114 @@ impl Engine {
         self
     }
 
+    /// Reserve an identifier as a keyword in the language.
+    ///
+    /// A reserved keyword can no longer be used as the name of a variable, constant, function,
+    /// parameter or property. Any such usage raises a parse error.
+    ///
+    /// It is still possible to use the keyword as the first token of a custom syntax
+    /// registered via [`register_custom_syntax`][Engine::register_custom_syntax], or as a custom
+    /// operator registered via [`register_custom_operator`][Engine::register_custom_operator].
+    ///
+    /// An error is returned if the word is not a valid identifier, or if it is already a keyword
+    /// or symbol in the language.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rhai::{Engine, ParseErrorType};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.reserve_keyword("entity").expect("should succeed");
+    ///
+    /// let err = engine.compile("let entity = 42;").expect_err("should error");
+    ///
+    /// assert_eq!(*err.err_type(), ParseErrorType::Reserved("entity".into()));
+    ///
+    /// // Symbols and existing keywords cannot be reserved
+    /// assert!(engine.reserve_keyword("#").is_err());
+    /// assert!(engine.reserve_keyword("while").is_err());
+    /// ```
+    pub fn reserve_keyword(&mut self, keyword: impl AsRef<str>) -> Result<&mut Self, String> {
+        let keyword = keyword.as_ref();
+
+        if crate::tokenizer::Token::lookup_from_syntax(keyword).is_some() {
+            return Err(format!("'{keyword}' is already a keyword or symbol"));
+        }
+        if !crate::tokenizer::is_valid_identifier(keyword.chars()) {
+            return Err(format!("'{keyword}' is not a valid identifier"));
+        }
+
+        self.reserved_keywords.insert(keyword.into());
+
+        Ok(self)
+    }
+
+    /// Is a script flag turned on?
+    ///
+    /// Flags that have never been set are off.
+    #[inline(always)]
+    #[must_use]
+    pub fn script_flag(&self, name: &str) -> bool {
+        self.script_flags.contains(name)
+    }
+    /// Turn a script flag on or off, for use in conditional compilation directives.
+    ///
+    /// Scripts can contain blocks of code that are included or stripped at compile time depending
+    /// on the value of a flag:
+    ///
+    /// ```text
+    /// #if FLAG
+    ///     // code compiled when FLAG is on
+    /// #else
+    ///     // code compiled when FLAG is off
+    /// #endif
+    /// ```
+    ///
+    /// The `#else` branch is optional and blocks can be nested. Flags that have never been set
+    /// are off. A block without a matching `#endif` is a syntax error.
+    ///
+    /// Code inside a stripped block is never parsed, but must still consist of valid tokens.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, INT};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.set_script_flag("PRODUCTION", true);
+    ///
+    /// let script = "
+    ///     #if PRODUCTION
+    ///         42
+    ///     #else
+    ///         0
+    ///     #endif
+    /// ";
+    ///
+    /// assert_eq!(engine.eval::<INT>(script)?, 42);
+    ///
+    /// engine.set_script_flag("PRODUCTION", false);
+    ///
+    /// assert_eq!(engine.eval::<INT>(script)?, 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn set_script_flag(&mut self, name: impl Into<Identifier>, value: bool) -> &mut Self {
+        let name = name.into();
+
+        if value {
+            self.script_flags.insert(name);
+        } else {
+            self.script_flags.remove(&name);
+        }
+        self
+    }
+
     /// Register a custom operator with a precedence into the language.
     ///
     /// Not available under `no_custom_syntax`.
@@ -143,6 +256,12 @@ impl Engine {
     ///
     /// The precedence cannot be zero.
     ///
+    /// # Op-Assignment
+    ///
+    /// The op-assignment form `a OP= b` (no space between the operator and `=`) is also supported.
+    /// A function named `OP=` taking a `&mut` first parameter is called if registered;
+    /// otherwise it expands to `a = a OP b`.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -216,6 +335,18 @@ impl Engine {
 
         Ok(self)
     }
+    /// Is a symbol the op-assignment form (e.g. `#=`) of a custom operator (e.g. `#`)?
+    #[cfg(not(feature = "no_custom_syntax"))]
+    #[inline]
+    #[must_use]
+    pub(crate) fn is_custom_op_assignment(&self, symbol: &str) -> bool {
+        symbol.len() > 1
+            && symbol.ends_with('=')
+            && self
+                .custom_keywords
+                .get(&symbol[..symbol.len() - 1])
+                .map_or(false, Option::is_some)
+    }
 
     /// Get the default value of the custom state for each evaluation run.
     #[inline(always)]
@@ -235,4 +366,47 @@ impl Engine {
         self.def_tag = value.into();
         self
     }
+    /// Get the default tag stamped onto values created during an evaluation run.
+    ///
+    /// Zero (the default) means that values are not stamped.
+    #[inline(always)]
+    #[must_use]
+    pub const fn default_value_tag(&self) -> Tag {
+        self.def_value_tag
+    }
+    /// Set the default tag stamped onto values created during an evaluation run
+    /// (zero to turn off stamping).
+    ///
+    /// Literals (including array and object map literals) and the results of function calls
+    /// (including operators and method calls) are stamped with this tag, unless they already carry
+    /// a non-zero tag (e.g. one set explicitly via `set_tag`).
+    ///
+    /// Values read from variables, properties or indices are never re-stamped, so values injected
+    /// via a [`Scope`][crate::Scope] keep their original tags.
+    ///
+    /// # Performance
+    ///
+    /// When turned on, a small overhead is incurred for every literal and function call evaluated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Dynamic};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.set_default_value_tag(42);
+    ///
+    /// let value = engine.eval::<Dynamic>("40 + 2")?;
+    ///
+    /// assert_eq!(value.tag(), 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn set_default_value_tag(&mut self, tag: Tag) -> &mut Self {
+        self.def_value_tag = tag;
+        self
+    }
 }
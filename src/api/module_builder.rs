@@ -0,0 +1,107 @@
+//! Module that defines a builder for static module namespaces registered with an [`Engine`].
+#![cfg(not(feature = "no_module"))]
+
+use crate::func::RegisterNativeFunction;
+use crate::types::dynamic::Variant;
+use crate::{Engine, FnAccess, FnNamespace, Identifier, Module};
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+impl Engine {
+    /// Start building a static module namespace to register with the [`Engine`].
+    ///
+    /// This is a short-hand for creating a [`Module`], adding functions and constants into it,
+    /// and registering it via [`register_static_module`][Engine::register_static_module].
+    ///
+    /// The module is registered when [`build`][ModuleBuilder::build] is called.
+    ///
+    /// Not available under `no_module`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::Engine;
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine
+    ///     .module_builder("calc")
+    ///     .with_fn("inc", |x: i64| x + 1)
+    ///     .with_constant("ANSWER", 42_i64)
+    ///     .build();
+    ///
+    /// assert_eq!(engine.eval::<i64>("calc::inc(calc::ANSWER)")?, 43);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn module_builder(&mut self, name: impl Into<Identifier>) -> ModuleBuilder {
+        ModuleBuilder {
+            engine: self,
+            name: name.into(),
+            module: Module::new(),
+        }
+    }
+}
+
+/// Builder to build a static module namespace for use with an [`Engine`].
+///
+/// Created via [`Engine::module_builder`].
+///
+/// Not available under `no_module`.
+#[derive(Debug)]
+#[must_use = "the module is only registered when `build` is called"]
+pub struct ModuleBuilder<'a> {
+    engine: &'a mut Engine,
+    name: Identifier,
+    module: Module,
+}
+
+impl<'a> ModuleBuilder<'a> {
+    /// Add a native Rust function into the module.
+    ///
+    /// The function is only accessible via the module's namespace (e.g. `name::func(...)`).
+    ///
+    /// If there is a similar existing function, it is replaced.
+    #[inline]
+    pub fn with_fn<A, R, S, F: RegisterNativeFunction<A, R, S>>(
+        mut self,
+        name: impl AsRef<str> + Into<Identifier>,
+        func: F,
+    ) -> Self {
+        self.module.set_fn(
+            name,
+            FnNamespace::Internal,
+            FnAccess::Public,
+            None,
+            &F::param_types(),
+            func.into_callable_function(),
+        );
+        self
+    }
+    /// Add a constant into the module.
+    ///
+    /// The constant is only accessible via the module's namespace (e.g. `name::CONSTANT`).
+    ///
+    /// If there is an existing constant of the same name, it is replaced.
+    #[inline(always)]
+    pub fn with_constant(
+        mut self,
+        name: impl Into<Identifier>,
+        value: impl Variant + Clone,
+    ) -> Self {
+        self.module.set_var(name, value);
+        self
+    }
+    /// Register the module as a static module namespace with the [`Engine`].
+    ///
+    /// The name may contain `::` to register the module as a sub-module,
+    /// just like [`Engine::register_static_module`].
+    #[inline]
+    pub fn build(self) -> &'a mut Engine {
+        self.engine
+            .register_static_module(self.name, self.module.into())
+    }
+}
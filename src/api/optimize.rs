@@ -23,6 +23,152 @@ impl Engine {
         self.optimization_level
     }
 
+    /// Exclude script-defined functions with the specified names from optimization, replacing any
+    /// previous exclusions.
+    ///
+    /// The bodies of excluded functions are kept verbatim regardless of the optimization level,
+    /// so that statement positions remain accurate (e.g. when stepping through them in a
+    /// debugger). All other code is optimized as usual.
+    ///
+    /// Not available under `no_optimize` or `no_function`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, OptimizationLevel};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.set_optimization_level(OptimizationLevel::Full)
+    ///       .set_optimize_exclusions(&["foo"]);
+    ///
+    /// assert!(engine.is_optimize_excluded("foo"));
+    /// assert!(!engine.is_optimize_excluded("bar"));
+    ///
+    /// // The body of 'foo' is kept verbatim, while 'bar' is optimized
+    /// let ast = engine.compile("
+    ///     fn foo() { if true { 42 } }
+    ///     fn bar() { if true { 42 } }
+    ///     foo() + bar()
+    /// ")?;
+    ///
+    /// assert_eq!(engine.eval_ast::<i64>(&ast)?, 84);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_function"))]
+    #[inline]
+    pub fn set_optimize_exclusions(&mut self, names: &[&str]) -> &mut Self {
+        self.optimize_exclusions = names.iter().map(|&name| name.into()).collect();
+        self
+    }
+    /// Is the script-defined function with the specified name excluded from optimization?
+    ///
+    /// Not available under `no_optimize` or `no_function`.
+    #[cfg(not(feature = "no_function"))]
+    #[inline(always)]
+    #[must_use]
+    pub fn is_optimize_excluded(&self, name: &str) -> bool {
+        self.optimize_exclusions.contains(name)
+    }
+
+    /// Control whether array and object map literals whose items are all constants are folded into
+    /// single constants during optimization. Default is `true`.
+    ///
+    /// Not available under `no_optimize`.
+    ///
+    /// Folding happens at [`OptimizationLevel::Simple`] and above. A folded literal is not rebuilt
+    /// item by item on each evaluation, and indexing into it (e.g. `[10, 20, 30][i]`) reads the
+    /// item directly without copying the whole literal. Mutating a copy (e.g. `let x = [1, 2];
+    /// x.push(3);`) never changes the constant itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::Engine;
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// assert!(engine.optimization_constant_array_folding());
+    ///
+    /// engine.set_optimization_constant_array_folding(false);
+    ///
+    /// assert!(!engine.optimization_constant_array_folding());
+    /// ```
+    #[inline(always)]
+    pub fn set_optimization_constant_array_folding(&mut self, enable: bool) -> &mut Self {
+        self.optimize_constant_array_folding = enable;
+        self
+    }
+    /// Are array and object map literals whose items are all constants folded into single
+    /// constants during optimization?
+    ///
+    /// Not available under `no_optimize`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn optimization_constant_array_folding(&self) -> bool {
+        self.optimize_constant_array_folding
+    }
+
+    /// Control whether private script-defined functions that cannot be reached from the top-level
+    /// statements or from any public function are removed from the [`AST`] at
+    /// [`OptimizationLevel::Full`]. Default is `false`.
+    ///
+    /// Not available under `no_optimize` or `no_function`.
+    ///
+    /// A function is considered reachable when its name appears in reachable code as the name of
+    /// a function call, a string literal or a function pointer. No functions are removed if the
+    /// script calls `eval`.
+    ///
+    /// # Caveat
+    ///
+    /// Only turn this on when private functions are never called from outside the script. Calls
+    /// that are not visible in the script itself fail once the function is removed, for example:
+    ///
+    /// * calling a private function via [`call_fn`][Engine::call_fn],
+    /// * calling a private function of one script from another script merged via
+    ///   [`AST::merge`] or [`AST::combine`],
+    /// * calling a function by a name computed at runtime (e.g. `Fn(name)`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, OptimizationLevel};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.set_optimization_level(OptimizationLevel::Full)
+    ///       .set_optimization_remove_unused_functions(true);
+    ///
+    /// let ast = engine.compile("
+    ///     private fn used() { 42 }
+    ///     private fn unused() { 0 }
+    ///     used()
+    /// ")?;
+    ///
+    /// assert_eq!(ast.iter_functions().count(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_function"))]
+    #[inline(always)]
+    pub fn set_optimization_remove_unused_functions(&mut self, enable: bool) -> &mut Self {
+        self.optimize_remove_unused_functions = enable;
+        self
+    }
+    /// Are private script-defined functions that cannot be reached removed from the [`AST`] at
+    /// [`OptimizationLevel::Full`]?
+    ///
+    /// Not available under `no_optimize` or `no_function`.
+    #[cfg(not(feature = "no_function"))]
+    #[inline(always)]
+    #[must_use]
+    pub const fn optimization_remove_unused_functions(&self) -> bool {
+        self.optimize_remove_unused_functions
+    }
+
     /// Optimize the [`AST`] with constants defined in an external Scope.
     /// An optimized copy of the [`AST`] is returned while the original [`AST`] is consumed.
     ///
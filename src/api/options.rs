@@ -29,6 +29,19 @@ bitflags! {
         const FAIL_ON_INVALID_MAP_PROPERTY = 0b_0000_1000_0000;
         /// Fast operators mode?
         const FAST_OPS = 0b_0001_0000_0000;
+        /// Require a default case in `switch`?
+        const STRICT_SWITCH = 0b_0010_0000_0000;
+        /// Keep the full chain of function calls in errors?
+        const ERROR_BACKTRACE = 0b_0100_0000_0000;
+        /// Raise error if no arm of a `match` matches the value?
+        const STRICT_MATCH = 0b_1000_0000_0000;
+        /// Raise error when mixing integers and floating-point numbers in arithmetic?
+        #[cfg(not(feature = "no_float"))]
+        const STRICT_NUMERIC = 0b_0001_0000_0000_0000;
+        /// Pretty-print nested arrays and object maps in `debug`?
+        const DEBUG_PRETTY = 0b_0010_0000_0000_0000;
+        /// Can a new line terminate a statement?
+        const NEWLINE_STMT_END = 0b_0100_0000_0000_0000;
     }
 }
 
@@ -56,6 +69,27 @@ impl LangOptions {
     }
 }
 
+bitflags! {
+    /// Bit-flags containing the statements allowed before the final expression in
+    /// [`Engine::compile_expression_with_options`].
+    ///
+    /// Statements must be terminated by semicolons, and the script must still end with an
+    /// expression, which is the result.
+    pub struct ExpressionOptions: u8 {
+        /// Are `let` and `const` statements allowed?
+        const VARIABLES = 0b_0000_0001;
+        /// Are assignments (including op-assignments) allowed?
+        const ASSIGNMENTS = 0b_0000_0010;
+        /// Are `while`, `loop`, `do` and `for` loops allowed?
+        ///
+        /// Loops are still subject to [`Engine::allow_looping`].
+        ///
+        /// The body of a loop is a normal statements block, so it may contain any statement
+        /// (e.g. `let` or assignments) regardless of the other flags.
+        const LOOPS = 0b_0000_0100;
+    }
+}
+
 impl Engine {
     /// Is `if`-expression allowed?
     /// Default is `true`.
@@ -105,6 +139,9 @@ impl Engine {
     }
     /// Set whether anonymous function is allowed.
     ///
+    /// When disabled, closures (including closure block arguments such as `foo { |x| x }`) fail to
+    /// parse with [`ParseErrorType::ForbiddenAnonymousFn`][crate::ParseErrorType::ForbiddenAnonymousFn].
+    ///
     /// Not available under `no_function`.
     #[cfg(not(feature = "no_function"))]
     #[inline(always)]
@@ -135,6 +172,26 @@ impl Engine {
     pub fn set_allow_shadowing(&mut self, enable: bool) {
         self.options.set(LangOptions::SHADOW, enable);
     }
+    /// Can a new line terminate a statement?
+    /// Default is `false`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn allow_newline_as_statement_end(&self) -> bool {
+        self.options.contains(LangOptions::NEWLINE_STMT_END)
+    }
+    /// Set whether a new line can terminate a statement.
+    ///
+    /// When enabled, a statement that is not followed by `;` is terminated if the next token
+    /// starts on a new line. Explicit `;` still works.
+    ///
+    /// A new line only ends a statement where a `;` would otherwise be missing, so an expression
+    /// that continues on the next line (e.g. with a binary operator) is not affected.
+    ///
+    /// Has no effect under `no_position`.
+    #[inline(always)]
+    pub fn set_allow_newline_as_statement_end(&mut self, enable: bool) {
+        self.options.set(LangOptions::NEWLINE_STMT_END, enable);
+    }
     /// Is strict variables mode enabled?
     /// Default is `false`.
     #[inline(always)]
@@ -147,6 +204,82 @@ impl Engine {
     pub fn set_strict_variables(&mut self, enable: bool) {
         self.options.set(LangOptions::STRICT_VAR, enable);
     }
+    /// Is strict `switch` mode enabled?
+    /// Default is `false`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn strict_switch(&self) -> bool {
+        self.options.contains(LangOptions::STRICT_SWITCH)
+    }
+    /// Set whether strict `switch` mode is enabled.
+    ///
+    /// In strict `switch` mode, a `switch` without a default (`_`) case is a parse error,
+    /// because any value not matched by a case would silently evaluate to `()`.
+    #[inline(always)]
+    pub fn set_strict_switch(&mut self, enable: bool) {
+        self.options.set(LangOptions::STRICT_SWITCH, enable);
+    }
+    /// Is error backtrace mode enabled?
+    /// Default is `false`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn error_backtrace(&self) -> bool {
+        self.options.contains(LangOptions::ERROR_BACKTRACE)
+    }
+    /// Set whether error backtrace mode is enabled.
+    ///
+    /// Normally, when an error propagates out of nested script function calls, the calls are
+    /// collapsed into a single [`ErrorInFunctionCall`][crate::EvalAltResult::ErrorInFunctionCall]
+    /// whose function name lists the chain (e.g. `inner < outer`) and only the position of the
+    /// outermost call is kept.
+    ///
+    /// In error backtrace mode, one [`ErrorInFunctionCall`][crate::EvalAltResult::ErrorInFunctionCall]
+    /// is kept for each call, so the position of every call in the chain is available via
+    /// [`EvalAltResult::backtrace`][crate::EvalAltResult::backtrace].
+    ///
+    /// There is no overhead for successful calls. Each function call that an error propagates
+    /// through allocates one more level of nested error, which also lengthens the error message.
+    #[inline(always)]
+    pub fn set_error_backtrace(&mut self, enable: bool) {
+        self.options.set(LangOptions::ERROR_BACKTRACE, enable);
+    }
+    /// Is strict `match` mode enabled?
+    /// Default is `false`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn strict_match(&self) -> bool {
+        self.options.contains(LangOptions::STRICT_MATCH)
+    }
+    /// Set whether strict `match` mode is enabled.
+    ///
+    /// In strict `match` mode, a `match` in which no arm matches the value raises an error,
+    /// instead of evaluating to `()`.
+    #[inline(always)]
+    pub fn set_strict_match(&mut self, enable: bool) {
+        self.options.set(LangOptions::STRICT_MATCH, enable);
+    }
+    /// Is strict numeric coercion mode enabled?
+    /// Default is `false`.
+    ///
+    /// Not available under `no_float`.
+    #[cfg(not(feature = "no_float"))]
+    #[inline(always)]
+    #[must_use]
+    pub const fn strict_numeric_coercion(&self) -> bool {
+        self.options.contains(LangOptions::STRICT_NUMERIC)
+    }
+    /// Set whether strict numeric coercion mode is enabled.
+    ///
+    /// In strict numeric coercion mode, arithmetic between an integer and a floating-point number
+    /// raises an error instead of silently promoting the integer to floating-point.
+    /// Comparisons between integers and floating-point numbers are not affected.
+    ///
+    /// Not available under `no_float`.
+    #[cfg(not(feature = "no_float"))]
+    #[inline(always)]
+    pub fn set_strict_numeric_coercion(&mut self, enable: bool) {
+        self.options.set(LangOptions::STRICT_NUMERIC, enable);
+    }
     /// Raise error if an object map property does not exist?
     /// Default is `false`.
     ///
@@ -168,15 +301,56 @@ impl Engine {
             .set(LangOptions::FAIL_ON_INVALID_MAP_PROPERTY, enable);
     }
     /// Is fast operators mode enabled?
-    /// Default is `false`.
+    /// Default is `true`.
     #[inline(always)]
     #[must_use]
     pub const fn fast_operators(&self) -> bool {
         self.options.contains(LangOptions::FAST_OPS)
     }
     /// Set whether fast operators mode is enabled.
+    ///
+    /// In fast operators mode, built-in operators on standard types (e.g. `+` on two integers)
+    /// are evaluated inline and any function registered under the same operator is ignored.
+    ///
+    /// When disabled, all operators are looked up among the registered functions first, so they
+    /// can be overridden (e.g. to provide saturating or logging arithmetic). Compound assignments
+    /// such as `x += y` call the registered `+=` function if any, otherwise they are evaluated as
+    /// `x = x + y`.
+    ///
+    /// # Performance
+    ///
+    /// Disabling fast operators mode makes every operator a function call that must be resolved,
+    /// which is considerably slower for arithmetic-heavy scripts.
     #[inline(always)]
     pub fn set_fast_operators(&mut self, enable: bool) {
         self.options.set(LangOptions::FAST_OPS, enable);
     }
+    /// Is pretty-printing of arrays and object maps in `debug` enabled?
+    /// Default is `false`.
+    ///
+    /// When enabled, `debug` formats arrays and object maps over multiple lines, with nested
+    /// values indented by [`debug_indent`][Engine::debug_indent] spaces per level.
+    /// `print`, `to_string` and `to_debug` are not affected.
+    #[inline(always)]
+    #[must_use]
+    pub const fn debug_pretty(&self) -> bool {
+        self.options.contains(LangOptions::DEBUG_PRETTY)
+    }
+    /// Set whether pretty-printing of arrays and object maps in `debug` is enabled.
+    #[inline(always)]
+    pub fn set_debug_pretty(&mut self, enable: bool) {
+        self.options.set(LangOptions::DEBUG_PRETTY, enable);
+    }
+    /// Number of spaces per level of indentation when pretty-printing in `debug`.
+    /// Default is 4.
+    #[inline(always)]
+    #[must_use]
+    pub const fn debug_indent(&self) -> usize {
+        self.debug_indent
+    }
+    /// Set the number of spaces per level of indentation when pretty-printing in `debug`.
+    #[inline(always)]
+    pub fn set_debug_indent(&mut self, indent: usize) {
+        self.debug_indent = indent;
+    }
 }
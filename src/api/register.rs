@@ -1,6 +1,9 @@
 //! Module that defines the public function/module registration API of [`Engine`].
 
-use crate::func::{FnCallArgs, RegisterNativeFunction, SendSync};
+use crate::func::{
+    CallableFunction, FnCallArgs, RegisterFallibleFunction, RegisterLazyFunction,
+    RegisterNativeFunction, SendSync,
+};
 use crate::types::dynamic::Variant;
 use crate::{
     Engine, FnAccess, FnNamespace, Identifier, Module, NativeCallContext, RhaiResultOf, Shared,
@@ -30,6 +33,65 @@ impl Engine {
     }
     /// Register a custom function with the [`Engine`].
     ///
+    /// # String Parameters
+    ///
+    /// Parameters of type `&str` borrow directly from the [`ImmutableString`][crate::ImmutableString]
+    /// argument, so the string is never cloned on the call path.
+    ///
+    /// Parameters of type `String` take the string by value, which clones it unless the argument
+    /// holds the only reference to it. Prefer `&str` for functions that only read the string.
+    ///
+    /// # Slice Parameters
+    ///
+    /// Parameters of type `&[INT]`, `&[FLOAT]`, `&[bool]`, `&[char]`, `&[ImmutableString]` or
+    /// `&[Dynamic]` take an [`Array`][crate::Array] (not available under `no_index`).
+    ///
+    /// The elements of the array are cloned into a temporary [`Vec`] for the duration of the
+    /// call, and an error is raised with the index of the first element that is not of the
+    /// required type.
+    ///
+    /// # Fixed-Size Array Parameters
+    ///
+    /// Parameters of type `[T; N]`, where `T` is one of the element types above and `N` is at most
+    /// 16, also take an [`Array`][crate::Array] (not available under `no_index`).
+    ///
+    /// An error is raised, stating both the expected and the actual lengths, if the length of the
+    /// array is not `N`.
+    ///
+    /// # Optional Parameters
+    ///
+    /// Parameters of type `Option<INT>`, `Option<FLOAT>`, `Option<bool>`, `Option<char>`,
+    /// `Option<ImmutableString>`, `Option<String>`, `Option<Array>` or `Option<Map>` take either
+    /// `()`, which is passed as `None`, or a value of the inner type, which is passed as `Some`.
+    ///
+    /// Such parameters accept arguments of any type during function resolution, so an overload
+    /// with a more specific parameter type (and the same number of parameters) is always preferred.
+    /// Optional parameters do not change the number of parameters: to call the function with the
+    /// argument omitted (e.g. `foo()` instead of `foo(())`), register an overload without it.
+    ///
+    /// # Fallible Functions
+    ///
+    /// A function returning [`Result<T, Box<EvalAltResult>>`][crate::EvalAltResult] raises the
+    /// error in the script when it returns [`Err`]. This includes functions that only perform an
+    /// action and return `Result<(), Box<EvalAltResult>>`, which can be called as statements.
+    ///
+    /// The error propagates up the call stack and aborts the script unless it is caught by a
+    /// `try` ... `catch` block, so there is no need to check the result within the script.
+    ///
+    /// # Mutable First Parameter
+    ///
+    /// A first parameter of type `&mut T` receives a reference to the variable when the function
+    /// is called in method-call style (`x.foo()`) or as `foo(x)` on a variable `x`, so changes are
+    /// visible in the script. When the argument is a temporary value (e.g. the result of another
+    /// function call), the function receives that value and any changes are discarded with it.
+    ///
+    /// Function resolution does not distinguish a first parameter of type `&mut T` from one of
+    /// type `T`; both register the same signature. Therefore a function cannot be overloaded on
+    /// whether its first parameter is mutable: registering a function with the same name and
+    /// parameter types replaces the one registered before it. Use different names for reading and
+    /// mutating functions instead. A reading function can still take `&mut T` to avoid cloning
+    /// the argument.
+    ///
     /// # Example
     ///
     /// ```
@@ -60,17 +122,35 @@ impl Engine {
         name: impl AsRef<str> + Into<Identifier>,
         func: F,
     ) -> &mut Self {
-        let param_types = F::param_types();
-
+        self.register_native_fn(
+            name,
+            F::param_types(),
+            #[cfg(feature = "metadata")]
+            &F::param_names(),
+            #[cfg(feature = "metadata")]
+            (F::return_type() != TypeId::of::<()>()).then(F::return_type_name),
+            func.into_callable_function(),
+        )
+    }
+    /// Register a native function into the global namespace, recording the type names of its
+    /// parameters and return value (if any) as metadata.
+    fn register_native_fn(
+        &mut self,
+        name: impl AsRef<str> + Into<Identifier>,
+        param_types: impl AsRef<[TypeId]>,
+        #[cfg(feature = "metadata")] param_names: &[&str],
+        #[cfg(feature = "metadata")] return_type_name: Option<&str>,
+        func: CallableFunction,
+    ) -> &mut Self {
         #[cfg(feature = "metadata")]
-        let mut param_type_names: crate::StaticVec<_> = F::param_names()
+        let mut param_type_names: crate::StaticVec<_> = param_names
             .iter()
             .map(|ty| format!("_: {}", self.format_type_name(ty)))
             .collect();
 
         #[cfg(feature = "metadata")]
-        if F::return_type() != TypeId::of::<()>() {
-            param_type_names.push(self.format_type_name(F::return_type_name()).into());
+        if let Some(ty) = return_type_name {
+            param_type_names.push(self.format_type_name(ty).into());
         }
 
         #[cfg(feature = "metadata")]
@@ -88,10 +168,105 @@ impl Engine {
             FnAccess::Public,
             param_type_names,
             param_types,
-            func.into_callable_function(),
+            func,
         );
         self
     }
+    /// Register a custom fallible function with the [`Engine`].
+    ///
+    /// The function returns [`Result`] with any error type that implements [`Display`][std::fmt::Display],
+    /// such as [`Box<dyn std::error::Error>`][std::error::Error] or `anyhow::Error`.
+    /// An error is turned into an [`ErrorRuntime`][crate::EvalAltResult::ErrorRuntime] holding the
+    /// error message, at the position of the function call.
+    ///
+    /// To return a specific [`EvalAltResult`][crate::EvalAltResult] error instead, use
+    /// [`register_fn`][Engine::register_fn] with a function returning
+    /// [`Result<T, Box<EvalAltResult>>`][crate::EvalAltResult].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Engine, EvalAltResult};
+    ///
+    /// fn parse(s: &str) -> Result<i64, std::num::ParseIntError> {
+    ///     s.parse()
+    /// }
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.register_fn_fallible("parse", parse);
+    ///
+    /// assert_eq!(engine.eval::<i64>(r#"parse("42")"#).unwrap(), 42);
+    ///
+    /// let err = engine.eval::<i64>(r#"parse("hello")"#).unwrap_err();
+    ///
+    /// assert!(matches!(*err, EvalAltResult::ErrorRuntime(ref msg, ..)
+    ///                        if msg.to_string() == "invalid digit found in string"));
+    /// ```
+    #[inline]
+    pub fn register_fn_fallible<A, R, F: RegisterFallibleFunction<A, R>>(
+        &mut self,
+        name: impl AsRef<str> + Into<Identifier>,
+        func: F,
+    ) -> &mut Self {
+        self.register_native_fn(
+            name,
+            F::param_types(),
+            #[cfg(feature = "metadata")]
+            &F::param_names(),
+            #[cfg(feature = "metadata")]
+            (F::return_type() != TypeId::of::<()>()).then(F::return_type_name),
+            func.into_callable_function(),
+        )
+    }
+    /// Register a custom function returning a Rust iterator with the [`Engine`].
+    ///
+    /// The iterator is wrapped in a [`LazySequence`][crate::LazySequence] which scripts iterate
+    /// with `for` loops, producing each value only when needed. This avoids building large
+    /// intermediate arrays for streaming sources.
+    ///
+    /// In scripts, a lazy sequence supports the `take(n)` and `map(fn)` combinators, which also
+    /// return lazy sequences.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Dynamic, Engine};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// // An endless sequence of numbers
+    /// engine.register_lazy_fn("numbers", |start: i64| (start..).map(Dynamic::from));
+    ///
+    /// let result = engine.eval::<i64>(
+    ///     "
+    ///         let sum = 0;
+    ///         for x in numbers(1).map(|x| x * x).take(3) { sum += x; }
+    ///         sum
+    ///     ",
+    /// )?;
+    ///
+    /// assert_eq!(result, 14);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn register_lazy_fn<A, F: RegisterLazyFunction<A>>(
+        &mut self,
+        name: impl AsRef<str> + Into<Identifier>,
+        func: F,
+    ) -> &mut Self {
+        self.register_native_fn(
+            name,
+            F::param_types(),
+            #[cfg(feature = "metadata")]
+            &F::param_names(),
+            #[cfg(feature = "metadata")]
+            Some(type_name::<crate::LazySequence>()),
+            func.into_callable_function(),
+        )
+    }
     /// Register a function of the [`Engine`].
     ///
     /// # WARNING - Low Level API
@@ -214,7 +389,8 @@ impl Engine {
     /// ```
     #[inline(always)]
     pub fn register_type_with_name<T: Variant + Clone>(&mut self, name: &str) -> &mut Self {
-        self.global_namespace_mut().set_custom_type::<T>(name);
+        self.global_namespace_mut()
+            .set_custom_type_with_id::<T>(name);
         self
     }
     /// Register a custom type for use with the [`Engine`], with a pretty-print name
@@ -234,6 +410,121 @@ impl Engine {
             .set_custom_type_raw(fully_qualified_type_path, name);
         self
     }
+    /// Register a custom type with the [`Engine`] that cannot be cloned in scripts, such as a
+    /// handle to an expensive native resource that scripts must not duplicate.
+    ///
+    /// Reading a variable holding a value of this type _moves_ the value out of the variable,
+    /// leaving `()` behind (e.g. `let y = x;` leaves `x` empty). Method calls and function calls
+    /// with the variable as the first argument do not move the value when the function takes it
+    /// by reference (`&mut`), but do move it when the function takes it by value.
+    ///
+    /// Any attempt to copy a value that cannot be moved, such as a constant, a value captured in a
+    /// closure or the `this` pointer, raises a runtime error. As a constant can neither be copied
+    /// nor modified in place, passing it to a function also raises a runtime error.
+    ///
+    /// Values are still cloned when necessary by Rust code outside of scripts (e.g. via
+    /// [`Scope::get_value`][crate::Scope::get_value]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, INT};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// struct Connection {
+    ///     id: INT,
+    /// }
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine
+    ///     .register_type_with_name::<Connection>("Connection")
+    ///     .register_type_no_clone::<Connection>()
+    ///     .register_fn("connect", |id: INT| Connection { id })
+    ///     .register_fn("query", |conn: &mut Connection| conn.id);
+    ///
+    /// // The connection is moved from 'conn' to 'other', leaving 'conn' empty
+    /// assert_eq!(
+    ///     engine.eval::<INT>("let conn = connect(42); let other = conn; other.query()")?,
+    ///     42
+    /// );
+    /// engine.eval::<()>("let conn = connect(42); let other = conn; conn")?;
+    ///
+    /// // Constants cannot be moved
+    /// assert!(engine.run("const conn = connect(42); let other = conn;").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn register_type_no_clone<T: Variant + Clone>(&mut self) -> &mut Self {
+        self.no_clone_types.insert(TypeId::of::<T>());
+        self
+    }
+    /// Register a custom type as usable for keys of a [`TypedMap`][crate::TypedMap], with functions
+    /// to compare two keys for equality and to hash a key.
+    ///
+    /// Keys that are equal must have the same hash.
+    ///
+    /// Object maps are not affected; their keys are always strings.
+    ///
+    /// Not available under `no_object`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Engine, INT};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// struct Id {
+    ///     kind: char,
+    ///     num: INT,
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// let mut engine = Engine::new();
+    ///
+    /// engine
+    ///     .register_type_with_name::<Id>("Id")
+    ///     .register_fn("id", |kind: char, num: INT| Id { kind, num })
+    ///     .register_type_as_map_key::<Id>(
+    ///         |a, b| a.kind == b.kind && a.num == b.num,
+    ///         |x| ((x.kind as u64) << 32) ^ (x.num as u64),
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     engine.eval::<String>(
+    ///         r#"
+    ///             let m = typed_map();
+    ///             m[id('a', 1)] = "first";
+    ///             m[id('b', 1)] = "second";
+    ///             m[id('a', 1)]
+    ///         "#
+    ///     )?,
+    ///     "first"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_object"))]
+    #[inline]
+    pub fn register_type_as_map_key<T: Variant + Clone>(
+        &mut self,
+        eq_fn: impl Fn(&T, &T) -> bool + SendSync + 'static,
+        hash_fn: impl Fn(&T) -> u64 + SendSync + 'static,
+    ) -> &mut Self {
+        self.map_key_types.insert(
+            TypeId::of::<T>(),
+            (
+                Box::new(move |a, b| match (a.read_lock::<T>(), b.read_lock::<T>()) {
+                    (Some(a), Some(b)) => eq_fn(&a, &b),
+                    _ => false,
+                }),
+                Box::new(move |x| x.read_lock::<T>().map_or(0, |x| hash_fn(&x))),
+            ),
+        );
+        self
+    }
     /// Register a type iterator for an iterable type with the [`Engine`].
     /// This is an advanced API.
     #[inline(always)]
@@ -262,6 +553,17 @@ impl Engine {
     ///
     /// Not available under `no_object`.
     ///
+    /// # Returning Collections
+    ///
+    /// The value returned by a getter is owned by the script, so a getter cannot return a
+    /// reference (such as `&[T]`) borrowing from `self`.
+    ///
+    /// To expose a large collection for read-only traversal without cloning it, keep it in a
+    /// [`Shared`][crate::Shared] inside the custom type and return a light-weight view type that
+    /// holds a clone of the [`Shared`][crate::Shared] pointer. Register an iterator for the view
+    /// type via [`register_iterator`][Engine::register_iterator] so that scripts can traverse it
+    /// with a `for` loop.
+    ///
     /// # Example
     ///
     /// ```
@@ -485,10 +787,93 @@ impl Engine {
 
         self.register_fn(crate::engine::FN_IDX_GET, get_fn)
     }
+    /// Register a range index getter for a custom type with the [`Engine`].
+    ///
+    /// The function is called with the start and end of the range whenever the custom type is
+    /// indexed by an exclusive range (`obj[start..end]`) or an inclusive range (`obj[start..=end]`).
+    /// The end is always exclusive, so an inclusive range `start..=end` is passed as `end + 1`.
+    ///
+    /// Index getters for scalar indices are registered separately via
+    /// [`register_indexer_get`][Engine::register_indexer_get] and continue to work alongside.
+    ///
+    /// The function signature must start with `&mut self` and not `&self`.
+    ///
+    /// Not available under both `no_index` and `no_object`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the type is [`Array`][crate::Array], [`Map`][crate::Map], [`String`],
+    /// [`ImmutableString`][crate::ImmutableString], `&str` or [`INT`][crate::INT].
+    /// Indexers for arrays, object maps, strings and integers cannot be registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[derive(Clone)]
+    /// struct TestStruct {
+    ///     fields: Vec<i64>
+    /// }
+    ///
+    /// impl TestStruct {
+    ///     fn new() -> Self {
+    ///         Self { fields: vec![1, 2, 3, 4, 5] }
+    ///     }
+    ///     fn get_fields(&mut self, start: i64, end: i64) -> i64 {
+    ///         self.fields[start as usize..end as usize].iter().sum()
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::Engine;
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// // Register API for the custom type.
+    /// # #[cfg(not(feature = "no_object"))]
+    /// engine.register_type::<TestStruct>();
+    ///
+    /// engine
+    ///     .register_fn("new_ts", TestStruct::new)
+    ///     // Register a range indexer.
+    ///     .register_indexer_range_get(TestStruct::get_fields);
+    ///
+    /// # #[cfg(not(feature = "no_index"))]
+    /// assert_eq!(engine.eval::<i64>("let a = new_ts(); a[1..3]")?, 5);
+    /// # #[cfg(not(feature = "no_index"))]
+    /// assert_eq!(engine.eval::<i64>("let a = new_ts(); a[1..=3]")?, 9);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(not(feature = "no_index"), not(feature = "no_object")))]
+    #[inline]
+    pub fn register_indexer_range_get<T: Variant + Clone, V: Variant + Clone>(
+        &mut self,
+        get_fn: impl Fn(&mut T, crate::INT, crate::INT) -> V + SendSync + 'static,
+    ) -> &mut Self {
+        let get_fn = Shared::new(get_fn);
+        let get_fn2 = get_fn.clone();
+
+        self.register_indexer_get(move |obj: &mut T, range: crate::ExclusiveRange| {
+            get_fn(obj, range.start, range.end)
+        })
+        .register_indexer_get(move |obj: &mut T, range: crate::InclusiveRange| {
+            get_fn2(obj, *range.start(), range.end().saturating_add(1))
+        })
+    }
     /// Register an index setter for a custom type with the [`Engine`].
     ///
     /// Not available under both `no_index` and `no_object`.
     ///
+    /// # Index Assignment
+    ///
+    /// The setter is called for every index assignment (e.g. `obj["key"] = 1`), whether or not
+    /// the index already exists, so a map-like type can grow by inserting new indices in the
+    /// setter.
+    ///
+    /// For a compound assignment (e.g. `obj["key"] += 1`), the index getter, if any, is called
+    /// first to get the current value. If it fails (e.g. because the index does not exist), the
+    /// right-hand side is passed unchanged to the setter.
+    ///
     /// # Panics
     ///
     /// Panics if the type is [`Array`][crate::Array], [`Map`][crate::Map], [`String`],
@@ -720,6 +1105,79 @@ impl Engine {
         register_static_module_raw(&mut self.global_sub_modules, name.as_ref(), module);
         self
     }
+    /// Register a static module namespace with the [`Engine`] whose [`Module`] is only built, by
+    /// calling the specified closure, when it is first accessed by a script.
+    ///
+    /// This speeds up creating an [`Engine`] with many expensive modules of which few are used
+    /// in each evaluation. The closure is called at most once and the module is cached.
+    ///
+    /// Not available under `no_module`.
+    ///
+    /// # Differences from [`register_static_module`][Engine::register_static_module]
+    ///
+    /// A lazy module is only reachable via its namespace (e.g. `name::func(42)`), so functions
+    /// marked [`FnNamespace::Global`][crate::FnNamespace::Global] and type iterators are _not_
+    /// exposed to scripts without namespace qualifications. It is also not included in metadata
+    /// and definitions generated from the [`Engine`].
+    ///
+    /// A static module registered under the same name takes precedence.
+    ///
+    /// The name is the root namespace and must not contain `::`.
+    ///
+    /// # Thread Safety
+    ///
+    /// Under the `sync` feature, the closure must be `Send + Sync`. The module is built while
+    /// holding a lock, so it is built only once even when first accessed by multiple threads at
+    /// the same time; the other threads wait until it is ready.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Module};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.register_lazy_module("calc", || {
+    ///     // Expensive module construction goes here...
+    ///     let mut module = Module::new();
+    ///     module.set_native_fn("inc", |x: i64| Ok(x + 1));
+    ///     module
+    /// });
+    ///
+    /// assert_eq!(engine.eval::<i64>("calc::inc(41)")?, 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_module"))]
+    #[inline]
+    pub fn register_lazy_module(
+        &mut self,
+        name: impl Into<Identifier>,
+        init: impl Fn() -> Module + SendSync + 'static,
+    ) -> &mut Self {
+        let name = name.into();
+        crate::func::locked_write(&self.lazy_modules_cache).remove(&name);
+        self.lazy_modules.insert(name, Box::new(init));
+        self
+    }
+    /// Perform all registrations queued by native functions via
+    /// [`NativeCallContext::queue_registration`][crate::NativeCallContext::queue_registration],
+    /// in the order they were queued.
+    ///
+    /// Registrations are queued because the [`Engine`] cannot be modified during an evaluation.
+    /// Call this after an evaluation returns to make them take effect.
+    ///
+    /// Returns the number of registrations performed.
+    #[inline]
+    pub fn apply_queued_registrations(&mut self) -> usize {
+        let queue = std::mem::take(&mut *crate::func::locked_write(&self.queued_registrations));
+        let count = queue.len();
+
+        queue.into_iter().for_each(|callback| callback(self));
+
+        count
+    }
     /// _(metadata)_ Generate a list of all registered functions.
     /// Exported under the `metadata` feature only.
     ///
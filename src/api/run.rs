@@ -1,8 +1,11 @@
 //! Module that defines the public evaluation API of [`Engine`].
 
+use crate::ast::{ASTFlags, Stmt};
 use crate::eval::{Caches, GlobalRuntimeState};
 use crate::parser::ParseState;
-use crate::{Engine, Module, RhaiResultOf, Scope, AST};
+use crate::types::dynamic::Variant;
+use crate::{Dynamic, Engine, Module, Position, RhaiResultOf, Scope, AST, ERR};
+use std::any::type_name;
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 
@@ -25,6 +28,71 @@ impl Engine {
     pub fn run(&self, script: &str) -> RhaiResultOf<()> {
         self.run_with_scope(&mut Scope::new(), script)
     }
+    /// Evaluate a string as a script, returning the value of the script if it ends in an
+    /// expression.
+    ///
+    /// Returns `Some` if the last statement of the script is an expression (including a function
+    /// call, with or without a terminating `;`), a `return` with a value, or an `if`, `switch` or
+    /// statements block, and [`None`] otherwise.
+    ///
+    /// A value of `()` is always returned as [`None`] (e.g. a call to `print` or an `if` without
+    /// `else` whose condition is false).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ErrorMismatchOutputType`][crate::EvalAltResult::ErrorMismatchOutputType] if the
+    /// script ends in an expression whose value is not of type `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::Engine;
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// assert_eq!(engine.run_returning::<i64>("let x = 40; x + 2")?, Some(42));
+    /// assert_eq!(engine.run_returning::<i64>("let x = 40; x + 2;")?, Some(42));
+    /// assert_eq!(engine.run_returning::<i64>("let x = 40;")?, None);
+    /// assert_eq!(engine.run_returning::<i64>("let x = 40; if x > 0 { x + 2 }")?, Some(42));
+    /// assert_eq!(engine.run_returning::<i64>("let x = 40; if x < 0 { x + 2 }")?, None);
+    /// assert_eq!(engine.run_returning::<i64>("let x = 40; print(x);")?, None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn run_returning<T: Variant + Clone>(&self, script: &str) -> RhaiResultOf<Option<T>> {
+        let scope = &mut Scope::new();
+        let ast = self.compile_with_scope_and_optimization_level(
+            scope,
+            &[script],
+            self.optimization_level,
+        )?;
+
+        let result = match ast.statements().last() {
+            Some(
+                Stmt::Expr(..)
+                | Stmt::FnCall(..)
+                | Stmt::If(..)
+                | Stmt::Switch(..)
+                | Stmt::Block(..),
+            ) => self.eval_ast_with_scope::<Dynamic>(scope, &ast)?,
+            Some(Stmt::Return(Some(..), flags, ..)) if !flags.contains(ASTFlags::BREAK) => {
+                self.eval_ast_with_scope::<Dynamic>(scope, &ast)?
+            }
+            _ => return self.run_ast_with_scope(scope, &ast).map(|_| None),
+        };
+
+        if result.is_unit() {
+            return Ok(None);
+        }
+
+        let typ = self.map_type_name(result.type_name());
+
+        result.try_cast::<T>().map(Some).ok_or_else(|| {
+            let t = self.map_type_name(type_name::<T>()).into();
+            ERR::ErrorMismatchOutputType(t, typ.into(), Position::NONE).into()
+        })
+    }
     /// Evaluate a string as a script with own scope.
     ///
     /// ## Constants Propagation
@@ -114,6 +182,7 @@ impl Engine {
         let caches = &mut Caches::new();
         let global = &mut GlobalRuntimeState::new(self);
         global.source = ast.source_raw().clone();
+        global.ast_metadata = ast.shared_metadata().cloned();
 
         #[cfg(not(feature = "no_module"))]
         {
@@ -147,6 +216,42 @@ impl Engine {
 
         Ok(())
     }
+    /// Evaluate an [`AST`] with a [`Scope`], taking ownership of the [`Scope`] and returning it
+    /// after evaluation.
+    ///
+    /// This is useful for staged evaluation where the [`Scope`] resulting from one [`AST`] is fed
+    /// into the next, making the transfer of ownership explicit.
+    ///
+    /// The [`Scope`] is dropped if an error occurs during evaluation.
+    /// Use [`run_ast_with_scope`][Engine::run_ast_with_scope] to keep it in all cases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Scope};
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let setup = engine.compile("let x = 40;")?;
+    /// let work = engine.compile("x += 2;")?;
+    ///
+    /// let scope = engine.run_ast_with_scope_returning_scope(Scope::new(), &setup)?;
+    /// let scope = engine.run_ast_with_scope_returning_scope(scope, &work)?;
+    ///
+    /// assert_eq!(scope.get_value::<i64>("x").expect("variable x should exist"), 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn run_ast_with_scope_returning_scope<'a>(
+        &self,
+        mut scope: Scope<'a>,
+        ast: &AST,
+    ) -> RhaiResultOf<Scope<'a>> {
+        self.run_ast_with_scope(&mut scope, ast)?;
+        Ok(scope)
+    }
 }
 
 /// Evaluate a string as a script.
@@ -2,14 +2,14 @@ use crate::packages::iter_basic::{BitRange, CharsStream, StepRange};
 use crate::{
     Engine, ExclusiveRange, FnPtr, ImmutableString, InclusiveRange, Position, RhaiError, ERR,
 };
-use std::any::type_name;
+use std::any::{type_name, TypeId};
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 
 /// Map the name of a standard type into a friendly form.
 #[inline]
 #[must_use]
-fn map_std_type_name(name: &str, shorthands: bool) -> &str {
+pub(crate) fn map_std_type_name(name: &str, shorthands: bool) -> &str {
     let name = name.trim();
 
     if name == type_name::<String>() {
@@ -25,6 +25,9 @@ fn map_std_type_name(name: &str, shorthands: bool) -> &str {
     if name == type_name::<&str>() {
         return if shorthands { "string" } else { "&str" };
     }
+    if name == type_name::<std::borrow::Cow<str>>() {
+        return if shorthands { "string" } else { "Cow<str>" };
+    }
     #[cfg(feature = "decimal")]
     if name == type_name::<rust_decimal::Decimal>() {
         return if shorthands { "decimal" } else { "Decimal" };
@@ -44,6 +47,10 @@ fn map_std_type_name(name: &str, shorthands: bool) -> &str {
     if name == type_name::<crate::Map>() || name == "Map" {
         return if shorthands { "map" } else { "Map" };
     }
+    #[cfg(not(feature = "no_object"))]
+    if name == type_name::<crate::TypedMap>() || name == "TypedMap" {
+        return if shorthands { "typed_map" } else { "TypedMap" };
+    }
     #[cfg(not(feature = "no_std"))]
     if name == type_name::<crate::Instant>() || name == "Instant" {
         return if shorthands { "timestamp" } else { "Instant" };
@@ -66,6 +73,16 @@ fn map_std_type_name(name: &str, shorthands: bool) -> &str {
             "RangeInclusive<i64>"
         };
     }
+    if name == type_name::<std::ops::Range<char>>() {
+        return if shorthands { "range" } else { "Range<char>" };
+    }
+    if name == type_name::<std::ops::RangeInclusive<char>>() {
+        return if shorthands {
+            "range="
+        } else {
+            "RangeInclusive<char>"
+        };
+    }
     if name == type_name::<BitRange>() {
         return if shorthands { "range" } else { "BitRange" };
     }
@@ -198,6 +215,48 @@ impl Engine {
             .unwrap_or_else(|| map_std_type_name(name, true))
     }
 
+    /// Get the display name of a custom type by its [`TypeId`].
+    ///
+    /// Only custom types registered via [`register_type_with_name`][Engine::register_type_with_name]
+    /// (or [`Module::set_custom_type_with_id`][crate::Module::set_custom_type_with_id] in a global
+    /// module) are found.
+    /// Returns [`None`] for any other type.
+    ///
+    /// This can be used together with [`Dynamic::type_id`][crate::Dynamic::type_id].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Dynamic, Engine};
+    ///
+    /// #[derive(Clone)]
+    /// struct TestStruct;
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.register_type_with_name::<TestStruct>("MyType");
+    ///
+    /// let value = Dynamic::from(TestStruct);
+    ///
+    /// assert_eq!(engine.type_name_of(value.type_id()), Some("MyType"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn type_name_of(&self, type_id: TypeId) -> Option<&str> {
+        self.global_modules
+            .iter()
+            .find_map(|m| m.get_custom_type_by_id(type_id))
+            .or_else(|| {
+                #[cfg(not(feature = "no_module"))]
+                return self
+                    .global_sub_modules
+                    .iter()
+                    .find_map(|(_, m)| m.get_custom_type_by_id(type_id));
+                #[cfg(feature = "no_module")]
+                return None;
+            })
+    }
+
     /// Format a type name.
     ///
     /// If a type is registered via [`register_type_with_name`][Engine::register_type_with_name],
@@ -245,4 +304,14 @@ impl Engine {
         let t = self.map_type_name(type_name::<T>()).into();
         ERR::ErrorMismatchDataType(t, typ.into(), pos).into()
     }
+
+    /// Make a `Box<`[`EvalAltResult<ErrorRuntime>`][ERR::ErrorRuntime]`>` for a value of a type
+    /// that cannot be cloned.
+    #[cold]
+    #[inline(never)]
+    #[must_use]
+    pub(crate) fn make_no_clone_err(&self, typ: &str, pos: Position) -> RhaiError {
+        let t = self.map_type_name(typ);
+        ERR::ErrorRuntime(format!("Value of type '{t}' cannot be cloned").into(), pos).into()
+    }
 }
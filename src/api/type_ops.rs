@@ -0,0 +1,143 @@
+//! Helper to register operators for a custom type with the [`Engine`].
+
+use crate::func::{SendSync, Shared};
+use crate::{types::dynamic::Variant, Engine, OP_EQUALS};
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+impl Engine {
+    /// Register operators for a custom type with the [`Engine`].
+    ///
+    /// Each method on the returned [`TypeOpsBuilder`] registers the appropriate operator functions,
+    /// so they do not need to be registered one by one under their exact names.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, INT};
+    ///
+    /// #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+    /// struct Money(INT);
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.register_fn("money", Money);
+    ///
+    /// engine
+    ///     .register_type_ops::<Money>()
+    ///     .add(|a, b| Money(a.0 + b.0))
+    ///     .eq(|a, b| a == b)
+    ///     .cmp(|a, b| a.cmp(b));
+    ///
+    /// assert_eq!(engine.eval::<Money>("money(40) + money(2)")?, Money(42));
+    /// assert!(engine.eval::<bool>("money(1) != money(2)")?);
+    /// assert!(engine.eval::<bool>("money(2) >= money(1)")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn register_type_ops<T: Variant + Clone>(&mut self) -> TypeOpsBuilder<T> {
+        TypeOpsBuilder {
+            engine: self,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Builder to register operators for a custom type with an [`Engine`].
+///
+/// Created by [`Engine::register_type_ops`].
+///
+/// Operators that can be derived from others are registered together, so they always stay
+/// consistent with each other:
+///
+/// * [`eq`][TypeOpsBuilder::eq] registers both `==` and `!=`.
+/// * [`cmp`][TypeOpsBuilder::cmp] registers `<`, `<=`, `>` and `>=`.
+pub struct TypeOpsBuilder<'a, T: Variant + Clone> {
+    engine: &'a mut Engine,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T: Variant + Clone> TypeOpsBuilder<'a, T> {
+    /// Register the `+` operator.
+    #[inline(always)]
+    pub fn add<R: Variant + Clone>(
+        &mut self,
+        add_fn: impl Fn(T, T) -> R + SendSync + 'static,
+    ) -> &mut Self {
+        self.engine.register_fn("+", add_fn);
+        self
+    }
+    /// Register the binary `-` operator.
+    #[inline(always)]
+    pub fn sub<R: Variant + Clone>(
+        &mut self,
+        sub_fn: impl Fn(T, T) -> R + SendSync + 'static,
+    ) -> &mut Self {
+        self.engine.register_fn("-", sub_fn);
+        self
+    }
+    /// Register the `*` operator.
+    #[inline(always)]
+    pub fn mul<R: Variant + Clone>(
+        &mut self,
+        mul_fn: impl Fn(T, T) -> R + SendSync + 'static,
+    ) -> &mut Self {
+        self.engine.register_fn("*", mul_fn);
+        self
+    }
+    /// Register the `/` operator.
+    #[inline(always)]
+    pub fn div<R: Variant + Clone>(
+        &mut self,
+        div_fn: impl Fn(T, T) -> R + SendSync + 'static,
+    ) -> &mut Self {
+        self.engine.register_fn("/", div_fn);
+        self
+    }
+    /// Register the unary `-` operator.
+    #[inline(always)]
+    pub fn neg<R: Variant + Clone>(
+        &mut self,
+        neg_fn: impl Fn(T) -> R + SendSync + 'static,
+    ) -> &mut Self {
+        self.engine.register_fn("-", neg_fn);
+        self
+    }
+    /// Register the `==` operator, plus `!=` as its negation.
+    #[inline]
+    pub fn eq(&mut self, eq_fn: impl Fn(&T, &T) -> bool + SendSync + 'static) -> &mut Self {
+        let eq_fn = Shared::new(eq_fn);
+
+        let f = eq_fn.clone();
+        self.engine
+            .register_fn(OP_EQUALS, move |a: T, b: T| f(&a, &b));
+        self.engine
+            .register_fn("!=", move |a: T, b: T| !eq_fn(&a, &b));
+        self
+    }
+    /// Register the `<`, `<=`, `>` and `>=` operators, all derived from a single comparison
+    /// function.
+    ///
+    /// `==` and `!=` are _not_ registered; use [`eq`][TypeOpsBuilder::eq] for them.
+    #[inline]
+    pub fn cmp(&mut self, cmp_fn: impl Fn(&T, &T) -> Ordering + SendSync + 'static) -> &mut Self {
+        let cmp_fn = Shared::new(cmp_fn);
+
+        let f = cmp_fn.clone();
+        self.engine
+            .register_fn("<", move |a: T, b: T| f(&a, &b) == Ordering::Less);
+        let f = cmp_fn.clone();
+        self.engine
+            .register_fn("<=", move |a: T, b: T| f(&a, &b) != Ordering::Greater);
+        let f = cmp_fn.clone();
+        self.engine
+            .register_fn(">", move |a: T, b: T| f(&a, &b) == Ordering::Greater);
+        self.engine
+            .register_fn(">=", move |a: T, b: T| cmp_fn(&a, &b) != Ordering::Less);
+        self
+    }
+}
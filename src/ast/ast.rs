@@ -5,6 +5,7 @@ use crate::{Dynamic, FnNamespace, Identifier, Position};
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 use std::{
+    collections::BTreeMap,
     fmt,
     hash::Hash,
     ops::{Add, AddAssign},
@@ -32,6 +33,10 @@ pub struct AST {
     /// Embedded module resolver, if any.
     #[cfg(not(feature = "no_module"))]
     resolver: Option<crate::Shared<crate::module::resolvers::StaticModuleResolver>>,
+    /// Custom metadata attached to the [`AST`], if any.
+    metadata: Option<crate::Shared<BTreeMap<Identifier, Dynamic>>>,
+    /// Map of positions back to the named sources the [`AST`] was compiled from, if any.
+    source_map: Option<Box<crate::SourceMap>>,
 }
 
 impl Default for AST {
@@ -52,6 +57,12 @@ impl fmt::Debug for AST {
         fp.field("doc", &self.doc);
         #[cfg(not(feature = "no_module"))]
         fp.field("resolver", &self.resolver);
+        if let Some(ref metadata) = self.metadata {
+            fp.field("metadata", metadata);
+        }
+        if let Some(ref source_map) = self.source_map {
+            fp.field("source_map", source_map);
+        }
 
         fp.field("body", &self.body.as_slice());
 
@@ -84,6 +95,8 @@ impl AST {
             lib: functions.into(),
             #[cfg(not(feature = "no_module"))]
             resolver: None,
+            metadata: None,
+            source_map: None,
         }
     }
     /// _(internals)_ Create a new [`AST`].
@@ -104,6 +117,8 @@ impl AST {
             lib: functions.into(),
             #[cfg(not(feature = "no_module"))]
             resolver: None,
+            metadata: None,
+            source_map: None,
         }
     }
     /// Create a new [`AST`] with a source name.
@@ -154,6 +169,8 @@ impl AST {
             lib: crate::Module::new().into(),
             #[cfg(not(feature = "no_module"))]
             resolver: None,
+            metadata: None,
+            source_map: None,
         }
     }
     /// Get the source, if any.
@@ -189,6 +206,94 @@ impl AST {
         self.source.clear();
         self
     }
+    /// Get the [`SourceMap`][crate::SourceMap] of the [`AST`], if it was compiled from multiple
+    /// named sources via [`Engine::compile_sources`][crate::Engine::compile_sources].
+    ///
+    /// Use it to map positions in errors back to the original sources.
+    ///
+    /// The map only describes statements compiled together. Statements merged or combined
+    /// from other [`AST`]'s are not mapped correctly.
+    #[inline(always)]
+    #[must_use]
+    pub fn source_map(&self) -> Option<&crate::SourceMap> {
+        self.source_map.as_deref()
+    }
+    /// Set the [`SourceMap`][crate::SourceMap] of the [`AST`].
+    #[inline(always)]
+    pub(crate) fn set_source_map(&mut self, source_map: crate::SourceMap) -> &mut Self {
+        self.source_map = Some(source_map.into());
+        self
+    }
+    /// Get a custom metadata value attached to the [`AST`], if any.
+    ///
+    /// During evaluation, metadata of the [`AST`] being run is also available via
+    /// [`EvalContext::ast_metadata`][crate::EvalContext::ast_metadata] (e.g. in the debugger).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::Engine;
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let mut ast = engine.compile("40 + 2")?;
+    ///
+    /// ast.set_metadata("author", "John").set_metadata("version", 3_i64);
+    ///
+    /// assert_eq!(ast.get_metadata("author").unwrap().clone().into_string().unwrap(), "John");
+    /// assert_eq!(ast.get_metadata("version").unwrap().as_int().unwrap(), 3);
+    /// assert!(ast.get_metadata("path").is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_metadata(&self, key: &str) -> Option<&Dynamic> {
+        self.metadata.as_ref().and_then(|m| m.get(key))
+    }
+    /// Attach a custom metadata value to the [`AST`], replacing any existing value under the
+    /// same key.
+    ///
+    /// Metadata travels with the [`AST`] when it is cloned, merged or combined.
+    #[inline]
+    pub fn set_metadata(
+        &mut self,
+        key: impl Into<Identifier>,
+        value: impl Into<Dynamic>,
+    ) -> &mut Self {
+        crate::func::shared_make_mut(self.metadata.get_or_insert_with(Default::default))
+            .insert(key.into(), value.into());
+        self
+    }
+    /// Remove a custom metadata value from the [`AST`], returning it if it exists.
+    #[inline]
+    pub fn remove_metadata(&mut self, key: &str) -> Option<Dynamic> {
+        self.metadata
+            .as_mut()
+            .and_then(|m| crate::func::shared_make_mut(m).remove(key))
+    }
+    /// Remove all custom metadata from the [`AST`].
+    #[inline(always)]
+    pub fn clear_metadata(&mut self) -> &mut Self {
+        self.metadata = None;
+        self
+    }
+    /// Iterate through all custom metadata attached to the [`AST`], in sorted key order.
+    #[inline]
+    pub fn iter_metadata(&self) -> impl Iterator<Item = (&str, &Dynamic)> {
+        self.metadata
+            .iter()
+            .flat_map(|m| m.iter().map(|(k, v)| (k.as_str(), v)))
+    }
+    /// Get the shared custom metadata of the [`AST`], if any.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) const fn shared_metadata(
+        &self,
+    ) -> Option<&crate::Shared<BTreeMap<Identifier, Dynamic>>> {
+        self.metadata.as_ref()
+    }
     /// Get the documentation (if any).
     /// Exported under the `metadata` feature only.
     ///
@@ -345,6 +450,8 @@ impl AST {
             lib: lib.into(),
             #[cfg(not(feature = "no_module"))]
             resolver: self.resolver.clone(),
+            metadata: self.metadata.clone(),
+            source_map: self.source_map.clone(),
         }
     }
     /// Clone the [`AST`]'s script statements into a new [`AST`].
@@ -361,6 +468,8 @@ impl AST {
             lib: crate::Module::new().into(),
             #[cfg(not(feature = "no_module"))]
             resolver: self.resolver.clone(),
+            metadata: self.metadata.clone(),
+            source_map: self.source_map.clone(),
         }
     }
     /// Merge two [`AST`] into one.  Both [`AST`]'s are untouched and a new, merged,
@@ -469,6 +578,64 @@ impl AST {
     pub fn combine(&mut self, other: Self) -> &mut Self {
         self.combine_filtered_impl(other, |_, _, _, _, _| true)
     }
+    /// Combine one [`AST`] with another, failing if any function would be overwritten.
+    /// The second [`AST`] is consumed.
+    ///
+    /// This is the same as [`combine`][AST::combine], except that a script-defined function in
+    /// the second [`AST`] with the same name and number of parameters as one in the first [`AST`]
+    /// is an error instead of silently overwriting it. Nothing is combined if there is an error.
+    ///
+    /// This is useful for combining a cached prelude with user scripts that must not redefine any
+    /// of the prelude's functions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseErrorType::FnDuplicatedDefinition`][crate::ParseErrorType::FnDuplicatedDefinition]
+    /// for the first duplicated function found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// # #[cfg(not(feature = "no_function"))]
+    /// # {
+    /// use rhai::{Engine, ParseErrorType};
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let mut prelude = engine.compile("fn foo(x) { 42 + x }")?;
+    ///
+    /// let script = engine.compile("fn foo(n) { n } foo(1)")?;
+    ///
+    /// let err = prelude.try_combine(script).expect_err("should error");
+    ///
+    /// assert_eq!(*err.err_type(), ParseErrorType::FnDuplicatedDefinition("foo".into(), 1));
+    ///
+    /// let script = engine.compile("fn bar() { 1 } foo(bar())")?;
+    ///
+    /// prelude.try_combine(script)?;
+    ///
+    /// assert_eq!(engine.eval_ast::<i64>(&prelude)?, 43);
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_combine(&mut self, other: Self) -> Result<&mut Self, crate::ParseError> {
+        #[cfg(not(feature = "no_function"))]
+        if let Some((.., name, num_params)) = other
+            .lib
+            .iter_script_fn_info()
+            .find(|&(.., name, num_params)| self.lib.get_script_fn(name, num_params).is_some())
+        {
+            return Err(
+                crate::PERR::FnDuplicatedDefinition(name.to_string(), num_params)
+                    .into_err(Position::NONE),
+            );
+        }
+
+        Ok(self.combine(other))
+    }
     /// Merge two [`AST`] into one.  Both [`AST`]'s are untouched and a new, merged, version
     /// is returned.
     ///
@@ -594,6 +761,20 @@ impl AST {
             }
         }
 
+        _ast.source_map = self.source_map.clone();
+
+        match (self.metadata.as_ref(), other.metadata.as_ref()) {
+            (None, None) => (),
+            (Some(metadata), None) | (None, Some(metadata)) => {
+                _ast.metadata = Some(metadata.clone());
+            }
+            (Some(metadata), Some(other_metadata)) => {
+                let mut metadata = metadata.as_ref().clone();
+                metadata.extend(other_metadata.iter().map(|(k, v)| (k.clone(), v.clone())));
+                _ast.metadata = Some(metadata.into());
+            }
+        }
+
         #[cfg(feature = "metadata")]
         if !other.doc.is_empty() {
             if !_ast.doc.is_empty() {
@@ -695,6 +876,14 @@ impl AST {
             crate::func::shared_make_mut(&mut self.lib).merge_filtered(&other.lib, &_filter);
         }
 
+        if let Some(other_metadata) = other.metadata {
+            match self.metadata {
+                Some(ref mut metadata) => crate::func::shared_make_mut(metadata)
+                    .extend(crate::func::shared_take_or_clone(other_metadata)),
+                None => self.metadata = Some(other_metadata),
+            }
+        }
+
         #[cfg(feature = "metadata")]
         if !other.doc.is_empty() {
             if !self.doc.is_empty() {
@@ -765,9 +954,38 @@ impl AST {
             .iter_script_fn()
             .map(|(.., fn_def)| fn_def.as_ref())
     }
-    /// Iterate through all function definitions.
+    /// Iterate through all function definitions, returning the [metadata][super::ScriptFnMetadata]
+    /// (name, parameter names, access mode and doc-comments) of each function.
+    ///
+    /// This allows discovering the functions defined in a script without running it.
+    /// To both evaluate a script and inspect its functions, compile it into an [`AST`] first
+    /// and then evaluate it via [`Engine::eval_ast`][crate::Engine::eval_ast].
     ///
     /// Not available under `no_function`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, FnAccess};
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let ast = engine.compile("fn on_click(x, y) { x + y } private fn helper() { 42 }")?;
+    ///
+    /// let mut functions: Vec<_> = ast.iter_functions().collect();
+    /// functions.sort();
+    ///
+    /// assert_eq!(functions[0].name, "helper");
+    /// assert_eq!(functions[0].access, FnAccess::Private);
+    ///
+    /// assert_eq!(functions[1].name, "on_click");
+    /// assert_eq!(functions[1].params, vec!["x", "y"]);
+    /// assert_eq!(functions[1].access, FnAccess::Public);
+    /// assert_eq!(functions[1].to_string(), "on_click(x, y)");
+    /// # Ok(())
+    /// # }
+    /// ```
     #[cfg(not(feature = "no_function"))]
     #[inline]
     pub fn iter_functions<'a>(&'a self) -> impl Iterator<Item = super::ScriptFnMetadata> + 'a {
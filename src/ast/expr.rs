@@ -959,7 +959,7 @@ impl Expr {
                     return false;
                 }
             }
-            Self::FnCall(x, ..) => {
+            Self::FnCall(x, ..) | Self::MethodCall(x, ..) => {
                 for e in &x.args {
                     if !e.walk(path, on_node) {
                         return false;
@@ -93,6 +93,21 @@ impl OpAssignment {
             pos,
         )
     }
+    /// Create a new [`OpAssignment`] for a custom operator (e.g. `#=` for the custom operator `#`).
+    #[cfg(not(feature = "no_custom_syntax"))]
+    #[must_use]
+    pub(crate) fn new_custom_op_assignment(op: &str, pos: Position) -> Self {
+        let mut op_assign = crate::Identifier::from(op);
+        op_assign.push('=');
+
+        Self {
+            hash_op_assign: calc_fn_hash(None, &op_assign, 2),
+            hash_op: calc_fn_hash(None, op, 2),
+            op_assign: Token::Custom(op_assign.into()),
+            op: Token::Custom(Box::new(op.into())),
+            pos,
+        }
+    }
     /// Convert a [`Token`] into a new [`OpAssignment`].
     ///
     /// # Panics
@@ -289,6 +304,62 @@ pub struct SwitchCasesCollection {
     pub def_case: Option<usize>,
 }
 
+/// _(internals)_ A pattern in an arm of a `match` statement.
+/// Exported under the `internals` feature only.
+#[derive(Debug, Clone, Hash)]
+pub enum MatchPattern {
+    /// `_` - matches any value.
+    Wildcard,
+    /// `name` - matches any value, binding it to a variable.
+    Bind(Ident),
+    /// Literal value, matched in the same way as a `switch` case.
+    Literal(crate::Dynamic),
+    /// Integer range.
+    Range(RangeCase),
+    /// `[` pattern `,` ... `,` `..` `]`
+    ///
+    /// If the flag is `true`, the array pattern ends with `..` and matches arrays with extra
+    /// elements.
+    #[cfg(not(feature = "no_index"))]
+    Array(StaticVec<MatchPattern>, bool),
+    /// `#{` key `:` pattern `,` ... `}`
+    ///
+    /// Properties not named in the pattern are ignored.
+    #[cfg(not(feature = "no_object"))]
+    Map(StaticVec<(Ident, MatchPattern)>),
+}
+
+impl MatchPattern {
+    /// Does this pattern match every value?
+    #[must_use]
+    pub fn is_irrefutable(&self) -> bool {
+        matches!(self, Self::Wildcard | Self::Bind(..))
+    }
+    /// Call a function on each variable bound by this pattern, in order.
+    pub fn for_each_binding<'a>(&'a self, f: &mut impl FnMut(&'a Ident)) {
+        match self {
+            Self::Wildcard | Self::Literal(..) | Self::Range(..) => (),
+            Self::Bind(name) => f(name),
+            #[cfg(not(feature = "no_index"))]
+            Self::Array(x, ..) => x.iter().for_each(|p| p.for_each_binding(f)),
+            #[cfg(not(feature = "no_object"))]
+            Self::Map(x) => x.iter().for_each(|(.., p)| p.for_each_binding(f)),
+        }
+    }
+}
+
+/// _(internals)_ An arm of a `match` statement.
+/// Exported under the `internals` feature only.
+#[derive(Debug, Clone, Hash)]
+pub struct MatchArm {
+    /// Pattern to match.
+    pub pattern: MatchPattern,
+    /// Guard condition and action.
+    ///
+    /// The condition is [`Expr::BoolConstant`] with `true` if there is no `if` guard.
+    pub body: ConditionalExpr,
+}
+
 /// _(internals)_ A `try-catch` block.
 /// Exported under the `internals` feature only.
 #[derive(Debug, Clone, Hash)]
@@ -536,6 +607,8 @@ pub enum Stmt {
     /// 1) Default block
     /// 2) List of ranges: (start, end, inclusive, condition, statement)
     Switch(Box<(Expr, SwitchCasesCollection)>, Position),
+    /// `match` expr `{` pattern `if` condition `=>` stmt `,` ... `}`
+    Match(Box<(Expr, StaticVec<MatchArm>)>, Position),
     /// `while` expr `{` stmt `}` | `loop` `{` stmt `}`
     ///
     /// If the guard expression is [`UNIT`][Expr::Unit], then it is a `loop` statement.
@@ -569,13 +642,13 @@ pub enum Stmt {
     TryCatch(Box<TryCatchBlock>, Position),
     /// [expression][Expr]
     Expr(Box<Expr>),
-    /// `continue`/`break`
+    /// `continue`/`break` `expr`
     ///
     /// ### Flags
     ///
     /// * [`NONE`][ASTFlags::NONE] = `continue`
     /// * [`BREAK`][ASTFlags::BREAK] = `break`
-    BreakLoop(ASTFlags, Position),
+    BreakLoop(Option<Box<Expr>>, ASTFlags, Position),
     /// `return`/`throw`
     ///
     /// ### Flags
@@ -649,6 +722,7 @@ impl Stmt {
             | Self::FnCall(.., pos)
             | Self::If(.., pos)
             | Self::Switch(.., pos)
+            | Self::Match(.., pos)
             | Self::While(.., pos)
             | Self::Do(.., pos)
             | Self::For(.., pos)
@@ -679,6 +753,7 @@ impl Stmt {
             | Self::FnCall(.., pos)
             | Self::If(.., pos)
             | Self::Switch(.., pos)
+            | Self::Match(.., pos)
             | Self::While(.., pos)
             | Self::Do(.., pos)
             | Self::For(.., pos)
@@ -711,6 +786,7 @@ impl Stmt {
         match self {
             Self::If(..)
             | Self::Switch(..)
+            | Self::Match(..)
             | Self::Block(..)
             | Self::Expr(..)
             | Self::FnCall(..) => true,
@@ -736,6 +812,7 @@ impl Stmt {
         match self {
             Self::If(..)
             | Self::Switch(..)
+            | Self::Match(..)
             | Self::While(..)
             | Self::For(..)
             | Self::Block(..)
@@ -789,6 +866,17 @@ impl Stmt {
                     && sw.def_case.is_some()
                     && sw.expressions[sw.def_case.unwrap()].expr.is_pure()
             }
+            Self::Match(x, ..) => {
+                let (expr, arms) = &**x;
+                expr.is_pure()
+                    && arms
+                        .iter()
+                        .all(|arm| arm.body.condition.is_pure() && arm.body.expr.is_pure())
+                    && arms.iter().any(|arm| {
+                        arm.pattern.is_irrefutable()
+                            && matches!(arm.body.condition, Expr::BoolConstant(true, ..))
+                    })
+            }
 
             // Loops that exit can be pure because it can never be infinite.
             Self::While(x, ..) if matches!(x.0, Expr::BoolConstant(false, ..)) => true,
@@ -956,6 +1044,21 @@ impl Stmt {
                     }
                 }
             }
+            Self::Match(x, ..) => {
+                let (expr, arms) = &**x;
+
+                if !expr.walk(path, on_node) {
+                    return false;
+                }
+                for arm in arms {
+                    if !arm.body.condition.walk(path, on_node) {
+                        return false;
+                    }
+                    if !arm.body.expr.walk(path, on_node) {
+                        return false;
+                    }
+                }
+            }
             Self::While(x, ..) | Self::Do(x, ..) => {
                 if !x.0.walk(path, on_node) {
                     return false;
@@ -1015,7 +1118,7 @@ impl Stmt {
                     return false;
                 }
             }
-            Self::Return(Some(e), ..) => {
+            Self::BreakLoop(Some(e), ..) | Self::Return(Some(e), ..) => {
                 if !e.walk(path, on_node) {
                     return false;
                 }
@@ -2,8 +2,9 @@
 
 use crate::api::options::LangOptions;
 use crate::func::native::{
-    locked_write, OnDebugCallback, OnDefVarCallback, OnParseTokenCallback, OnPrintCallback,
-    OnVarCallback,
+    locked_read, locked_write, OnDebugCallback, OnDefVarCallback, OnFnCallCallback, OnLogCallback,
+    OnMissingFnCallback, OnParseNumberCallback, OnParseTokenCallback, OnPrintCallback,
+    OnRegisterCallback, OnVarCallback,
 };
 use crate::packages::{Package, StandardPackage};
 use crate::tokenizer::Token;
@@ -96,6 +97,13 @@ pub struct Engine {
     /// A collection of all sub-modules directly loaded into the Engine.
     #[cfg(not(feature = "no_module"))]
     pub(crate) global_sub_modules: std::collections::BTreeMap<Identifier, Shared<Module>>,
+    /// Callbacks to build sub-modules lazily, on first access.
+    #[cfg(not(feature = "no_module"))]
+    pub(crate) lazy_modules:
+        std::collections::BTreeMap<Identifier, Box<crate::func::native::OnLazyModuleCallback>>,
+    /// Sub-modules already built from lazy module callbacks.
+    #[cfg(not(feature = "no_module"))]
+    pub(crate) lazy_modules_cache: Locked<std::collections::BTreeMap<Identifier, Shared<Module>>>,
 
     /// A module resolution service.
     #[cfg(not(feature = "no_module"))]
@@ -104,8 +112,19 @@ pub struct Engine {
     /// An empty [`ImmutableString`] for cloning purposes.
     pub(crate) interned_strings: Locked<StringsInterner<'static>>,
 
+    /// A cache of compiled regular expressions.
+    #[cfg(feature = "regex")]
+    pub(crate) regex_cache: Locked<std::collections::BTreeMap<Identifier, regex::Regex>>,
+
+    /// Registrations queued by native functions during evaluation.
+    pub(crate) queued_registrations: Locked<Vec<Box<OnRegisterCallback>>>,
+
     /// A set of symbols to disable.
     pub(crate) disabled_symbols: BTreeSet<Identifier>,
+    /// A set of identifiers reserved as keywords.
+    pub(crate) reserved_keywords: BTreeSet<Identifier>,
+    /// A set of script flags that are turned on, for use in `#if` directives.
+    pub(crate) script_flags: BTreeSet<Identifier>,
     /// A map containing custom keywords and precedence to recognize.
     #[cfg(not(feature = "no_custom_syntax"))]
     pub(crate) custom_keywords: std::collections::BTreeMap<Identifier, Option<Precedence>>,
@@ -113,29 +132,63 @@ pub struct Engine {
     #[cfg(not(feature = "no_custom_syntax"))]
     pub(crate) custom_syntax:
         std::collections::BTreeMap<Identifier, crate::api::custom_syntax::CustomSyntax>,
+    /// Custom types that cannot be cloned in scripts.
+    pub(crate) no_clone_types: BTreeSet<std::any::TypeId>,
+    /// Equality and hashing functions of custom types usable as keys of a [`TypedMap`][crate::TypedMap].
+    #[cfg(not(feature = "no_object"))]
+    pub(crate) map_key_types: std::collections::BTreeMap<
+        std::any::TypeId,
+        (
+            Box<crate::func::native::OnMapKeyEqCallback>,
+            Box<crate::func::native::OnMapKeyHashCallback>,
+        ),
+    >,
     /// Callback closure for filtering variable definition.
     pub(crate) def_var_filter: Option<Box<OnDefVarCallback>>,
     /// Callback closure for resolving variable access.
     pub(crate) resolve_var: Option<Box<OnVarCallback>>,
+    /// Callback closure for intercepting function calls.
+    pub(crate) fn_call_interceptor: Option<Box<OnFnCallCallback>>,
+    /// Callback closure for handling calls to functions that are not found.
+    pub(crate) missing_fn_handler: Option<Box<OnMissingFnCallback>>,
     /// Callback closure to remap tokens during parsing.
     pub(crate) token_mapper: Option<Box<OnParseTokenCallback>>,
+    /// Callback closure to parse number literals during parsing.
+    pub(crate) number_parser: Option<Box<OnParseNumberCallback>>,
 
     /// Callback closure for implementing the `print` command.
     pub(crate) print: Box<OnPrintCallback>,
     /// Callback closure for implementing the `debug` command.
     pub(crate) debug: Box<OnDebugCallback>,
+    /// Callback closure for implementing the `log` function.
+    pub(crate) log: Box<OnLogCallback>,
     /// Callback closure for progress reporting.
     #[cfg(not(feature = "unchecked"))]
     pub(crate) progress: Option<Box<crate::func::native::OnProgressCallback>>,
 
     /// Language options.
     pub(crate) options: LangOptions,
+    /// Number of spaces per level of indentation when pretty-printing in `debug`.
+    pub(crate) debug_indent: usize,
 
     /// Default value for the custom state.
     pub(crate) def_tag: Dynamic,
+    /// Default tag for newly-created values.
+    pub(crate) def_value_tag: crate::types::dynamic::Tag,
 
     /// Script optimization level.
     pub(crate) optimization_level: OptimizationLevel,
+    /// Names of script-defined functions whose bodies are not optimized.
+    #[cfg(not(feature = "no_optimize"))]
+    #[cfg(not(feature = "no_function"))]
+    pub(crate) optimize_exclusions: BTreeSet<Identifier>,
+    /// Fold constant array and object map literals into constants?
+    #[cfg(not(feature = "no_optimize"))]
+    pub(crate) optimize_constant_array_folding: bool,
+    /// Remove unreachable private script-defined functions at [`OptimizationLevel::Full`]?
+    #[cfg(not(feature = "no_optimize"))]
+    #[cfg(not(feature = "no_function"))]
+    pub(crate) optimize_remove_unused_functions: bool,
 
     /// Max limits.
     #[cfg(not(feature = "unchecked"))]
@@ -159,8 +212,15 @@ impl fmt::Debug for Engine {
 
         #[cfg(not(feature = "no_module"))]
         f.field("global_sub_modules", &self.global_sub_modules);
+        #[cfg(not(feature = "no_module"))]
+        f.field(
+            "lazy_modules",
+            &self.lazy_modules.keys().collect::<Vec<_>>(),
+        );
 
         f.field("disabled_symbols", &self.disabled_symbols);
+        f.field("reserved_keywords", &self.reserved_keywords);
+        f.field("script_flags", &self.script_flags);
 
         #[cfg(not(feature = "no_custom_syntax"))]
         f.field("custom_keywords", &self.custom_keywords).field(
@@ -174,7 +234,10 @@ impl fmt::Debug for Engine {
 
         f.field("def_var_filter", &self.def_var_filter.is_some())
             .field("resolve_var", &self.resolve_var.is_some())
-            .field("token_mapper", &self.token_mapper.is_some());
+            .field("fn_call_interceptor", &self.fn_call_interceptor.is_some())
+            .field("missing_fn_handler", &self.missing_fn_handler.is_some())
+            .field("token_mapper", &self.token_mapper.is_some())
+            .field("number_parser", &self.number_parser.is_some());
 
         #[cfg(not(feature = "unchecked"))]
         f.field("progress", &self.progress.is_some());
@@ -249,6 +312,7 @@ impl Engine {
                     |source| println!("{source} @ {pos:?} | {s}"),
                 )
             });
+            engine.log = Box::new(|level, s| println!("[{level}] {s}"));
         }
 
         engine.register_global_module(StandardPackage::new().as_shared_module());
@@ -267,35 +331,61 @@ impl Engine {
 
             #[cfg(not(feature = "no_module"))]
             global_sub_modules: std::collections::BTreeMap::new(),
+            #[cfg(not(feature = "no_module"))]
+            lazy_modules: std::collections::BTreeMap::new(),
+            #[cfg(not(feature = "no_module"))]
+            lazy_modules_cache: std::collections::BTreeMap::new().into(),
 
             #[cfg(not(feature = "no_module"))]
             module_resolver: Box::new(crate::module::resolvers::DummyModuleResolver::new()),
 
             interned_strings: StringsInterner::new().into(),
+            #[cfg(feature = "regex")]
+            regex_cache: std::collections::BTreeMap::new().into(),
+            queued_registrations: Vec::new().into(),
             disabled_symbols: BTreeSet::new(),
+            reserved_keywords: BTreeSet::new(),
+            script_flags: BTreeSet::new(),
             #[cfg(not(feature = "no_custom_syntax"))]
             custom_keywords: std::collections::BTreeMap::new(),
             #[cfg(not(feature = "no_custom_syntax"))]
             custom_syntax: std::collections::BTreeMap::new(),
 
+            no_clone_types: BTreeSet::new(),
+            #[cfg(not(feature = "no_object"))]
+            map_key_types: std::collections::BTreeMap::new(),
             def_var_filter: None,
             resolve_var: None,
+            fn_call_interceptor: None,
+            missing_fn_handler: None,
             token_mapper: None,
+            number_parser: None,
 
             print: Box::new(|_| {}),
             debug: Box::new(|_, _, _| {}),
+            log: Box::new(|_, _| {}),
 
             #[cfg(not(feature = "unchecked"))]
             progress: None,
 
             options: LangOptions::new(),
+            debug_indent: 4,
 
             def_tag: Dynamic::UNIT,
+            def_value_tag: 0,
 
             #[cfg(not(feature = "no_optimize"))]
             optimization_level: OptimizationLevel::Simple,
             #[cfg(feature = "no_optimize")]
             optimization_level: (),
+            #[cfg(not(feature = "no_optimize"))]
+            #[cfg(not(feature = "no_function"))]
+            optimize_exclusions: BTreeSet::new(),
+            #[cfg(not(feature = "no_optimize"))]
+            optimize_constant_array_folding: true,
+            #[cfg(not(feature = "no_optimize"))]
+            #[cfg(not(feature = "no_function"))]
+            optimize_remove_unused_functions: false,
 
             #[cfg(not(feature = "unchecked"))]
             limits: crate::api::limits::Limits::new(),
@@ -312,6 +402,30 @@ impl Engine {
         engine
     }
 
+    /// Get a lazily-initialized sub-module, building it on first access.
+    ///
+    /// The module is built while holding the cache lock, so it is built at most once even when
+    /// accessed from multiple threads at the same time.
+    #[cfg(not(feature = "no_module"))]
+    #[must_use]
+    pub(crate) fn get_lazy_module(&self, name: &str) -> Option<Shared<Module>> {
+        let init = self.lazy_modules.get(name)?;
+
+        if let Some(module) = locked_read(&self.lazy_modules_cache).get(name) {
+            return Some(module.clone());
+        }
+
+        let mut cache = locked_write(&self.lazy_modules_cache);
+
+        let module = cache.entry(name.into()).or_insert_with(|| {
+            let mut module = init();
+            module.build_index();
+            module.into()
+        });
+
+        Some(module.clone())
+    }
+
     /// Get an interned [string][ImmutableString].
     #[cfg(not(feature = "internals"))]
     #[inline(always)]
@@ -338,6 +452,58 @@ impl Engine {
         locked_write(&self.interned_strings).get(string)
     }
 
+    /// Intern an identifier (e.g. a property or function name) into the [`Engine`], returning a
+    /// shared [`ImmutableString`].
+    ///
+    /// The same cache is used when compiling scripts, so identifiers that are repeated across many
+    /// compilations share a single allocation.
+    ///
+    /// Long identifiers are only cached on their second use, and the least-used entries are evicted
+    /// when the cache is full.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::Engine;
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let x = engine.intern_identifier("hello");
+    /// let y = engine.intern_identifier("hello");
+    ///
+    /// assert!(x.ptr_eq(&y));
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn intern_identifier(&self, name: &str) -> ImmutableString {
+        locked_write(&self.interned_strings).get(name)
+    }
+    /// Get the number of strings currently interned in the [`Engine`].
+    #[inline(always)]
+    #[must_use]
+    pub fn num_interned_strings(&self) -> usize {
+        locked_read(&self.interned_strings).len()
+    }
+    /// Get a copy of the [`Engine`]'s interned strings for use by the parser.
+    ///
+    /// The [`Engine`]'s own interned strings are left in place, so that they remain available
+    /// to other threads parsing at the same time.
+    ///
+    /// They should be merged back via [`restore_interned_strings`][Engine::restore_interned_strings]
+    /// once parsing is done.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn copy_interned_strings(&self) -> StringsInterner<'static> {
+        locked_read(&self.interned_strings).clone()
+    }
+    /// Merge strings interned by the parser back into the [`Engine`].
+    ///
+    /// Strings interned into the [`Engine`] in the meantime are kept, subject to its capacity.
+    #[inline(always)]
+    pub(crate) fn restore_interned_strings(&self, strings: StringsInterner) {
+        locked_write(&self.interned_strings).merge(strings);
+    }
+
     /// Get an empty [`ImmutableString`] which refers to a shared instance.
     #[inline(always)]
     #[must_use]
@@ -345,6 +511,14 @@ impl Engine {
         self.get_interned_string("")
     }
 
+    /// Stamp a newly-created value with the default value tag, unless it already carries a tag.
+    #[inline(always)]
+    pub(crate) fn stamp_value_tag(&self, value: &mut Dynamic) {
+        if self.def_value_tag != 0 && value.tag() == 0 {
+            value.set_tag(self.def_value_tag);
+        }
+    }
+
     /// Check a result to ensure that it is valid.
     #[inline]
     pub(crate) fn check_return_value(&self, result: RhaiResult, _pos: Position) -> RhaiResult {
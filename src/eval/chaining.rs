@@ -94,6 +94,11 @@ impl Engine {
                                 Ok((result, true)) if is_obj_temp_val => {
                                     (Some(obj.take_or_clone()), (result, true))
                                 }
+                                // Write back changes made to a copied part of the value (e.g. a BLOB range)
+                                Ok((result, true)) => {
+                                    obj.propagate_changed_value(idx_pos)?;
+                                    (None, (result, true))
+                                }
                                 Ok(result) => (None, result),
                                 Err(err) => return Err(err.fill_position(*x_pos)),
                             }
@@ -131,8 +136,9 @@ impl Engine {
                                 self.eval_op_assignment(
                                     global, caches, lib, op_info, obj_ptr, root, new_val, level,
                                 )?;
+                                // Check the source, which may have grown (e.g. a BLOB range)
                                 #[cfg(not(feature = "unchecked"))]
-                                self.check_data_size(obj_ptr, op_info.pos)?;
+                                self.check_data_size(obj_ptr.source(), op_info.pos)?;
                                 None
                             }
                             // Indexed value cannot be referenced - use indexer
@@ -616,15 +622,47 @@ impl Engine {
                 let obj_ptr = &mut target;
                 let root = (x.3.as_str(), *var_pos);
 
-                self.eval_dot_index_chain_helper(
+                let result = self.eval_dot_index_chain_helper(
                     global, caches, lib, &mut None, obj_ptr, root, expr, rhs, options, idx_values,
                     chain_type, level, new_val,
-                )
+                );
+
+                // Check the data size of the whole variable if it may have grown via a nested
+                // element (e.g. `x[0].push(42)` or `x.a.b = 42`).
+                // Changes to the variable itself are already checked where they are made.
+                #[cfg(not(feature = "unchecked"))]
+                if matches!(result, Ok((_, true)))
+                    && !options.contains(ASTFlags::BREAK)
+                    && is_nested_chain(rhs)
+                {
+                    self.check_data_size(target.source(), *var_pos)?;
+                }
+
+                result
             }
             // {expr}.??? = ??? or {expr}[???] = ???
             _ if new_val.is_some() => unreachable!("cannot assign to an expression"),
             // {expr}.??? or {expr}[???]
             expr => {
+                // {constant}[idx] - read the item directly without copying the whole constant
+                #[cfg(not(feature = "no_index"))]
+                if let Expr::DynamicConstant(value, ..) = expr {
+                    if chain_type == ChainType::Indexing
+                        && idx_values.len() == 1
+                        && is_terminal_index(rhs)
+                    {
+                        if let Some(item) = get_constant_item(value, &idx_values[0]) {
+                            #[cfg(feature = "debugging")]
+                            self.run_debugger(scope, global, lib, this_ptr, expr, level)?;
+
+                            #[cfg(not(feature = "unchecked"))]
+                            self.inc_operations(&mut global.num_operations, rhs.start_position())?;
+
+                            return Ok(item);
+                        }
+                    }
+                }
+
                 let value = self
                     .eval_expr(scope, global, caches, lib, this_ptr, expr, level)?
                     .flatten();
@@ -833,6 +871,42 @@ impl Engine {
                 Ok(arr.get_mut(arr_idx).map(Target::from).unwrap())
             }
 
+            #[cfg(not(feature = "no_index"))]
+            Dynamic(Union::Blob(arr, ..))
+                if idx.is::<crate::ExclusiveRange>() || idx.is::<crate::InclusiveRange>() =>
+            {
+                // val_blob[range]
+                let (start, end, end_idx) =
+                    if let Some(range) = idx.read_lock::<crate::ExclusiveRange>() {
+                        (range.start, range.end, range.end)
+                    } else if let Some(range) = idx.read_lock::<crate::InclusiveRange>() {
+                        let end = *range.end();
+                        (*range.start(), end.saturating_add(1), end)
+                    } else {
+                        unreachable!("Range or RangeInclusive expected but gets {:?}", idx);
+                    };
+
+                let len = arr.len();
+
+                if start < 0 || start > len as crate::INT {
+                    return Err(ERR::ErrorArrayBounds(len, start, idx_pos).into());
+                }
+                if end < 0 || end > len as crate::INT {
+                    return Err(ERR::ErrorArrayBounds(len, end_idx, idx_pos).into());
+                }
+
+                let start = start as usize;
+                let end = usize::max(start, end as usize);
+                let value = Dynamic::from_blob(arr[start..end].to_vec());
+
+                Ok(Target::BlobRange {
+                    source: target,
+                    value,
+                    start,
+                    end,
+                })
+            }
+
             #[cfg(not(feature = "no_index"))]
             Dynamic(Union::Blob(arr, ..)) => {
                 // val_blob[idx]
@@ -1033,3 +1107,46 @@ impl Engine {
         }
     }
 }
+
+/// Does a chain continue into a nested element?
+#[cfg(not(feature = "unchecked"))]
+#[inline]
+fn is_nested_chain(rhs: &Expr) -> bool {
+    match rhs {
+        #[cfg(not(feature = "no_index"))]
+        Expr::Index(..) => true,
+        #[cfg(not(feature = "no_object"))]
+        Expr::Dot(..) => true,
+        _ => false,
+    }
+}
+
+/// Is an index expression the last one in a chain?
+#[cfg(not(feature = "no_index"))]
+#[inline]
+fn is_terminal_index(rhs: &Expr) -> bool {
+    match rhs {
+        Expr::Index(..) => false,
+        #[cfg(not(feature = "no_object"))]
+        Expr::Dot(..) => false,
+        _ => true,
+    }
+}
+
+/// Get a copy of an item inside a constant array or object map without copying the container.
+///
+/// Returns [`None`] if the item does not exist or cannot be read directly, in which case the
+/// normal indexing rules (including errors) apply.
+#[cfg(not(feature = "no_index"))]
+fn get_constant_item(value: &Dynamic, idx: &Dynamic) -> Option<Dynamic> {
+    match (&value.0, &idx.0) {
+        (Union::Array(arr, ..), Union::Int(index, ..)) => {
+            super::calc_index(arr.len(), *index, true, || Err(()))
+                .ok()
+                .map(|i| arr[i].clone())
+        }
+        #[cfg(not(feature = "no_object"))]
+        (Union::Map(map, ..), Union::Str(key, ..)) => map.get(key.as_str()).cloned(),
+        _ => None,
+    }
+}
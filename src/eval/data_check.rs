@@ -101,7 +101,7 @@ impl Engine {
             .max_string_size
             .map_or(usize::MAX, NonZeroUsize::get)
         {
-            return Err(ERR::ErrorDataTooLarge("Length of string".to_string(), pos).into());
+            return Err(ERR::ErrorDataTooLarge(format!("Length of string ({s})"), pos).into());
         }
 
         #[cfg(not(feature = "no_index"))]
@@ -111,7 +111,7 @@ impl Engine {
                 .max_array_size
                 .map_or(usize::MAX, NonZeroUsize::get)
         {
-            return Err(ERR::ErrorDataTooLarge("Size of array".to_string(), pos).into());
+            return Err(ERR::ErrorDataTooLarge(format!("Size of array ({_arr})"), pos).into());
         }
 
         #[cfg(not(feature = "no_object"))]
@@ -121,7 +121,7 @@ impl Engine {
                 .max_map_size
                 .map_or(usize::MAX, NonZeroUsize::get)
         {
-            return Err(ERR::ErrorDataTooLarge("Size of object map".to_string(), pos).into());
+            return Err(ERR::ErrorDataTooLarge(format!("Size of object map ({_map})"), pos).into());
         }
 
         Ok(())
@@ -165,9 +165,12 @@ impl Engine {
 
         // Report progress - only in steps
         if let Some(ref progress) = self.progress {
-            if let Some(token) = progress(*num_operations) {
+            match progress(*num_operations) {
+                crate::api::events::ProgressAction::Continue => (),
                 // Terminate script if progress returns a termination token
-                return Err(ERR::ErrorTerminated(token, pos).into());
+                crate::api::events::ProgressAction::Abort(token) => {
+                    return Err(ERR::ErrorTerminated(token, pos).into())
+                }
             }
         }
 
@@ -530,7 +530,8 @@ impl Engine {
             Some(source.as_str())
         };
 
-        let context = crate::EvalContext::new(self, scope, global, None, lib, this_ptr, level);
+        let context = crate::EvalContext::new(self, scope, global, None, lib, this_ptr, level)
+            .with_position(node.position());
 
         if let Some((.., ref on_debugger)) = self.debugger {
             let command = on_debugger(context, event, node, source, node.position())?;
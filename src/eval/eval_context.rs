@@ -1,7 +1,7 @@
 //! Evaluation context.
 
 use super::{Caches, GlobalRuntimeState};
-use crate::{Dynamic, Engine, Module, Scope};
+use crate::{Dynamic, Engine, Identifier, Module, Position, Scope};
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 
@@ -23,6 +23,8 @@ pub struct EvalContext<'a, 's, 'ps, 'g, 'pg, 'c, 'pc, 't, 'pt> {
     this_ptr: &'t mut Option<&'pt mut Dynamic>,
     /// The current nesting level of function calls.
     level: usize,
+    /// The position of the current operation, if any.
+    pos: Position,
 }
 
 impl<'a, 's, 'ps, 'g, 'pg, 'c, 'pc, 't, 'pt> EvalContext<'a, 's, 'ps, 'g, 'pg, 'c, 'pc, 't, 'pt> {
@@ -46,8 +48,16 @@ impl<'a, 's, 'ps, 'g, 'pg, 'c, 'pc, 't, 'pt> EvalContext<'a, 's, 'ps, 'g, 'pg, '
             lib,
             this_ptr,
             level,
+            pos: Position::NONE,
         }
     }
+    /// Set the position of the current operation.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn with_position(mut self, pos: Position) -> Self {
+        self.pos = pos;
+        self
+    }
     /// The current [`Engine`].
     #[inline(always)]
     #[must_use]
@@ -64,6 +74,14 @@ impl<'a, 's, 'ps, 'g, 'pg, 'c, 'pc, 't, 'pt> EvalContext<'a, 's, 'ps, 'g, 'pg, '
             Some(self.global.source.as_str())
         }
     }
+    /// Get a custom metadata value attached to the [`AST`][crate::AST] being evaluated, if any.
+    ///
+    /// See [`AST::set_metadata`][crate::AST::set_metadata].
+    #[inline]
+    #[must_use]
+    pub fn ast_metadata(&self, key: &str) -> Option<&Dynamic> {
+        self.global.ast_metadata.as_ref().and_then(|m| m.get(key))
+    }
     /// The current [`Scope`].
     #[inline(always)]
     #[must_use]
@@ -76,6 +94,29 @@ impl<'a, 's, 'ps, 'g, 'pg, 'c, 'pc, 't, 'pt> EvalContext<'a, 's, 'ps, 'g, 'pg, '
     pub fn scope_mut(&mut self) -> &mut &'s mut Scope<'ps> {
         &mut self.scope
     }
+    /// Add a new variable into the current [`Scope`], just like a `let` statement.
+    ///
+    /// This is useful for custom syntax that introduces new variable bindings (e.g. a
+    /// `using x = expr` construct).
+    ///
+    /// The variable is visible to all statements following the custom syntax and lives until the
+    /// end of the enclosing block, exactly like a variable defined via `let`.
+    ///
+    /// The custom syntax must be registered with `scope_may_be_changed` set to `true`, otherwise
+    /// variables in following statements may not resolve correctly.
+    #[inline(always)]
+    pub fn push_var(&mut self, name: impl Into<Identifier>, value: Dynamic) -> &mut Self {
+        self.scope.push_dynamic(name, value);
+        self
+    }
+    /// Add a new constant into the current [`Scope`], just like a `const` statement.
+    ///
+    /// See [`push_var`][EvalContext::push_var] for details.
+    #[inline(always)]
+    pub fn push_constant(&mut self, name: impl Into<Identifier>, value: Dynamic) -> &mut Self {
+        self.scope.push_constant_dynamic(name, value);
+        self
+    }
     /// Get an iterator over the current set of modules imported via `import` statements,
     /// in reverse order (i.e. modules imported last come first).
     #[cfg(not(feature = "no_module"))]
@@ -142,9 +183,31 @@ impl<'a, 's, 'ps, 'g, 'pg, 'c, 'pc, 't, 'pt> EvalContext<'a, 's, 'ps, 'g, 'pg, '
     pub const fn call_level(&self) -> usize {
         self.level
     }
+    /// The position of the current operation, e.g. the variable being accessed in a variable
+    /// resolver or defined in a variable definition filter.
+    ///
+    /// Returns [`Position::NONE`] if not available.
+    #[inline(always)]
+    #[must_use]
+    pub const fn position(&self) -> Position {
+        self.pos
+    }
 
     /// Evaluate an [expression tree][crate::Expression] within this [evaluation context][`EvalContext`].
     ///
+    /// # Statement Blocks
+    ///
+    /// If the expression is a statement block (e.g. the input matched by `$block$`), the value of
+    /// the last statement in the block is returned, so custom syntax can be expression-valued.
+    ///
+    /// Changes to the [`Scope`] inside the block are contained:
+    ///
+    /// * variables defined inside the block are removed when the evaluation ends, even if it
+    ///   fails, so they are not visible to statements following the custom syntax;
+    /// * changes to the values of existing variables are kept.
+    ///
+    /// Use [`push_var`][EvalContext::push_var] to define variables that outlive the custom syntax.
+    ///
     /// # WARNING - Low Level API
     ///
     /// This function is very low level.  It evaluates an expression from an [`AST`][crate::AST].
@@ -154,6 +217,51 @@ impl<'a, 's, 'ps, 'g, 'pg, 'c, 'pc, 't, 'pt> EvalContext<'a, 's, 'ps, 'g, 'pg, '
         #[allow(deprecated)]
         self.eval_expression_tree_raw(expr, true)
     }
+    /// Evaluate an [expression tree][crate::Expression] within this [evaluation context][`EvalContext`],
+    /// with the variables in `scope` added on top of the current [`Scope`].
+    ///
+    /// Variables in `scope` shadow variables of the same names in the current [`Scope`] during the
+    /// evaluation only. Changes made to them by the expression are written back to `scope`.
+    ///
+    /// This is useful for custom syntax that binds variables for a sub-expression (e.g. a
+    /// `bind x = 1 { ... }` construct).
+    ///
+    /// # WARNING - Low Level API
+    ///
+    /// This function is very low level.  It evaluates an expression from an [`AST`][crate::AST].
+    #[cfg(not(feature = "no_custom_syntax"))]
+    pub fn eval_expression_tree_with_scope(
+        &mut self,
+        expr: &crate::Expression,
+        scope: &mut Scope,
+    ) -> crate::RhaiResult {
+        let orig_scope_len = self.scope.len();
+
+        for (name, is_constant, value) in scope.iter_raw() {
+            if is_constant {
+                self.scope.push_constant_dynamic(name, value.clone());
+            } else {
+                self.scope.push_dynamic(name, value.clone());
+            }
+        }
+
+        // Variable offsets calculated during parsing do not account for the added variables
+        let orig_always_search_scope = self.global.always_search_scope;
+        self.global.always_search_scope = true;
+
+        #[allow(deprecated)]
+        let result = self.eval_expression_tree_raw(expr, true);
+
+        self.global.always_search_scope = orig_always_search_scope;
+
+        for index in 0..scope.len() {
+            *scope.get_mut_by_index(index) =
+                std::mem::take(self.scope.get_mut_by_index(orig_scope_len + index));
+        }
+        self.scope.rewind(orig_scope_len);
+
+        result
+    }
     /// Evaluate an [expression tree][crate::Expression] within this [evaluation context][`EvalContext`].
     ///
     /// The following option is available:
@@ -41,7 +41,12 @@ impl Engine {
 
         // Do a text-match search if the index doesn't work
         global.find_import(root).map_or_else(
-            || self.global_sub_modules.get(root).cloned(),
+            || {
+                self.global_sub_modules
+                    .get(root)
+                    .cloned()
+                    .or_else(|| self.get_lazy_module(root))
+            },
             |offset| global.get_shared_import(offset),
         )
     }
@@ -170,7 +175,8 @@ impl Engine {
 
         // Check the variable resolver, if any
         if let Some(ref resolve_var) = self.resolve_var {
-            let context = EvalContext::new(self, scope, global, None, lib, this_ptr, level);
+            let context = EvalContext::new(self, scope, global, None, lib, this_ptr, level)
+                .with_position(var_pos);
             let var_name = expr.get_variable_name(true).expect("`Expr::Variable`");
             match resolve_var(var_name, index, context) {
                 Ok(Some(mut result)) => {
@@ -206,6 +212,30 @@ impl Engine {
         Ok((val.into(), var_pos))
     }
 
+    /// Is a value of a custom type that cannot be cloned in scripts?
+    #[inline]
+    #[must_use]
+    pub(crate) fn is_no_clone_value(&self, value: &Dynamic) -> bool {
+        !self.no_clone_types.is_empty() && self.no_clone_types.contains(&value.type_id())
+    }
+
+    /// Get the value of a [`Target`], cloning it if necessary.
+    ///
+    /// Values that cannot be cloned are moved out of the variable instead, raising an error if
+    /// that is not possible (e.g. a constant or a shared value).
+    pub(crate) fn take_or_move_target(&self, target: Target, pos: Position) -> RhaiResult {
+        if target.is_temp_value() || !self.is_no_clone_value(&target) {
+            return Ok(target.take_or_clone());
+        }
+
+        let type_name = target.type_name();
+
+        match target.take_ref() {
+            Some(value) if !value.is_read_only() => Ok(std::mem::take(value)),
+            _ => Err(self.make_no_clone_err(type_name, pos)),
+        }
+    }
+
     /// Evaluate a function call expression.
     pub(crate) fn eval_fn_call_expr(
         &self,
@@ -321,9 +351,13 @@ impl Engine {
             #[cfg(not(feature = "unchecked"))]
             self.inc_operations(&mut global.num_operations, expr.position())?;
 
-            let result =
+            let mut result =
                 self.eval_fn_call_expr(scope, global, caches, lib, this_ptr, x, x.pos, level);
 
+            if let Ok(ref mut value) = result {
+                self.stamp_value_tag(value);
+            }
+
             #[cfg(feature = "debugging")]
             global.debugger.reset_status(reset_debugger);
 
@@ -340,15 +374,21 @@ impl Engine {
             #[cfg(not(feature = "unchecked"))]
             self.inc_operations(&mut global.num_operations, expr.position())?;
 
-            return if index.is_none() && x.0.is_none() && x.3 == KEYWORD_THIS {
-                this_ptr
+            if index.is_none() && x.0.is_none() && x.3 == KEYWORD_THIS {
+                let value = this_ptr
                     .as_deref()
-                    .cloned()
-                    .ok_or_else(|| ERR::ErrorUnboundThis(*var_pos).into())
-            } else {
-                self.search_namespace(scope, global, lib, this_ptr, expr, level)
-                    .map(|(val, ..)| val.take_or_clone())
-            };
+                    .ok_or_else(|| ERR::ErrorUnboundThis(*var_pos))?;
+
+                if self.is_no_clone_value(value) {
+                    return Err(self.make_no_clone_err(value.type_name(), *var_pos));
+                }
+
+                return Ok(value.clone());
+            }
+
+            let (target, ..) = self.search_namespace(scope, global, lib, this_ptr, expr, level)?;
+
+            return self.take_or_move_target(target, *var_pos);
         }
 
         #[cfg(feature = "debugging")]
@@ -358,7 +398,7 @@ impl Engine {
         #[cfg(not(feature = "unchecked"))]
         self.inc_operations(&mut global.num_operations, expr.position())?;
 
-        let result = match expr {
+        let mut result = match expr {
             // Constants
             Expr::DynamicConstant(x, ..) => Ok(x.as_ref().clone()),
             Expr::IntegerConstant(x, ..) => Ok((*x).into()),
@@ -548,7 +588,8 @@ impl Engine {
                     ))
                 })?;
                 let mut context =
-                    EvalContext::new(self, scope, global, Some(caches), lib, this_ptr, level);
+                    EvalContext::new(self, scope, global, Some(caches), lib, this_ptr, level)
+                        .with_position(*pos);
 
                 let result = (custom_def.func)(&mut context, &expressions, &custom.state);
 
@@ -573,6 +614,16 @@ impl Engine {
             _ => unreachable!("expression cannot be evaluated: {:?}", expr),
         };
 
+        // Stamp newly-created values with the default value tag
+        if let Ok(ref mut value) = result {
+            match expr {
+                Expr::Coalesce(..) | Expr::Stmt(..) | Expr::Index(..) | Expr::Dot(..) => (),
+                #[cfg(not(feature = "no_custom_syntax"))]
+                Expr::Custom(..) => (),
+                _ => self.stamp_value_tag(value),
+            }
+        }
+
         #[cfg(feature = "debugging")]
         global.debugger.reset_status(reset_debugger);
 
@@ -33,6 +33,8 @@ pub struct GlobalRuntimeState<'a> {
     ///
     /// No source if the string is empty.
     pub source: Identifier,
+    /// Custom metadata of the [`AST`][crate::AST] being evaluated, if any.
+    pub ast_metadata: Option<crate::Shared<std::collections::BTreeMap<Identifier, Dynamic>>>,
     /// Number of operations performed.
     pub num_operations: u64,
     /// Number of modules loaded.
@@ -41,6 +43,23 @@ pub struct GlobalRuntimeState<'a> {
     ///
     /// The global (root) level is zero, a new block (or function call) is one level higher, and so on.
     pub scope_level: usize,
+    /// Call level of the script-defined function being evaluated (zero at global level).
+    #[cfg(not(feature = "unchecked"))]
+    #[cfg(not(feature = "no_function"))]
+    pub fn_level: usize,
+    /// Number of nested closure calls being evaluated.
+    ///
+    /// Only tracked when the maximum depth of closure calls is set.
+    #[cfg(not(feature = "unchecked"))]
+    #[cfg(not(feature = "no_function"))]
+    pub closure_depth: usize,
+    /// Number of call levels taken up by calling closures, which do not count toward the maximum
+    /// call levels.
+    ///
+    /// Only tracked when the maximum depth of closure calls is set.
+    #[cfg(not(feature = "unchecked"))]
+    #[cfg(not(feature = "no_function"))]
+    pub closure_levels: usize,
     /// Force a [`Scope`][crate::Scope] search by name.
     ///
     /// Normally, access to variables are parsed with a relative offset into the
@@ -66,6 +85,8 @@ pub struct GlobalRuntimeState<'a> {
     pub constants: Option<GlobalConstants>,
     /// Custom state that can be used by the external host.
     pub tag: Dynamic,
+    /// Statistics of the evaluation, if collected.
+    pub stats: Option<super::EvalStats>,
     /// Debugging interface.
     #[cfg(feature = "debugging")]
     pub debugger: super::Debugger,
@@ -84,9 +105,19 @@ impl GlobalRuntimeState<'_> {
             #[cfg(not(feature = "no_module"))]
             modules: crate::StaticVec::new_const(),
             source: Identifier::new_const(),
+            ast_metadata: None,
             num_operations: 0,
             num_modules_loaded: 0,
             scope_level: 0,
+            #[cfg(not(feature = "unchecked"))]
+            #[cfg(not(feature = "no_function"))]
+            fn_level: 0,
+            #[cfg(not(feature = "unchecked"))]
+            #[cfg(not(feature = "no_function"))]
+            closure_depth: 0,
+            #[cfg(not(feature = "unchecked"))]
+            #[cfg(not(feature = "no_function"))]
+            closure_levels: 0,
             always_search_scope: false,
             #[cfg(not(feature = "no_module"))]
             embedded_module_resolver: None,
@@ -97,6 +128,7 @@ impl GlobalRuntimeState<'_> {
             constants: None,
 
             tag: engine.default_tag().clone(),
+            stats: None,
 
             #[cfg(feature = "debugging")]
             debugger: crate::eval::Debugger::new(
@@ -115,6 +147,13 @@ impl GlobalRuntimeState<'_> {
             dummy: PhantomData::default(),
         }
     }
+    /// Count a function call in the evaluation statistics, if collected.
+    #[inline(always)]
+    pub(crate) fn count_fn_call(&mut self) {
+        if let Some(ref mut stats) = self.stats {
+            stats.fn_calls += 1;
+        }
+    }
     /// Get the length of the stack of globally-imported [modules][crate::Module].
     ///
     /// Not available under `no_module`.
@@ -363,6 +402,7 @@ impl fmt::Debug for GlobalRuntimeState<'_> {
         f.field("imports", &self.keys.iter().zip(self.modules.iter()));
 
         f.field("source", &self.source)
+            .field("ast_metadata", &self.ast_metadata)
             .field("num_operations", &self.num_operations)
             .field("num_modules_loaded", &self.num_modules_loaded);
 
@@ -376,6 +416,8 @@ impl fmt::Debug for GlobalRuntimeState<'_> {
         #[cfg(not(feature = "no_function"))]
         f.field("constants", &self.constants);
 
+        f.field("stats", &self.stats);
+
         f.finish()
     }
 }
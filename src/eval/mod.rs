@@ -5,6 +5,7 @@ mod debugger;
 mod eval_context;
 mod expr;
 mod global_state;
+mod stats;
 mod stmt;
 mod target;
 
@@ -21,4 +22,5 @@ pub use eval_context::EvalContext;
 #[cfg(not(feature = "no_function"))]
 pub use global_state::GlobalConstants;
 pub use global_state::GlobalRuntimeState;
+pub use stats::EvalStats;
 pub use target::{calc_index, calc_offset_len, Target};
@@ -0,0 +1,23 @@
+//! Module defining statistics collected during evaluation.
+
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+/// Statistics collected while evaluating a script via [`Engine::eval_with_stats`][crate::Engine::eval_with_stats].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[non_exhaustive]
+pub struct EvalStats {
+    /// Number of operations performed.
+    ///
+    /// This is the same counter used to enforce [`Engine::set_max_operations`][crate::Engine::set_max_operations].
+    /// Always zero under `unchecked`.
+    pub operations: u64,
+    /// Number of native and script-defined function calls, excluding built-in operators.
+    pub fn_calls: u64,
+    /// Maximum depth of nested script-defined function calls reached.
+    pub max_call_depth: usize,
+    /// Peak number of variables in a [`Scope`][crate::Scope].
+    ///
+    /// Each call to a script-defined function has its own [`Scope`][crate::Scope].
+    pub peak_scope_size: usize,
+}
@@ -3,12 +3,16 @@
 use super::{Caches, EvalContext, GlobalRuntimeState, Target};
 use crate::api::events::VarDefInfo;
 use crate::ast::{
-    ASTFlags, BinaryExpr, Expr, Ident, OpAssignment, Stmt, SwitchCasesCollection, TryCatchBlock,
+    ASTFlags, BinaryExpr, Expr, Ident, MatchPattern, OpAssignment, Stmt, SwitchCasesCollection,
+    TryCatchBlock,
 };
 use crate::func::{get_builtin_op_assignment_fn, get_hasher};
 use crate::types::dynamic::{AccessMode, Union};
+#[cfg(not(feature = "no_custom_syntax"))]
+use crate::{ast::FnCallHashes, tokenizer::is_valid_function_name, tokenizer::Token};
 use crate::{
-    Dynamic, Engine, ImmutableString, Module, Position, RhaiResult, RhaiResultOf, Scope, ERR, INT,
+    Dynamic, Engine, ImmutableString, Module, Position, RhaiResult, RhaiResultOf, Scope, StaticVec,
+    ERR, INT,
 };
 use std::hash::{Hash, Hasher};
 #[cfg(feature = "no_std")]
@@ -65,6 +69,12 @@ impl Engine {
                 level,
             );
 
+            // Sample the scope after the statement runs, even if it fails, so that any new
+            // variable it defines is counted
+            if let Some(ref mut stats) = global.stats {
+                stats.peak_scope_size = stats.peak_scope_size.max(scope.len());
+            }
+
             if result.is_err() {
                 break;
             }
@@ -159,8 +169,9 @@ impl Engine {
                 }
             }
 
-            let op_assign = op_assign.literal_syntax();
-            let op = op.literal_syntax();
+            let op_assign_token = op_assign;
+            let op_assign = &*op_assign.syntax();
+            let op_name = &*op.syntax();
 
             match self.call_native_fn(
                 global, caches, lib, op_assign, hash, args, true, true, *op_pos, level,
@@ -172,13 +183,43 @@ impl Engine {
                 Err(err) if matches!(*err, ERR::ErrorFunctionNotFound(ref f, ..) if f.starts_with(op_assign)) =>
                 {
                     // Expand to `var = var op rhs`
-                    *args[0] = self
-                        .call_native_fn(
-                            global, caches, lib, op, *hash_op, args, true, false, *op_pos, level,
-                        )
-                        .map_err(|err| err.fill_position(op_info.pos))?
-                        .0
-                        .flatten();
+                    let result = match op {
+                        // Custom operators may also be script-defined functions
+                        #[cfg(not(feature = "no_custom_syntax"))]
+                        Token::Custom(..) => {
+                            let hashes = if is_valid_function_name(op_name) {
+                                (*hash_op).into()
+                            } else {
+                                FnCallHashes::from_native(*hash_op)
+                            };
+                            self.exec_fn_call(
+                                None, global, caches, lib, op_name, hashes, args, true, false,
+                                *op_pos, level,
+                            )
+                        }
+                        _ => self.call_native_fn(
+                            global, caches, lib, op_name, *hash_op, args, true, false, *op_pos,
+                            level,
+                        ),
+                    };
+
+                    match result {
+                        Ok((value, ..)) => *args[0] = value.flatten(),
+                        // Not in fast operators mode and no `op` - use the built-in `op=`, if any
+                        Err(err) if matches!(*err, ERR::ErrorFunctionNotFound(ref f, ..) if f.starts_with(op_name)) =>
+                        {
+                            let func =
+                                get_builtin_op_assignment_fn(op_assign_token, args[0], args[1])
+                                    .ok_or_else(|| err.fill_position(op_info.pos))?;
+                            let op = op_assign_token.literal_syntax();
+                            let context = (self, op, None, &*global, lib, *op_pos, level).into();
+                            func(context, args)?;
+
+                            #[cfg(not(feature = "unchecked"))]
+                            self.check_data_size(args[0], root.1)?;
+                        }
+                        Err(err) => return Err(err.fill_position(op_info.pos)),
+                    }
                 }
                 Err(err) => return Err(err),
             }
@@ -510,6 +551,76 @@ impl Engine {
                 }
             }
 
+            // Match statement
+            Stmt::Match(x, pos) => {
+                let (expr, arms) = &**x;
+
+                let value_result = self
+                    .eval_expr(scope, global, caches, lib, this_ptr, expr, level)
+                    .map(Dynamic::flatten);
+
+                if let Ok(value) = value_result {
+                    let orig_scope_len = scope.len();
+                    let mut bindings = StaticVec::new_const();
+                    let mut result = None;
+
+                    for arm in arms {
+                        bindings.clear();
+
+                        if !match_pattern(&arm.pattern, &value, &mut bindings) {
+                            continue;
+                        }
+
+                        // Bring all variables bound by the pattern into scope
+                        for (name, v) in bindings.drain(..) {
+                            scope.push(name.clone(), v);
+                        }
+
+                        let cond_result = match arm.body.condition {
+                            Expr::BoolConstant(b, ..) => Ok(b),
+                            ref c => self
+                                .eval_expr(scope, global, caches, lib, this_ptr, c, level)
+                                .and_then(|v| {
+                                    v.as_bool().map_err(|typ| {
+                                        self.make_type_mismatch_err::<bool>(typ, c.position())
+                                    })
+                                }),
+                        };
+
+                        match cond_result {
+                            Ok(true) => {
+                                let action = &arm.body.expr;
+                                result = Some(self.eval_expr(
+                                    scope, global, caches, lib, this_ptr, action, level,
+                                ));
+                            }
+                            Ok(false) => (),
+                            Err(err) => result = Some(Err(err)),
+                        }
+
+                        scope.rewind(orig_scope_len);
+
+                        if result.is_some() {
+                            break;
+                        }
+                    }
+
+                    result.unwrap_or_else(|| {
+                        if self.strict_match() {
+                            Err(ERR::ErrorRuntime(
+                                format!("No arm of match matches the value: {value:?}").into(),
+                                *pos,
+                            )
+                            .into())
+                        } else {
+                            Ok(Dynamic::UNIT)
+                        }
+                    })
+                } else {
+                    value_result
+                }
+            }
+
             // Loop
             Stmt::While(x, ..) if matches!(x.0, Expr::Unit(..)) => loop {
                 let (.., body) = &**x;
@@ -525,6 +636,7 @@ impl Engine {
                         Err(err) => match *err {
                             ERR::LoopBreak(false, ..) => (),
                             ERR::LoopBreak(true, ..) => break Ok(Dynamic::UNIT),
+                            ERR::LoopBreakWithValue(value, ..) => break Ok(value),
                             _ => break Err(err),
                         },
                     }
@@ -553,7 +665,9 @@ impl Engine {
                             Ok(_) => (),
                             Err(err) => match *err {
                                 ERR::LoopBreak(false, ..) => (),
-                                ERR::LoopBreak(true, ..) => break Ok(Dynamic::UNIT),
+                                ERR::LoopBreak(true, ..) | ERR::LoopBreakWithValue(..) => {
+                                    break Ok(Dynamic::UNIT)
+                                }
                                 _ => break Err(err),
                             },
                         }
@@ -574,7 +688,9 @@ impl Engine {
                         Ok(_) => (),
                         Err(err) => match *err {
                             ERR::LoopBreak(false, ..) => continue,
-                            ERR::LoopBreak(true, ..) => break Ok(Dynamic::UNIT),
+                            ERR::LoopBreak(true, ..) | ERR::LoopBreakWithValue(..) => {
+                                break Ok(Dynamic::UNIT)
+                            }
                             _ => break Err(err),
                         },
                     }
@@ -604,28 +720,58 @@ impl Engine {
                     .map(Dynamic::flatten);
 
                 if let Ok(iter_obj) = iter_result {
-                    let iter_type = iter_obj.type_id();
+                    // Keys of an object map, bound to the counter variable
+                    #[cfg(not(feature = "no_object"))]
+                    let mut map_keys = None;
+                    // Functions mapping each value of a lazy sequence
+                    let mut seq_mappers = None;
+
+                    let iter = match iter_obj {
+                        // Object maps are iterated natively, in sorted key order
+                        #[cfg(not(feature = "no_object"))]
+                        obj if obj.is::<crate::Map>() => {
+                            let (keys, values): (Vec<_>, Vec<_>) =
+                                obj.cast::<crate::Map>().into_iter().unzip();
+                            map_keys = Some(keys.into_iter());
+                            Some(Box::new(values.into_iter().map(Ok))
+                                as Box<dyn Iterator<Item = RhaiResultOf<Dynamic>>>)
+                        }
+                        // Lazy sequences are iterated natively, mapping values on demand
+                        obj if obj.is::<crate::LazySequence>() => {
+                            let seq = obj.cast::<crate::LazySequence>();
+                            if !seq.mappers().is_empty() {
+                                seq_mappers = Some(seq.mappers().to_vec());
+                            }
+                            Some(Box::new(seq.source_iter().map(Ok))
+                                as Box<dyn Iterator<Item = RhaiResultOf<Dynamic>>>)
+                        }
+                        obj => {
+                            let iter_type = obj.type_id();
 
-                    // lib should only contain scripts, so technically they cannot have iterators
+                            // lib should only contain scripts, so technically they cannot have iterators
 
-                    // Search order:
-                    // 1) Global namespace - functions registered via Engine::register_XXX
-                    // 2) Global modules - packages
-                    // 3) Imported modules - functions marked with global namespace
-                    // 4) Global sub-modules - functions marked with global namespace
-                    let func = self
-                        .global_modules
-                        .iter()
-                        .find_map(|m| m.get_iter(iter_type));
+                            // Search order:
+                            // 1) Global namespace - functions registered via Engine::register_XXX
+                            // 2) Global modules - packages
+                            // 3) Imported modules - functions marked with global namespace
+                            // 4) Global sub-modules - functions marked with global namespace
+                            let func = self
+                                .global_modules
+                                .iter()
+                                .find_map(|m| m.get_iter(iter_type));
 
-                    #[cfg(not(feature = "no_module"))]
-                    let func = func.or_else(|| global.get_iter(iter_type)).or_else(|| {
-                        self.global_sub_modules
-                            .values()
-                            .find_map(|m| m.get_qualified_iter(iter_type))
-                    });
+                            #[cfg(not(feature = "no_module"))]
+                            let func = func.or_else(|| global.get_iter(iter_type)).or_else(|| {
+                                self.global_sub_modules
+                                    .values()
+                                    .find_map(|m| m.get_qualified_iter(iter_type))
+                            });
 
-                    if let Some(func) = func {
+                            func.map(|func| func(obj))
+                        }
+                    };
+
+                    if let Some(iter) = iter {
                         // Add the loop variables
                         let orig_scope_len = scope.len();
                         let counter_index = if counter.is_empty() {
@@ -640,9 +786,24 @@ impl Engine {
 
                         let mut loop_result = Ok(Dynamic::UNIT);
 
-                        for (x, iter_value) in func(iter_obj).enumerate() {
+                        #[cfg(not(feature = "no_object"))]
+                        let is_map = map_keys.is_some();
+                        #[cfg(feature = "no_object")]
+                        let is_map = false;
+
+                        for (x, iter_value) in iter.enumerate() {
+                            // Object map key
+                            #[cfg(not(feature = "no_object"))]
+                            if let Some(ref mut keys) = map_keys {
+                                if counter_index < usize::MAX {
+                                    let key: ImmutableString = keys.next().unwrap().into();
+                                    *scope.get_mut_by_index(counter_index).write_lock().unwrap() =
+                                        key.into();
+                                }
+                            }
+
                             // Increment counter
-                            if counter_index < usize::MAX {
+                            if counter_index < usize::MAX && !is_map {
                                 // As the variable increments from 0, this should always work
                                 // since any overflow will first be caught below.
                                 let index_value = x as INT;
@@ -661,6 +822,26 @@ impl Engine {
                                     Dynamic::from_int(index_value);
                             }
 
+                            // Map the value of a lazy sequence
+                            let iter_value = match seq_mappers {
+                                Some(ref mappers) => iter_value.and_then(|v| {
+                                    mappers.iter().try_fold(v, |v, mapper| {
+                                        let context = (
+                                            self,
+                                            mapper.fn_name(),
+                                            None,
+                                            &*global,
+                                            lib,
+                                            expr.position(),
+                                            level + 1,
+                                        )
+                                            .into();
+                                        mapper.call_raw(&context, None, [v])
+                                    })
+                                }),
+                                None => iter_value,
+                            };
+
                             let value = match iter_value {
                                 Ok(v) => v.flatten(),
                                 Err(err) => {
@@ -691,7 +872,7 @@ impl Engine {
                                 Ok(_) => (),
                                 Err(err) => match *err {
                                     ERR::LoopBreak(false, ..) => (),
-                                    ERR::LoopBreak(true, ..) => break,
+                                    ERR::LoopBreak(true, ..) | ERR::LoopBreakWithValue(..) => break,
                                     _ => {
                                         loop_result = Err(err);
                                         break;
@@ -712,8 +893,15 @@ impl Engine {
             }
 
             // Continue/Break statement
-            Stmt::BreakLoop(options, pos) => {
-                Err(ERR::LoopBreak(options.contains(ASTFlags::BREAK), *pos).into())
+            Stmt::BreakLoop(expr, options, pos) => {
+                let is_break = options.contains(ASTFlags::BREAK);
+
+                if let Some(ref expr) = expr {
+                    self.eval_expr(scope, global, caches, lib, this_ptr, expr, level)
+                        .and_then(|v| Err(ERR::LoopBreakWithValue(v.flatten(), *pos).into()))
+                } else {
+                    Err(ERR::LoopBreak(is_break, *pos).into())
+                }
             }
 
             // Try/Catch statement
@@ -849,7 +1037,8 @@ impl Engine {
                         nesting_level,
                         will_shadow,
                     };
-                    let context = EvalContext::new(self, scope, global, None, lib, this_ptr, level);
+                    let context = EvalContext::new(self, scope, global, None, lib, this_ptr, level)
+                        .with_position(*pos);
 
                     match filter(true, info, context) {
                         Ok(true) => None,
@@ -902,6 +1091,15 @@ impl Engine {
                             None
                         };
 
+                        // Guard against too many variables
+                        #[cfg(not(feature = "unchecked"))]
+                        if index.is_none()
+                            && self.max_variables() > 0
+                            && scope.len() >= self.max_variables()
+                        {
+                            return Err(ERR::ErrorTooManyVariables(*pos).into());
+                        }
+
                         if let Some(index) = index {
                             value.set_access_mode(access);
                             *scope.get_mut_by_index(scope.len() - index.get()) = value;
@@ -1029,3 +1227,61 @@ impl Engine {
         result
     }
 }
+
+/// Match a value against a [`MatchPattern`], collecting the names and values of all variables
+/// bound by the pattern.
+///
+/// Literal patterns are matched in the same way as `switch` cases.
+fn match_pattern<'a>(
+    pattern: &'a MatchPattern,
+    value: &Dynamic,
+    bindings: &mut StaticVec<(&'a ImmutableString, Dynamic)>,
+) -> bool {
+    #[cfg(not(feature = "no_closure"))]
+    if value.is_shared() {
+        return match_pattern(pattern, &value.flatten_clone(), bindings);
+    }
+
+    match pattern {
+        MatchPattern::Wildcard => true,
+        MatchPattern::Bind(Ident { name, .. }) => {
+            bindings.push((name, value.clone()));
+            true
+        }
+        MatchPattern::Literal(literal) => {
+            value.is_hashable() && {
+                let hasher = &mut get_hasher();
+                value.hash(hasher);
+                let hash = hasher.finish();
+
+                let hasher = &mut get_hasher();
+                literal.hash(hasher);
+                hash == hasher.finish()
+            }
+        }
+        MatchPattern::Range(range) => value.as_int().map_or(false, |n| range.contains(n)),
+        #[cfg(not(feature = "no_index"))]
+        MatchPattern::Array(patterns, has_rest) => {
+            value.read_lock::<crate::Array>().map_or(false, |array| {
+                let len_ok = if *has_rest {
+                    array.len() >= patterns.len()
+                } else {
+                    array.len() == patterns.len()
+                };
+
+                len_ok
+                    && patterns
+                        .iter()
+                        .zip(array.iter())
+                        .all(|(p, v)| match_pattern(p, v, bindings))
+            })
+        }
+        #[cfg(not(feature = "no_object"))]
+        MatchPattern::Map(patterns) => value.read_lock::<crate::Map>().map_or(false, |map| {
+            patterns.iter().all(|(key, p)| {
+                map.get(key.name.as_str())
+                    .map_or(false, |v| match_pattern(p, v, bindings))
+            })
+        }),
+    }
+}
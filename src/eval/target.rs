@@ -121,6 +121,19 @@ pub enum Target<'a> {
         /// Offset index.
         index: usize,
     },
+    /// The target is a range of bytes inside a [`Blob`][crate::Blob].
+    /// This is necessary because a range of bytes (in [`Dynamic`] form) cannot point into a blob.
+    #[cfg(not(feature = "no_index"))]
+    BlobRange {
+        /// Mutable reference to the source [`Dynamic`].
+        source: &'a mut Dynamic,
+        /// Copy of the bytes in the range, as a [`Dynamic`].
+        value: Dynamic,
+        /// Start of the range.
+        start: usize,
+        /// End of the range (exclusive).
+        end: usize,
+    },
     /// The target is a character inside a string.
     /// This is necessary because directly pointing to a char inside a String is impossible.
     #[cfg(not(feature = "no_index"))]
@@ -149,6 +162,7 @@ impl<'a> Target<'a> {
             Self::Bit { .. }
             | Self::BitField { .. }
             | Self::BlobByte { .. }
+            | Self::BlobRange { .. }
             | Self::StringChar { .. } => false,
         }
     }
@@ -165,6 +179,7 @@ impl<'a> Target<'a> {
             Self::Bit { .. }
             | Self::BitField { .. }
             | Self::BlobByte { .. }
+            | Self::BlobRange { .. }
             | Self::StringChar { .. } => false,
         }
     }
@@ -182,6 +197,7 @@ impl<'a> Target<'a> {
             Self::Bit { .. }
             | Self::BitField { .. }
             | Self::BlobByte { .. }
+            | Self::BlobRange { .. }
             | Self::StringChar { .. } => false,
         }
     }
@@ -205,6 +221,8 @@ impl<'a> Target<'a> {
             #[cfg(not(feature = "no_index"))]
             Self::BlobByte { .. } => TypeId::of::<T>() == TypeId::of::<crate::Blob>(),
             #[cfg(not(feature = "no_index"))]
+            Self::BlobRange { .. } => TypeId::of::<T>() == TypeId::of::<crate::Blob>(),
+            #[cfg(not(feature = "no_index"))]
             Self::StringChar { .. } => TypeId::of::<T>() == TypeId::of::<char>(),
         }
     }
@@ -224,6 +242,8 @@ impl<'a> Target<'a> {
             #[cfg(not(feature = "no_index"))]
             Self::BlobByte { value, .. } => value, // byte is taken
             #[cfg(not(feature = "no_index"))]
+            Self::BlobRange { value, .. } => value, // bytes are taken
+            #[cfg(not(feature = "no_index"))]
             Self::StringChar { value, .. } => value, // char is taken
         }
     }
@@ -264,6 +284,8 @@ impl<'a> Target<'a> {
             #[cfg(not(feature = "no_index"))]
             Self::BlobByte { source, .. } => source,
             #[cfg(not(feature = "no_index"))]
+            Self::BlobRange { source, .. } => source,
+            #[cfg(not(feature = "no_index"))]
             Self::StringChar { source, .. } => source,
         }
     }
@@ -348,6 +370,29 @@ impl<'a> Target<'a> {
                 }
             }
             #[cfg(not(feature = "no_index"))]
+            Self::BlobRange {
+                source,
+                value,
+                start,
+                end,
+            } => {
+                // Replace the bytes in the range, which may change the length of the blob
+                let new_bytes = value.read_lock::<crate::Blob>().ok_or_else(|| {
+                    Box::new(crate::ERR::ErrorMismatchDataType(
+                        "blob".to_string(),
+                        value.type_name().to_string(),
+                        _pos,
+                    ))
+                })?;
+
+                let blob = &mut *source.write_lock::<crate::Blob>().expect("`Blob`");
+
+                blob.splice(*start..*end, new_bytes.iter().copied());
+
+                // The range now covers the new bytes
+                *end = *start + new_bytes.len();
+            }
+            #[cfg(not(feature = "no_index"))]
             Self::StringChar {
                 source,
                 value,
@@ -410,6 +455,7 @@ impl Deref for Target<'_> {
             Self::Bit { ref value, .. }
             | Self::BitField { ref value, .. }
             | Self::BlobByte { ref value, .. }
+            | Self::BlobRange { ref value, .. }
             | Self::StringChar { ref value, .. } => value,
         }
     }
@@ -436,6 +482,7 @@ impl DerefMut for Target<'_> {
             Self::Bit { ref mut value, .. }
             | Self::BitField { ref mut value, .. }
             | Self::BlobByte { ref mut value, .. }
+            | Self::BlobRange { ref mut value, .. }
             | Self::StringChar { ref mut value, .. } => value,
         }
     }
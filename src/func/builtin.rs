@@ -4,6 +4,8 @@ use super::call::FnCallArgs;
 use super::native::FnBuiltin;
 use crate::tokenizer::Token;
 use crate::{Dynamic, ExclusiveRange, ImmutableString, InclusiveRange, INT};
+#[cfg(not(feature = "no_float"))]
+use crate::{NativeCallContext, Position, RhaiResultOf, ERR};
 use std::any::TypeId;
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
@@ -63,6 +65,21 @@ fn is_numeric(type_id: TypeId) -> bool {
     false
 }
 
+/// Raise an error if arithmetic mixing integers and floating-point numbers is disallowed.
+#[cfg(not(feature = "no_float"))]
+#[inline]
+fn check_numeric_coercion(ctx: &NativeCallContext) -> RhaiResultOf<()> {
+    if ctx.engine().strict_numeric_coercion() {
+        Err(ERR::ErrorArithmetic(
+            "Cannot mix integers and floating-point numbers without explicit conversion".into(),
+            Position::NONE,
+        )
+        .into())
+    } else {
+        Ok(())
+    }
+}
+
 /// Build in common binary operator implementations to avoid the cost of calling a registered function.
 ///
 /// The return function will be registered as a _method_, so the first parameter cannot be consumed.
@@ -221,6 +238,16 @@ pub fn get_builtin_binary_op_fn(op: &Token, x: &Dynamic, y: &Dynamic) -> Option<
                 Token::GreaterThanEqualsTo => Some(impl_op!(char => as_char >= as_char)),
                 Token::LessThan => Some(impl_op!(char => as_char < as_char)),
                 Token::LessThanEqualsTo => Some(impl_op!(char => as_char <= as_char)),
+                Token::ExclusiveRange => Some(|_, args| {
+                    let x = args[0].as_char().expect(BUILTIN);
+                    let y = args[1].as_char().expect(BUILTIN);
+                    Ok(Dynamic::from(x..y))
+                }),
+                Token::InclusiveRange => Some(|_, args| {
+                    let x = args[0].as_char().expect(BUILTIN);
+                    let y = args[1].as_char().expect(BUILTIN);
+                    Ok(Dynamic::from(x..=y))
+                }),
                 _ => None,
             };
         }
@@ -265,15 +292,21 @@ pub fn get_builtin_binary_op_fn(op: &Token, x: &Dynamic, y: &Dynamic) -> Option<
 
     #[cfg(not(feature = "no_float"))]
     macro_rules! impl_float {
-        ($x:ty, $xx:ident, $y:ty, $yy:ident) => {
+        (@arith mixed $func:expr) => { |ctx, args| {
+            check_numeric_coercion(&ctx)?;
+            let func: FnBuiltin = $func;
+            func(ctx, args)
+        } };
+        (@arith $func:expr) => { $func };
+        ($x:ty, $xx:ident, $y:ty, $yy:ident $(, $mixed:ident)?) => {
             if (type1, type2) == (TypeId::of::<$x>(), TypeId::of::<$y>()) {
                 return match op {
-                    Token::Plus                 => Some(impl_op!(FLOAT => $xx + $yy)),
-                    Token::Minus                => Some(impl_op!(FLOAT => $xx - $yy)),
-                    Token::Multiply             => Some(impl_op!(FLOAT => $xx * $yy)),
-                    Token::Divide               => Some(impl_op!(FLOAT => $xx / $yy)),
-                    Token::Modulo               => Some(impl_op!(FLOAT => $xx % $yy)),
-                    Token::PowerOf              => Some(impl_op!(FLOAT => $xx.powf($yy as FLOAT))),
+                    Token::Plus                 => Some(impl_float!(@arith $($mixed)? impl_op!(FLOAT => $xx + $yy))),
+                    Token::Minus                => Some(impl_float!(@arith $($mixed)? impl_op!(FLOAT => $xx - $yy))),
+                    Token::Multiply             => Some(impl_float!(@arith $($mixed)? impl_op!(FLOAT => $xx * $yy))),
+                    Token::Divide               => Some(impl_float!(@arith $($mixed)? impl_op!(FLOAT => $xx / $yy))),
+                    Token::Modulo               => Some(impl_float!(@arith $($mixed)? impl_op!(FLOAT => $xx % $yy))),
+                    Token::PowerOf              => Some(impl_float!(@arith $($mixed)? impl_op!(FLOAT => $xx.powf($yy as FLOAT)))),
                     Token::EqualsTo             => Some(impl_op!(FLOAT => $xx == $yy)),
                     Token::NotEqualsTo          => Some(impl_op!(FLOAT => $xx != $yy)),
                     Token::GreaterThan          => Some(impl_op!(FLOAT => $xx > $yy)),
@@ -289,8 +322,8 @@ pub fn get_builtin_binary_op_fn(op: &Token, x: &Dynamic, y: &Dynamic) -> Option<
     #[cfg(not(feature = "no_float"))]
     {
         impl_float!(FLOAT, as_float, FLOAT, as_float);
-        impl_float!(FLOAT, as_float, INT, as_int);
-        impl_float!(INT, as_int, FLOAT, as_float);
+        impl_float!(FLOAT, as_float, INT, as_int, mixed);
+        impl_float!(INT, as_int, FLOAT, as_float, mixed);
     }
 
     #[cfg(feature = "decimal")]
@@ -671,15 +704,21 @@ pub fn get_builtin_op_assignment_fn(op: &Token, x: &Dynamic, y: &Dynamic) -> Opt
 
     #[cfg(not(feature = "no_float"))]
     macro_rules! impl_float {
-        ($x:ident, $xx:ident, $y:ty, $yy:ident) => {
+        (@arith mixed $func:expr) => { |ctx, args| {
+            check_numeric_coercion(&ctx)?;
+            let func: FnBuiltin = $func;
+            func(ctx, args)
+        } };
+        (@arith $func:expr) => { $func };
+        ($x:ident, $xx:ident, $y:ty, $yy:ident $(, $mixed:ident)?) => {
             if (type1, type2) == (TypeId::of::<$x>(), TypeId::of::<$y>()) {
                 return match op {
-                    Token::PlusAssign       => Some(impl_op!($x += $yy)),
-                    Token::MinusAssign      => Some(impl_op!($x -= $yy)),
-                    Token::MultiplyAssign   => Some(impl_op!($x *= $yy)),
-                    Token::DivideAssign     => Some(impl_op!($x /= $yy)),
-                    Token::ModuloAssign     => Some(impl_op!($x %= $yy)),
-                    Token::PowerOfAssign    => Some(impl_op!($x => $xx.powf($yy as $x))),
+                    Token::PlusAssign       => Some(impl_float!(@arith $($mixed)? impl_op!($x += $yy))),
+                    Token::MinusAssign      => Some(impl_float!(@arith $($mixed)? impl_op!($x -= $yy))),
+                    Token::MultiplyAssign   => Some(impl_float!(@arith $($mixed)? impl_op!($x *= $yy))),
+                    Token::DivideAssign     => Some(impl_float!(@arith $($mixed)? impl_op!($x /= $yy))),
+                    Token::ModuloAssign     => Some(impl_float!(@arith $($mixed)? impl_op!($x %= $yy))),
+                    Token::PowerOfAssign    => Some(impl_float!(@arith $($mixed)? impl_op!($x => $xx.powf($yy as $x)))),
                     _                       => None,
                 };
             }
@@ -689,7 +728,7 @@ pub fn get_builtin_op_assignment_fn(op: &Token, x: &Dynamic, y: &Dynamic) -> Opt
     #[cfg(not(feature = "no_float"))]
     {
         impl_float!(FLOAT, as_float, FLOAT, as_float);
-        impl_float!(FLOAT, as_float, INT, as_int);
+        impl_float!(FLOAT, as_float, INT, as_int, mixed);
     }
 
     #[cfg(feature = "decimal")]
@@ -9,7 +9,7 @@ use crate::engine::{
     KEYWORD_IS_DEF_VAR, KEYWORD_PRINT, KEYWORD_TYPE_OF,
 };
 use crate::eval::{Caches, FnResolutionCacheEntry, GlobalRuntimeState};
-use crate::tokenizer::Token;
+use crate::tokenizer::{is_valid_function_name, Token};
 use crate::{
     calc_fn_hash, calc_fn_params_hash, combine_hashes, Dynamic, Engine, FnArgsVec, FnPtr,
     ImmutableString, Module, OptimizationLevel, Position, RhaiError, RhaiResult, RhaiResultOf,
@@ -83,7 +83,32 @@ impl<'a> ArgBackup<'a> {
             mem::transmute(&mut self.value_copy)
         }));
     }
-    /// This function restores the first argument that was replaced by `change_first_arg_to_copy`.
+    /// This function replaces the first argument of a method call with the value moved out of it,
+    /// leaving `()` behind.
+    /// This is for values that cannot be cloned, which a pure function consumes.
+    ///
+    /// `restore_first_arg` must be called before the end of the scope to prevent the shorter
+    /// lifetime from leaking.
+    ///
+    /// # Safety
+    ///
+    /// See [`change_first_arg_to_copy`][ArgBackup::change_first_arg_to_copy].
+    ///
+    /// # Panics
+    ///
+    /// Panics when `args` is empty.
+    #[inline(always)]
+    pub fn change_first_arg_to_moved(&mut self, args: &mut FnCallArgs<'a>) {
+        // Move the original value.
+        self.value_copy = mem::take(args[0]);
+
+        // SAFETY: see `change_first_arg_to_copy`.
+        self.orig_mut = Some(mem::replace(&mut args[0], unsafe {
+            mem::transmute(&mut self.value_copy)
+        }));
+    }
+    /// This function restores the first argument that was replaced by `change_first_arg_to_copy`
+    /// or `change_first_arg_to_moved`.
     ///
     /// # Safety
     ///
@@ -280,7 +305,11 @@ impl Engine {
 
                         // Try to find a built-in version
                         let builtin = args.and_then(|args| {
-                            if let Some(op_assign) = op_assignment_token {
+                            if op_assignment_token.is_some() && !self.fast_operators() {
+                                // Not in fast operators mode - let the caller expand to
+                                // `var = var op rhs` so that any override of `op` is called
+                                None
+                            } else if let Some(op_assign) = op_assignment_token {
                                 let (first_arg, rest_args) = args.split_first().unwrap();
 
                                 get_builtin_op_assignment_fn(op_assign, *first_arg, rest_args[0])
@@ -334,6 +363,26 @@ impl Engine {
         }
     }
 
+    /// Replace the first argument of a call with a copy, so that it is not consumed.
+    ///
+    /// Values that cannot be cloned are moved out of the argument instead, raising an error if
+    /// the argument is a constant.
+    fn backup_first_arg<'a>(
+        &self,
+        backup: &mut ArgBackup<'a>,
+        args: &mut FnCallArgs<'a>,
+        pos: Position,
+    ) -> RhaiResultOf<()> {
+        if !self.is_no_clone_value(args[0]) {
+            backup.change_first_arg_to_copy(args);
+        } else if args[0].is_read_only() {
+            return Err(self.make_no_clone_err(args[0].type_name(), pos));
+        } else {
+            backup.change_first_arg_to_moved(args);
+        }
+        Ok(())
+    }
+
     /// # Main Entry-Point
     ///
     /// Call a native Rust function registered with the [`Engine`].
@@ -397,8 +446,14 @@ impl Engine {
 
                 // Calling pure function but the first argument is a reference?
                 if is_ref_mut && func.is_pure() && !args.is_empty() {
-                    // Clone the first argument
-                    backup.change_first_arg_to_copy(args);
+                    self.backup_first_arg(&mut backup, args, pos)?;
+                } else if is_ref_mut
+                    && !args.is_empty()
+                    && args[0].is_read_only()
+                    && self.is_no_clone_value(args[0])
+                {
+                    // A constant that cannot be copied must not be modified in place either
+                    return Err(ERR::ErrorAssignmentToConstant(String::new(), pos).into());
                 }
 
                 let source = match (source, parent_source.as_str()) {
@@ -417,6 +472,8 @@ impl Engine {
                     );
                 }
 
+                global.count_fn_call();
+
                 // Run external function
                 let context = (self, name, source, &*global, lib, pos, level).into();
 
@@ -554,6 +611,20 @@ impl Engine {
                 .into())
             }
 
+            // Try the handler registered via `Engine::on_missing_fn`
+            _ if self.missing_fn_handler.is_some() && is_valid_function_name(name) => {
+                match (self.missing_fn_handler.as_ref().unwrap())(name, args) {
+                    Some(result) => result
+                        .map(|r| (r, false))
+                        .map_err(|err| err.fill_position(pos)),
+                    None => Err(ERR::ErrorFunctionNotFound(
+                        gen_fn_call_signature(self, name, args),
+                        pos,
+                    )
+                    .into()),
+                }
+            }
+
             // Raise error
             _ => {
                 Err(ERR::ErrorFunctionNotFound(gen_fn_call_signature(self, name, args), pos).into())
@@ -561,6 +632,20 @@ impl Engine {
         }
     }
 
+    /// Run the function call interceptor registered via [`Engine::on_fn_call`], if any.
+    ///
+    /// Returns `Some` if the call is replaced by the interceptor.
+    #[inline]
+    fn intercept_fn_call(&self, fn_name: &str, args: &FnCallArgs) -> Option<Dynamic> {
+        self.fn_call_interceptor.as_ref().and_then(|callback| {
+            let values = args
+                .iter()
+                .map(|a| a.flatten_clone())
+                .collect::<FnArgsVec<_>>();
+            callback(fn_name, &values)
+        })
+    }
+
     /// # Main Entry-Point
     ///
     /// Perform an actual function call, native Rust or scripted, taking care of special functions.
@@ -643,6 +728,11 @@ impl Engine {
             _ => (),
         }
 
+        // Intercepted by the host?
+        if let Some(value) = self.intercept_fn_call(fn_name, args) {
+            return Ok((value, false));
+        }
+
         let level = level + 1;
 
         // Script-defined function call?
@@ -710,13 +800,16 @@ impl Engine {
                 let mut backup = ArgBackup::new();
 
                 // The first argument is a reference?
-                if is_ref_mut && !args.is_empty() {
-                    backup.change_first_arg_to_copy(args);
+                let result = if is_ref_mut && !args.is_empty() {
+                    self.backup_first_arg(&mut backup, args, pos)
+                } else {
+                    Ok(())
                 }
-
-                let result = self.call_script_fn(
-                    scope, global, caches, lib, &mut None, func, args, true, pos, level,
-                );
+                .and_then(|_| {
+                    self.call_script_fn(
+                        scope, global, caches, lib, &mut None, func, args, true, pos, level,
+                    )
+                });
 
                 // Restore the original reference
                 backup.restore_first_arg(args);
@@ -754,7 +847,7 @@ impl Engine {
         )
         .or_else(|err| match *err {
             ERR::Return(out, ..) => Ok(out),
-            ERR::LoopBreak(..) => {
+            ERR::LoopBreak(..) | ERR::LoopBreakWithValue(..) => {
                 unreachable!("no outer loop scope to break out of")
             }
             _ => Err(err),
@@ -814,7 +907,7 @@ impl Engine {
     ) -> RhaiResultOf<(Dynamic, bool)> {
         let is_ref_mut = target.is_ref();
 
-        let (result, updated) = match fn_name {
+        let (mut result, updated) = match fn_name {
             KEYWORD_FN_PTR_CALL if target.is::<FnPtr>() => {
                 // FnPtr call
                 let fn_ptr = target.read_lock::<FnPtr>().expect("`FnPtr`");
@@ -984,6 +1077,8 @@ impl Engine {
             target.propagate_changed_value(fn_call_pos)?;
         }
 
+        self.stamp_value_tag(&mut result);
+
         Ok((result, updated))
     }
 
@@ -1225,15 +1320,17 @@ impl Engine {
                         .map(|(value, ..)| arg_values.push(value.flatten()))
                 })?;
 
-                let (mut target, _pos) =
+                let (mut target, first_pos) =
                     self.search_namespace(scope, global, lib, this_ptr, first_expr, level)?;
 
-                if target.is_read_only() {
+                // Values that cannot be cloned are passed by reference; constants are then
+                // rejected (raising an error) when the function is called
+                if target.is_read_only() && !self.is_no_clone_value(&target) {
                     target = target.into_owned();
                 }
 
                 #[cfg(not(feature = "unchecked"))]
-                self.inc_operations(&mut global.num_operations, _pos)?;
+                self.inc_operations(&mut global.num_operations, first_pos)?;
 
                 #[cfg(not(feature = "no_closure"))]
                 let target_is_shared = target.is_shared();
@@ -1241,7 +1338,7 @@ impl Engine {
                 let target_is_shared = false;
 
                 if target_is_shared || target.is_temp_value() {
-                    arg_values.insert(0, target.take_or_clone().flatten());
+                    arg_values.insert(0, self.take_or_move_target(target, first_pos)?.flatten());
                 } else {
                     // Turn it into a method call only if the object is not shared and not a simple value
                     is_ref_mut = true;
@@ -1309,11 +1406,11 @@ impl Engine {
 
                 // Get target reference to first argument
                 let first_arg = &args_expr[0];
-                let (target, _pos) =
+                let (target, first_pos) =
                     self.search_scope_only(scope, global, lib, this_ptr, first_arg, level)?;
 
                 #[cfg(not(feature = "unchecked"))]
-                self.inc_operations(&mut global.num_operations, _pos)?;
+                self.inc_operations(&mut global.num_operations, first_pos)?;
 
                 #[cfg(not(feature = "no_closure"))]
                 let target_is_shared = target.is_shared();
@@ -1321,7 +1418,7 @@ impl Engine {
                 let target_is_shared = false;
 
                 if target_is_shared || target.is_temp_value() {
-                    arg_values[0] = target.take_or_clone().flatten();
+                    arg_values[0] = self.take_or_move_target(target, first_pos)?.flatten();
                     args.extend(arg_values.iter_mut());
                 } else {
                     // Turn it into a method call only if the object is not shared and not a simple value
@@ -1341,6 +1438,11 @@ impl Engine {
             }
         }
 
+        // Intercepted by the host?
+        if let Some(value) = self.intercept_fn_call(fn_name, &args) {
+            return Ok(value);
+        }
+
         // Search for the root namespace
         let module = self
             .search_imports(global, namespace)
@@ -1399,7 +1501,14 @@ impl Engine {
         // Clone first argument if the function is not a method after-all
         if !func.map_or(true, CallableFunction::is_method) {
             if let Some(first) = first_arg_value {
-                *first = args[0].clone();
+                *first = if !self.is_no_clone_value(args[0]) {
+                    args[0].clone()
+                } else if args[0].is_read_only() {
+                    return Err(self.make_no_clone_err(args[0].type_name(), pos));
+                } else {
+                    // Values that cannot be cloned are moved out of the variable instead
+                    mem::take(args[0])
+                };
                 args[0] = first;
             }
         }
@@ -1424,6 +1533,7 @@ impl Engine {
             }
 
             Some(f) if f.is_plugin_fn() => {
+                global.count_fn_call();
                 let context = (self, fn_name, module.id(), &*global, lib, pos, level).into();
                 let result = f
                     .get_plugin_fn()
@@ -1434,6 +1544,7 @@ impl Engine {
             }
 
             Some(f) if f.is_native() => {
+                global.count_fn_call();
                 let func = f.get_native_fn().expect("native function");
                 let context = (self, fn_name, module.id(), &*global, lib, pos, level).into();
                 let result = func(context, &mut args);
@@ -24,7 +24,8 @@ pub use hashing::{
 };
 pub use native::{
     locked_read, locked_write, shared_get_mut, shared_make_mut, shared_take, shared_take_or_clone,
-    shared_try_take, FnAny, FnPlugin, IteratorFn, Locked, NativeCallContext, SendSync, Shared,
+    shared_try_take, FnAny, FnPlugin, IteratorFn, LazySequenceSource, Locked, NativeCallContext,
+    SendSync, Shared,
 };
 pub use plugin::PluginFunction;
-pub use register::RegisterNativeFunction;
+pub use register::{RegisterFallibleFunction, RegisterLazyFunction, RegisterNativeFunction};
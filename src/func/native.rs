@@ -7,7 +7,7 @@ use crate::plugin::PluginFunction;
 use crate::tokenizer::{Token, TokenizeState};
 use crate::types::dynamic::Variant;
 use crate::{
-    calc_fn_hash, Dynamic, Engine, EvalContext, FuncArgs, Module, Position, RhaiResult,
+    calc_fn_hash, Dynamic, Engine, EvalContext, FuncArgs, LogLevel, Module, Position, RhaiResult,
     RhaiResultOf, StaticVec, VarDefInfo, ERR,
 };
 use std::any::type_name;
@@ -187,9 +187,42 @@ impl<'a> NativeCallContext<'a> {
     /// The current [`Engine`].
     #[inline(always)]
     #[must_use]
-    pub const fn engine(&self) -> &Engine {
+    pub const fn engine(&self) -> &'a Engine {
         self.engine
     }
+    /// Queue a registration (e.g. of a new function or type) to be performed on the [`Engine`].
+    ///
+    /// The [`Engine`] cannot be modified during an evaluation, so the registration is deferred.
+    /// It does **not** take effect until the current evaluation returns and the host calls
+    /// [`Engine::apply_queued_registrations`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, NativeCallContext, INT};
+    ///
+    /// let mut engine = Engine::new();
+    ///
+    /// engine.register_fn("load_plugin", |ctx: NativeCallContext| {
+    ///     ctx.queue_registration(|engine| {
+    ///         engine.register_fn("plugin_fn", || 42 as INT);
+    ///     });
+    /// });
+    ///
+    /// engine.run("load_plugin()")?;
+    ///
+    /// // The registration is only performed now
+    /// assert_eq!(engine.apply_queued_registrations(), 1);
+    ///
+    /// assert_eq!(engine.eval::<INT>("plugin_fn()")?, 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn queue_registration(&self, callback: impl FnOnce(&mut Engine) + SendSync + 'static) {
+        locked_write(&self.engine.queued_registrations).push(Box::new(callback));
+    }
     /// Name of the function called.
     #[inline(always)]
     #[must_use]
@@ -432,6 +465,13 @@ pub type IteratorFn = dyn Fn(Dynamic) -> Box<dyn Iterator<Item = RhaiResultOf<Dy
 pub type IteratorFn =
     dyn Fn(Dynamic) -> Box<dyn Iterator<Item = RhaiResultOf<Dynamic>>> + Send + Sync;
 
+/// Rust iterator driving a lazy sequence.
+#[cfg(not(feature = "sync"))]
+pub type LazySequenceSource = dyn Iterator<Item = Dynamic>;
+/// Rust iterator driving a lazy sequence.
+#[cfg(feature = "sync")]
+pub type LazySequenceSource = dyn Iterator<Item = Dynamic> + Send + Sync;
+
 #[cfg(not(feature = "sync"))]
 pub type FnPlugin = dyn PluginFunction;
 #[cfg(feature = "sync")]
@@ -440,11 +480,11 @@ pub type FnPlugin = dyn PluginFunction + Send + Sync;
 /// Callback function for progress reporting.
 #[cfg(not(feature = "unchecked"))]
 #[cfg(not(feature = "sync"))]
-pub type OnProgressCallback = dyn Fn(u64) -> Option<Dynamic>;
+pub type OnProgressCallback = dyn Fn(u64) -> crate::api::events::ProgressAction;
 /// Callback function for progress reporting.
 #[cfg(not(feature = "unchecked"))]
 #[cfg(feature = "sync")]
-pub type OnProgressCallback = dyn Fn(u64) -> Option<Dynamic> + Send + Sync;
+pub type OnProgressCallback = dyn Fn(u64) -> crate::api::events::ProgressAction + Send + Sync;
 
 /// Callback function for printing.
 #[cfg(not(feature = "sync"))]
@@ -453,6 +493,27 @@ pub type OnPrintCallback = dyn Fn(&str);
 #[cfg(feature = "sync")]
 pub type OnPrintCallback = dyn Fn(&str) + Send + Sync;
 
+/// Callback function for logging.
+#[cfg(not(feature = "sync"))]
+pub type OnLogCallback = dyn Fn(LogLevel, &str);
+/// Callback function for logging.
+#[cfg(feature = "sync")]
+pub type OnLogCallback = dyn Fn(LogLevel, &str) + Send + Sync;
+
+/// Callback function to compare two map keys of a custom type for equality.
+#[cfg(not(feature = "sync"))]
+pub type OnMapKeyEqCallback = dyn Fn(&Dynamic, &Dynamic) -> bool;
+/// Callback function to compare two map keys of a custom type for equality.
+#[cfg(feature = "sync")]
+pub type OnMapKeyEqCallback = dyn Fn(&Dynamic, &Dynamic) -> bool + Send + Sync;
+
+/// Callback function to hash a map key of a custom type.
+#[cfg(not(feature = "sync"))]
+pub type OnMapKeyHashCallback = dyn Fn(&Dynamic) -> u64;
+/// Callback function to hash a map key of a custom type.
+#[cfg(feature = "sync")]
+pub type OnMapKeyHashCallback = dyn Fn(&Dynamic) -> u64 + Send + Sync;
+
 /// Callback function for debugging.
 #[cfg(not(feature = "sync"))]
 pub type OnDebugCallback = dyn Fn(&str, Option<&str>, Position);
@@ -467,6 +528,13 @@ pub type OnParseTokenCallback = dyn Fn(Token, Position, &TokenizeState) -> Token
 #[cfg(feature = "sync")]
 pub type OnParseTokenCallback = dyn Fn(Token, Position, &TokenizeState) -> Token + Send + Sync;
 
+/// Callback function for parsing number literals during parsing.
+#[cfg(not(feature = "sync"))]
+pub type OnParseNumberCallback = dyn Fn(&str) -> Option<Dynamic>;
+/// Callback function for parsing number literals during parsing.
+#[cfg(feature = "sync")]
+pub type OnParseNumberCallback = dyn Fn(&str) -> Option<Dynamic> + Send + Sync;
+
 /// Callback function for variable access.
 #[cfg(not(feature = "sync"))]
 pub type OnVarCallback = dyn Fn(&str, usize, EvalContext) -> RhaiResultOf<Option<Dynamic>>;
@@ -475,6 +543,34 @@ pub type OnVarCallback = dyn Fn(&str, usize, EvalContext) -> RhaiResultOf<Option
 pub type OnVarCallback =
     dyn Fn(&str, usize, EvalContext) -> RhaiResultOf<Option<Dynamic>> + Send + Sync;
 
+/// Callback function for intercepting function calls.
+#[cfg(not(feature = "sync"))]
+pub type OnFnCallCallback = dyn Fn(&str, &[Dynamic]) -> Option<Dynamic>;
+/// Callback function for intercepting function calls.
+#[cfg(feature = "sync")]
+pub type OnFnCallCallback = dyn Fn(&str, &[Dynamic]) -> Option<Dynamic> + Send + Sync;
+
+/// Callback function for handling calls to functions that are not found.
+#[cfg(not(feature = "sync"))]
+pub type OnMissingFnCallback = dyn Fn(&str, &mut FnCallArgs) -> Option<RhaiResult>;
+/// Callback function for handling calls to functions that are not found.
+#[cfg(feature = "sync")]
+pub type OnMissingFnCallback = dyn Fn(&str, &mut FnCallArgs) -> Option<RhaiResult> + Send + Sync;
+
+/// Callback function for a registration queued from within a native function.
+#[cfg(not(feature = "sync"))]
+pub type OnRegisterCallback = dyn FnOnce(&mut Engine);
+/// Callback function for a registration queued from within a native function.
+#[cfg(feature = "sync")]
+pub type OnRegisterCallback = dyn FnOnce(&mut Engine) + Send + Sync;
+
+/// Callback function for building a lazily-initialized module.
+#[cfg(not(feature = "sync"))]
+pub type OnLazyModuleCallback = dyn Fn() -> Module;
+/// Callback function for building a lazily-initialized module.
+#[cfg(feature = "sync")]
+pub type OnLazyModuleCallback = dyn Fn() -> Module + Send + Sync;
+
 /// Callback function for variable definition.
 #[cfg(not(feature = "sync"))]
 pub type OnDefVarCallback = dyn Fn(bool, VarDefInfo, EvalContext) -> RhaiResultOf<bool>;
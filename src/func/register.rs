@@ -6,10 +6,10 @@ use super::call::FnCallArgs;
 use super::callable_function::CallableFunction;
 use super::native::{SendSync, Shared};
 use crate::types::dynamic::{DynamicWriteLock, Variant};
-use crate::{reify, Dynamic, NativeCallContext, RhaiResultOf};
+use crate::{reify, Dynamic, Engine, LazySequence, NativeCallContext, RhaiResultOf};
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
-use std::{any::TypeId, mem};
+use std::{any::TypeId, borrow::Cow, fmt, mem};
 
 /// These types are used to build a unique _marker_ tuple type for each combination
 /// of function parameter types in order to make each trait implementation unique.
@@ -33,31 +33,331 @@ pub struct Mut<T>(T);
 
 /// Dereference into [`DynamicWriteLock`]
 #[inline(always)]
-#[must_use]
-pub fn by_ref<T: Variant + Clone>(data: &mut Dynamic) -> DynamicWriteLock<T> {
+pub fn by_ref<T: Variant + Clone>(data: &mut Dynamic) -> RhaiResultOf<DynamicWriteLock<T>> {
     // Directly cast the &mut Dynamic into DynamicWriteLock to access the underlying data.
-    data.write_lock::<T>().expect("checked")
+    Ok(data.write_lock::<T>().expect("checked"))
 }
 
 /// Dereference into value.
 #[inline(always)]
-#[must_use]
-pub fn by_value<T: Variant + Clone>(data: &mut Dynamic) -> T {
+pub fn by_value<T: Variant + Clone>(data: &mut Dynamic) -> RhaiResultOf<T> {
     if TypeId::of::<T>() == TypeId::of::<&str>() {
-        // If T is `&str`, data must be `ImmutableString`, so map directly to it
+        // If T is `&str`, data must be `ImmutableString`, so borrow directly from it.
+        // Flattening a shared value only clones the `ImmutableString` handle, not the string itself.
         data.flatten_in_place();
         let ref_str = data.as_str_ref().expect("&str");
         // SAFETY: We already checked that `T` is `&str`, so it is safe to cast here.
-        return unsafe { mem::transmute_copy::<_, T>(&ref_str) };
+        return Ok(unsafe { mem::transmute_copy::<_, T>(&ref_str) });
     }
     if TypeId::of::<T>() == TypeId::of::<String>() {
         // If T is `String`, data must be `ImmutableString`, so map directly to it
-        return reify!(mem::take(data).into_string().expect("`ImmutableString`") => T);
+        return Ok(reify!(mem::take(data).into_string().expect("`ImmutableString`") => T));
+    }
+
+    // If T is a slice, data must be an `Array`, so convert it
+    #[cfg(not(feature = "no_index"))]
+    if is_array_slice(TypeId::of::<T>()) {
+        return array_to_slice(data);
+    }
+
+    // If T is a fixed-size array, data must be an `Array`, so convert it
+    #[cfg(not(feature = "no_index"))]
+    if is_fixed_array(TypeId::of::<T>()) {
+        return array_to_fixed(data);
+    }
+
+    // If T is an `Option`, data can be `()` or any value, so convert it
+    if is_option(TypeId::of::<T>()) {
+        return dynamic_to_option(data);
     }
 
     // We consume the argument and then replace it with () - the argument is not supposed to be used again.
     // This way, we avoid having to clone the argument again, because it is already a clone when passed here.
-    mem::take(data).cast::<T>()
+    Ok(mem::take(data).cast::<T>())
+}
+
+/// Is the type a slice parameter (e.g. `&[INT]`) which takes an [`Array`][crate::Array]?
+#[cfg(not(feature = "no_index"))]
+#[inline]
+#[must_use]
+pub fn is_array_slice(type_id: TypeId) -> bool {
+    if type_id == TypeId::of::<&[crate::INT]>()
+        || type_id == TypeId::of::<&[bool]>()
+        || type_id == TypeId::of::<&[char]>()
+        || type_id == TypeId::of::<&[crate::ImmutableString]>()
+        || type_id == TypeId::of::<&[Dynamic]>()
+    {
+        return true;
+    }
+    #[cfg(not(feature = "no_float"))]
+    if type_id == TypeId::of::<&[crate::FLOAT]>() {
+        return true;
+    }
+
+    false
+}
+
+/// Run `$action!(E, N)` for the fixed-size array type `[E; N]` with the specified [`TypeId`], if
+/// any, for each element type and each length up to 16.
+#[cfg(not(feature = "no_index"))]
+macro_rules! match_fixed_array {
+    ($type_id:expr => $action:ident) => {
+        match_fixed_array!(@types $type_id => $action;
+            crate::INT, bool, char, crate::ImmutableString, Dynamic
+        );
+        #[cfg(not(feature = "no_float"))]
+        match_fixed_array!(@types $type_id => $action; crate::FLOAT);
+    };
+    (@types $type_id:expr => $action:ident; $($elem:ty),+) => {
+        $(
+            match_fixed_array!(@lens $type_id => $action; $elem;
+                1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+            );
+        )+
+    };
+    (@lens $type_id:expr => $action:ident; $elem:ty; $($n:literal)+) => {
+        $(
+            if $type_id == TypeId::of::<[$elem; $n]>() {
+                $action!($elem, $n);
+            }
+        )+
+    };
+}
+
+/// Is the type a fixed-size array parameter (e.g. `[FLOAT; 3]`) which takes an
+/// [`Array`][crate::Array]?
+///
+/// Arrays of up to 16 elements of type `INT`, `FLOAT`, `bool`, `char`, `ImmutableString` or
+/// `Dynamic` are supported.
+#[cfg(not(feature = "no_index"))]
+#[inline]
+#[must_use]
+pub fn is_fixed_array(type_id: TypeId) -> bool {
+    macro_rules! found {
+        ($elem:ty, $n:literal) => {
+            return true
+        };
+    }
+
+    match_fixed_array!(type_id => found);
+
+    false
+}
+
+/// Is the type an optional parameter (e.g. `Option<INT>`) which takes either `()` or a value?
+#[inline]
+#[must_use]
+pub fn is_option(type_id: TypeId) -> bool {
+    if type_id == TypeId::of::<Option<crate::INT>>()
+        || type_id == TypeId::of::<Option<bool>>()
+        || type_id == TypeId::of::<Option<char>>()
+        || type_id == TypeId::of::<Option<crate::ImmutableString>>()
+        || type_id == TypeId::of::<Option<String>>()
+    {
+        return true;
+    }
+    #[cfg(not(feature = "no_float"))]
+    if type_id == TypeId::of::<Option<crate::FLOAT>>() {
+        return true;
+    }
+    #[cfg(not(feature = "no_index"))]
+    if type_id == TypeId::of::<Option<crate::Array>>() {
+        return true;
+    }
+    #[cfg(not(feature = "no_object"))]
+    if type_id == TypeId::of::<Option<crate::Map>>() {
+        return true;
+    }
+
+    false
+}
+
+/// Convert an argument into an optional parameter of type `T` (e.g. `Option<INT>`).
+///
+/// `()` converts into `None`, while any other value converts into `Some`.
+fn dynamic_to_option<T: Variant + Clone>(data: &mut Dynamic) -> RhaiResultOf<T> {
+    fn convert<E: Variant + Clone, T: Variant + Clone>(data: &mut Dynamic) -> RhaiResultOf<T> {
+        use crate::api::type_names::map_std_type_name;
+
+        let value = mem::take(data);
+
+        let value: Option<E> = if value.is_unit() {
+            None
+        } else {
+            let typ = value.type_name();
+
+            Some(value.try_cast::<E>().ok_or_else(|| {
+                crate::ERR::ErrorMismatchDataType(
+                    map_std_type_name(std::any::type_name::<E>(), true).into(),
+                    typ.into(),
+                    crate::Position::NONE,
+                )
+            })?)
+        };
+
+        Ok(reify!(value => T))
+    }
+
+    let type_id = TypeId::of::<T>();
+
+    if type_id == TypeId::of::<Option<crate::INT>>() {
+        return convert::<crate::INT, T>(data);
+    }
+    #[cfg(not(feature = "no_float"))]
+    if type_id == TypeId::of::<Option<crate::FLOAT>>() {
+        return convert::<crate::FLOAT, T>(data);
+    }
+    if type_id == TypeId::of::<Option<bool>>() {
+        return convert::<bool, T>(data);
+    }
+    if type_id == TypeId::of::<Option<char>>() {
+        return convert::<char, T>(data);
+    }
+    if type_id == TypeId::of::<Option<String>>() {
+        return convert::<String, T>(data);
+    }
+    #[cfg(not(feature = "no_index"))]
+    if type_id == TypeId::of::<Option<crate::Array>>() {
+        return convert::<crate::Array, T>(data);
+    }
+    #[cfg(not(feature = "no_object"))]
+    if type_id == TypeId::of::<Option<crate::Map>>() {
+        return convert::<crate::Map, T>(data);
+    }
+
+    convert::<crate::ImmutableString, T>(data)
+}
+
+/// Convert an [`Array`][crate::Array] argument into a slice of type `T` (e.g. `&[INT]`).
+///
+/// The elements are converted into a temporary [`Vec`] which replaces the argument, and which the
+/// returned slice refers to.
+#[cfg(not(feature = "no_index"))]
+fn array_to_slice<T: Variant + Clone>(data: &mut Dynamic) -> RhaiResultOf<T> {
+    fn convert<E: Variant + Clone, T: Variant + Clone>(data: &mut Dynamic) -> RhaiResultOf<T> {
+        use crate::api::type_names::map_std_type_name;
+
+        let values = mem::take(data)
+            .cast::<crate::Array>()
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let typ = value.type_name();
+                value.try_cast::<E>().ok_or_else(|| {
+                    crate::ERR::ErrorMismatchDataType(
+                        map_std_type_name(std::any::type_name::<E>(), true).into(),
+                        format!("{typ} at index {i}"),
+                        crate::Position::NONE,
+                    )
+                    .into()
+                })
+            })
+            .collect::<RhaiResultOf<Vec<E>>>()?;
+
+        *data = Dynamic::from(values);
+        let slice = data.downcast_ref::<Vec<E>>().expect("`Vec`").as_slice();
+        // SAFETY: We already checked that `T` is `&[E]`, so it is safe to cast here.
+        // The slice refers to the argument itself, which outlives the function call.
+        Ok(unsafe { mem::transmute_copy::<_, T>(&slice) })
+    }
+
+    let type_id = TypeId::of::<T>();
+
+    if type_id == TypeId::of::<&[crate::INT]>() {
+        return convert::<crate::INT, T>(data);
+    }
+    #[cfg(not(feature = "no_float"))]
+    if type_id == TypeId::of::<&[crate::FLOAT]>() {
+        return convert::<crate::FLOAT, T>(data);
+    }
+    if type_id == TypeId::of::<&[bool]>() {
+        return convert::<bool, T>(data);
+    }
+    if type_id == TypeId::of::<&[char]>() {
+        return convert::<char, T>(data);
+    }
+    if type_id == TypeId::of::<&[crate::ImmutableString]>() {
+        return convert::<crate::ImmutableString, T>(data);
+    }
+
+    convert::<Dynamic, T>(data)
+}
+
+/// Convert an [`Array`][crate::Array] argument into a fixed-size array of type `T` (e.g.
+/// `[FLOAT; 3]`).
+///
+/// An error is raised if the length of the array is not the length of `T`.
+#[cfg(not(feature = "no_index"))]
+fn array_to_fixed<T: Variant + Clone>(data: &mut Dynamic) -> RhaiResultOf<T> {
+    fn convert<E: Variant + Clone, const N: usize, T: Variant + Clone>(
+        data: &mut Dynamic,
+    ) -> RhaiResultOf<T> {
+        use crate::api::type_names::map_std_type_name;
+        use std::convert::TryInto;
+
+        let array = mem::take(data).cast::<crate::Array>();
+
+        if array.len() != N {
+            return Err(crate::ERR::ErrorMismatchDataType(
+                format!("array of length {N}"),
+                format!("array of length {}", array.len()),
+                crate::Position::NONE,
+            )
+            .into());
+        }
+
+        let values = array
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let typ = value.type_name();
+                value.try_cast::<E>().ok_or_else(|| {
+                    crate::ERR::ErrorMismatchDataType(
+                        map_std_type_name(std::any::type_name::<E>(), true).into(),
+                        format!("{typ} at index {i}"),
+                        crate::Position::NONE,
+                    )
+                    .into()
+                })
+            })
+            .collect::<RhaiResultOf<Vec<E>>>()?;
+
+        let values: [E; N] = values
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("length checked"));
+        Ok(reify!(values => T))
+    }
+
+    macro_rules! to_fixed {
+        ($elem:ty, $n:literal) => {
+            return convert::<$elem, $n, T>(data)
+        };
+    }
+
+    match_fixed_array!(TypeId::of::<T>() => to_fixed);
+
+    unreachable!(
+        "{} is not a supported fixed-size array",
+        std::any::type_name::<T>()
+    )
+}
+
+/// Map a function's return value into [`Dynamic`].
+///
+/// Static string slices (`&'static str` and `Cow::Borrowed`) are usually constant labels, so they
+/// are interned instead of being allocated anew on every call.
+#[inline(always)]
+#[must_use]
+fn into_dynamic<T: Variant + Clone>(engine: &Engine, value: T) -> Dynamic {
+    reify!(value, |v: &str| return engine.get_interned_string(v).into());
+    reify!(value, |v: Cow<'static, str>| {
+        return match v {
+            Cow::Borrowed(s) => engine.get_interned_string(s).into(),
+            Cow::Owned(s) => s.into(),
+        };
+    });
+
+    Dynamic::from(value)
 }
 
 /// Trait to register custom Rust functions.
@@ -90,6 +390,55 @@ pub trait RegisterNativeFunction<ARGS, RET, RESULT> {
     fn return_type_name() -> &'static str;
 }
 
+/// Trait to register custom Rust functions returning iterators as [lazy sequences][LazySequence].
+///
+/// # Type Parameters
+///
+/// * `ARGS` - a tuple containing parameter types, with `&mut T` represented by `Mut<T>`.
+pub trait RegisterLazyFunction<ARGS> {
+    /// Convert this function into a [`CallableFunction`].
+    #[must_use]
+    fn into_callable_function(self) -> CallableFunction;
+    /// Get the type ID's of this function's parameters.
+    #[must_use]
+    fn param_types() -> Box<[TypeId]>;
+    /// _(metadata)_ Get the type names of this function's parameters.
+    /// Exported under the `metadata` feature only.
+    #[cfg(feature = "metadata")]
+    #[must_use]
+    fn param_names() -> Box<[&'static str]>;
+}
+
+/// Trait to register custom Rust functions returning [`Result`] with any displayable error type.
+///
+/// # Type Parameters
+///
+/// * `ARGS` - a tuple containing parameter types, with `&mut T` represented by `Mut<T>`.
+/// * `RET` - the type of the `Ok` value returned by the function.
+pub trait RegisterFallibleFunction<ARGS, RET> {
+    /// Convert this function into a [`CallableFunction`].
+    #[must_use]
+    fn into_callable_function(self) -> CallableFunction;
+    /// Get the type ID's of this function's parameters.
+    #[must_use]
+    fn param_types() -> Box<[TypeId]>;
+    /// _(metadata)_ Get the type names of this function's parameters.
+    /// Exported under the `metadata` feature only.
+    #[cfg(feature = "metadata")]
+    #[must_use]
+    fn param_names() -> Box<[&'static str]>;
+    /// _(metadata)_ Get the type ID of this function's return value.
+    /// Exported under the `metadata` feature only.
+    #[cfg(feature = "metadata")]
+    #[must_use]
+    fn return_type() -> TypeId;
+    /// _(metadata)_ Get the type name of this function's return value.
+    /// Exported under the `metadata` feature only.
+    #[cfg(feature = "metadata")]
+    #[must_use]
+    fn return_type_name() -> &'static str;
+}
+
 const EXPECT_ARGS: &str = "arguments";
 
 macro_rules! check_constant {
@@ -145,13 +494,13 @@ macro_rules! def_register {
                     check_constant!(_ctx, args);
 
                     let mut _drain = args.iter_mut();
-                    $($let $par = ($clone)(_drain.next().expect(EXPECT_ARGS)); )*
+                    $($let $par = ($clone)(_drain.next().expect(EXPECT_ARGS))?; )*
 
                     // Call the function with each argument value
                     let r = self($($arg),*);
 
                     // Map the result
-                    Ok(Dynamic::from(r))
+                    Ok(into_dynamic(_ctx.engine(), r))
                 }))
             }
         }
@@ -171,13 +520,15 @@ macro_rules! def_register {
                     check_constant!(ctx, args);
 
                     let mut _drain = args.iter_mut();
-                    $($let $par = ($clone)(_drain.next().expect(EXPECT_ARGS)); )*
+                    $($let $par = ($clone)(_drain.next().expect(EXPECT_ARGS))?; )*
+
+                    let engine = ctx.engine();
 
                     // Call the function with each argument value
                     let r = self(ctx, $($arg),*);
 
                     // Map the result
-                    Ok(Dynamic::from(r))
+                    Ok(into_dynamic(engine, r))
                 }))
             }
         }
@@ -197,10 +548,10 @@ macro_rules! def_register {
                     check_constant!(_ctx, args);
 
                     let mut _drain = args.iter_mut();
-                    $($let $par = ($clone)(_drain.next().expect(EXPECT_ARGS)); )*
+                    $($let $par = ($clone)(_drain.next().expect(EXPECT_ARGS))?; )*
 
                     // Call the function with each argument value
-                    self($($arg),*).map(Dynamic::from)
+                    self($($arg),*).map(|r| into_dynamic(_ctx.engine(), r))
                 }))
             }
         }
@@ -220,10 +571,60 @@ macro_rules! def_register {
                     check_constant!(ctx, args);
 
                     let mut _drain = args.iter_mut();
-                    $($let $par = ($clone)(_drain.next().expect(EXPECT_ARGS)); )*
+                    $($let $par = ($clone)(_drain.next().expect(EXPECT_ARGS))?; )*
+
+                    let engine = ctx.engine();
 
                     // Call the function with each argument value
-                    self(ctx, $($arg),*).map(Dynamic::from)
+                    self(ctx, $($arg),*).map(|r| into_dynamic(engine, r))
+                }))
+            }
+        }
+
+        impl<
+            FN: Fn($($param),*) -> Result<RET, ERROR> + SendSync + 'static,
+            $($par: Variant + Clone,)*
+            RET: Variant + Clone,
+            ERROR: fmt::Display
+        > RegisterFallibleFunction<($($mark,)*), RET> for FN {
+            #[inline(always)] fn param_types() -> Box<[TypeId]> { vec![$(TypeId::of::<$par>()),*].into_boxed_slice() }
+            #[cfg(feature = "metadata")] #[inline(always)] fn param_names() -> Box<[&'static str]> { vec![$(std::any::type_name::<$param>()),*].into_boxed_slice() }
+            #[cfg(feature = "metadata")] #[inline(always)] fn return_type() -> TypeId { TypeId::of::<RET>() }
+            #[cfg(feature = "metadata")] #[inline(always)] fn return_type_name() -> &'static str { std::any::type_name::<RET>() }
+            #[inline(always)] fn into_callable_function(self) -> CallableFunction {
+                CallableFunction::$abi(Shared::new(move |ctx: NativeCallContext, args: &mut FnCallArgs| {
+                    // The arguments are assumed to be of the correct number and types!
+                    check_constant!(ctx, args);
+
+                    let mut _drain = args.iter_mut();
+                    $($let $par = ($clone)(_drain.next().expect(EXPECT_ARGS))?; )*
+
+                    // Call the function with each argument value, mapping any error into a runtime error
+                    match self($($arg),*) {
+                        Ok(r) => Ok(into_dynamic(ctx.engine(), r)),
+                        Err(err) => Err(crate::ERR::ErrorRuntime(err.to_string().into(), ctx.position()).into()),
+                    }
+                }))
+            }
+        }
+
+        impl<
+            FN: Fn($($param),*) -> ITER + SendSync + 'static,
+            $($par: Variant + Clone,)*
+            ITER: IntoIterator<Item = Dynamic>
+        > RegisterLazyFunction<($($mark,)*)> for FN where ITER::IntoIter: SendSync + 'static {
+            #[inline(always)] fn param_types() -> Box<[TypeId]> { vec![$(TypeId::of::<$par>()),*].into_boxed_slice() }
+            #[cfg(feature = "metadata")] #[inline(always)] fn param_names() -> Box<[&'static str]> { vec![$(std::any::type_name::<$param>()),*].into_boxed_slice() }
+            #[inline(always)] fn into_callable_function(self) -> CallableFunction {
+                CallableFunction::$abi(Shared::new(move |_ctx: NativeCallContext, args: &mut FnCallArgs| {
+                    // The arguments are assumed to be of the correct number and types!
+                    check_constant!(_ctx, args);
+
+                    let mut _drain = args.iter_mut();
+                    $($let $par = ($clone)(_drain.next().expect(EXPECT_ARGS))?; )*
+
+                    // Call the function with each argument value, wrapping the iterator
+                    Ok(Dynamic::from(LazySequence::new(self($($arg),*))))
                 }))
             }
         }
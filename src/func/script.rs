@@ -68,10 +68,23 @@ impl Engine {
 
         // Check for stack overflow
         #[cfg(not(feature = "unchecked"))]
-        if level > self.max_call_levels() {
+        let is_closure =
+            self.max_closure_depth() > 0 && crate::parser::is_anonymous_fn(&fn_def.name);
+
+        #[cfg(not(feature = "unchecked"))]
+        if is_closure {
+            if global.closure_depth >= self.max_closure_depth() {
+                return Err(ERR::ErrorClosureStackOverflow(pos).into());
+            }
+        } else if level.saturating_sub(global.closure_levels) > self.max_call_levels() {
             return Err(ERR::ErrorStackOverflow(pos).into());
         }
 
+        if let Some(ref mut stats) = global.stats {
+            stats.fn_calls += 1;
+            stats.max_call_depth = stats.max_call_depth.max(level);
+        }
+
         #[cfg(feature = "debugging")]
         if self.debugger.is_none() && fn_def.body.is_empty() {
             return Ok(Dynamic::UNIT);
@@ -145,6 +158,20 @@ impl Engine {
             self.run_debugger(scope, global, lib, this_ptr, &node, level)?;
         }
 
+        // Track call levels taken up by closures
+        #[cfg(not(feature = "unchecked"))]
+        let orig_call_levels = (global.fn_level, global.closure_depth, global.closure_levels);
+
+        #[cfg(not(feature = "unchecked"))]
+        if is_closure {
+            global.closure_depth += 1;
+            global.closure_levels += level.saturating_sub(global.fn_level);
+        }
+        #[cfg(not(feature = "unchecked"))]
+        {
+            global.fn_level = level;
+        }
+
         // Evaluate the function
         let mut _result = self
             .eval_stmt_block(
@@ -160,6 +187,11 @@ impl Engine {
             .or_else(|err| match *err {
                 // Convert return statement to return value
                 ERR::Return(x, ..) => Ok(x),
+                // Error in sub function call - keep the full chain
+                ERR::ErrorInFunctionCall(name, src, err, err_pos) if self.error_backtrace() => {
+                    let err = ERR::ErrorInFunctionCall(name, src, err, err_pos).into();
+                    make_error(fn_def.name.to_string(), fn_def, global, err, pos)
+                }
                 // Error in sub function call
                 ERR::ErrorInFunctionCall(name, src, err, ..) => {
                     let fn_name = if src.is_empty() {
@@ -178,6 +210,11 @@ impl Engine {
                 _ => make_error(fn_def.name.to_string(), fn_def, global, err, pos),
             });
 
+        #[cfg(not(feature = "unchecked"))]
+        {
+            (global.fn_level, global.closure_depth, global.closure_levels) = orig_call_levels;
+        }
+
         #[cfg(feature = "debugging")]
         {
             let trigger = match global.debugger.status {
@@ -194,20 +194,31 @@ type InclusiveRange = std::ops::RangeInclusive<INT>;
 
 #[allow(deprecated)]
 pub use api::build_type::{CustomType, TypeBuilder};
+#[cfg(not(feature = "unchecked"))]
+pub use api::events::ProgressAction;
 #[cfg(not(feature = "no_std"))]
 #[cfg(not(target_family = "wasm"))]
 pub use api::files::{eval_file, run_file};
-pub use api::{eval::eval, events::VarDefInfo, run::run};
+pub use api::type_ops::TypeOpsBuilder;
+pub use api::{
+    eval::eval,
+    events::{LogLevel, VarDefInfo},
+    options::ExpressionOptions,
+    run::run,
+};
 pub use ast::{FnAccess, AST};
 pub use engine::{Engine, OP_CONTAINS, OP_EQUALS};
-pub use eval::EvalContext;
-pub use func::{NativeCallContext, RegisterNativeFunction};
+pub use eval::{EvalContext, EvalStats};
+pub use func::{
+    NativeCallContext, RegisterFallibleFunction, RegisterLazyFunction, RegisterNativeFunction,
+};
 pub use module::{FnNamespace, Module};
 pub use tokenizer::Position;
 #[cfg(not(feature = "no_std"))]
 pub use types::Instant;
 pub use types::{
-    Dynamic, EvalAltResult, FnPtr, ImmutableString, LexError, ParseError, ParseErrorType, Scope,
+    Dynamic, EvalAltResult, FnPtr, ImmutableString, LazySequence, LexError, ParseError,
+    ParseErrorType, PathSegment, Scope, ScopeBuilder, SourceMap,
 };
 
 #[cfg(not(feature = "no_custom_syntax"))]
@@ -247,6 +258,12 @@ pub use func::{plugin, FuncArgs};
 #[cfg(not(feature = "no_function"))]
 pub use func::Func;
 
+#[cfg(not(feature = "no_function"))]
+pub use api::call_fn::PreparedCall;
+
+#[cfg(not(feature = "no_module"))]
+pub use api::module_builder::ModuleBuilder;
+
 #[cfg(not(feature = "no_function"))]
 pub use ast::ScriptFnMetadata;
 
@@ -274,6 +291,9 @@ pub type Map = std::collections::BTreeMap<Identifier, Dynamic>;
 #[cfg(not(feature = "no_object"))]
 pub use api::json::format_map_as_json;
 
+#[cfg(not(feature = "no_object"))]
+pub use types::TypedMap;
+
 #[cfg(not(feature = "no_module"))]
 pub use module::ModuleResolver;
 
@@ -314,7 +334,8 @@ pub use parser::ParseState;
 #[cfg(feature = "internals")]
 pub use ast::{
     ASTFlags, ASTNode, BinaryExpr, ConditionalExpr, Expr, FnCallExpr, FnCallHashes, Ident,
-    OpAssignment, RangeCase, ScriptFnDef, Stmt, StmtBlock, SwitchCasesCollection, TryCatchBlock,
+    MatchArm, MatchPattern, OpAssignment, RangeCase, ScriptFnDef, Stmt, StmtBlock,
+    SwitchCasesCollection, TryCatchBlock,
 };
 
 #[cfg(feature = "internals")]
@@ -450,6 +471,10 @@ compile_error!("`wasm-bindgen` cannot be used with `no-std`");
 #[cfg(feature = "stdweb")]
 compile_error!("`stdweb` cannot be used with `no-std`");
 
+#[cfg(feature = "no_std")]
+#[cfg(feature = "regex")]
+compile_error!("`regex` cannot be used with `no_std`");
+
 #[cfg(target_family = "wasm")]
 #[cfg(feature = "no_std")]
 compile_error!("`no_std` cannot be used for WASM target");
@@ -15,7 +15,7 @@ use crate::{
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 use std::{
-    any::TypeId,
+    any::{Any, TypeId},
     collections::BTreeMap,
     fmt,
     ops::{Add, AddAssign},
@@ -475,6 +475,32 @@ impl Module {
             .add_type::<T>(name);
         self
     }
+    /// Map a custom type to a friendly display name, also recording its [`TypeId`] so that it
+    /// can be found via [`get_custom_type_by_id`][Module::get_custom_type_by_id].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// use std::any::{type_name, TypeId};
+    ///
+    /// #[derive(Clone)]
+    /// struct TestStruct;
+    ///
+    /// let mut module = Module::new();
+    ///
+    /// module.set_custom_type_with_id::<TestStruct>("MyType");
+    ///
+    /// assert_eq!(module.get_custom_type(type_name::<TestStruct>()), Some("MyType"));
+    /// assert_eq!(module.get_custom_type_by_id(TypeId::of::<TestStruct>()), Some("MyType"));
+    /// ```
+    #[inline(always)]
+    pub fn set_custom_type_with_id<T: Any>(&mut self, name: &str) -> &mut Self {
+        self.custom_types
+            .get_or_insert_with(CustomTypesCollection::new)
+            .add_type_with_id::<T>(name);
+        self
+    }
     /// Map a custom type to a friendly display name.
     ///
     /// ```
@@ -526,6 +552,34 @@ impl Module {
             .and_then(|c| c.get(key))
             .map(|t| t.display_name.as_str())
     }
+    /// Get the display name of a registered custom type by its [`TypeId`].
+    ///
+    /// Only custom types registered via [`set_custom_type_with_id`][Module::set_custom_type_with_id]
+    /// can be found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rhai::Module;
+    /// use std::any::TypeId;
+    ///
+    /// #[derive(Clone)]
+    /// struct TestStruct;
+    ///
+    /// let mut module = Module::new();
+    ///
+    /// module.set_custom_type_with_id::<TestStruct>("MyType");
+    ///
+    /// assert_eq!(module.get_custom_type_by_id(TypeId::of::<TestStruct>()), Some("MyType"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_custom_type_by_id(&self, type_id: TypeId) -> Option<&str> {
+        self.custom_types
+            .as_ref()
+            .and_then(|c| c.get_by_type_id(type_id))
+            .map(|t| t.display_name.as_str())
+    }
 
     /// Returns `true` if this [`Module`] contains no items.
     ///
@@ -960,6 +1014,20 @@ impl Module {
             // Map String to ImmutableString
             return TypeId::of::<ImmutableString>();
         }
+        #[cfg(not(feature = "no_index"))]
+        if crate::func::register::is_array_slice(type_id) {
+            // Map slices (e.g. &[INT]) to Array
+            return TypeId::of::<crate::Array>();
+        }
+        #[cfg(not(feature = "no_index"))]
+        if crate::func::register::is_fixed_array(type_id) {
+            // Map fixed-size arrays (e.g. [FLOAT; 3]) to Array
+            return TypeId::of::<crate::Array>();
+        }
+        if crate::func::register::is_option(type_id) {
+            // Map optional parameters (e.g. Option<INT>) to Dynamic
+            return TypeId::of::<Dynamic>();
+        }
 
         type_id
     }
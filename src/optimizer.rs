@@ -33,6 +33,10 @@ pub enum OptimizationLevel {
     Simple,
     /// Full optimizations performed, including evaluating functions.
     /// Take care that this may cause side effects as it essentially assumes that all functions are pure.
+    ///
+    /// Private script-defined functions that are never referenced (by name in a function call,
+    /// a string literal or a function pointer) are also removed, unless `eval` is called.
+    /// Take care that a private function called via a name constructed at run-time is removed.
     Full,
 }
 
@@ -760,6 +764,27 @@ fn optimize_stmt(stmt: &mut Stmt, state: &mut OptimizerState, preserve_result: b
             }
         }
 
+        // match
+        Stmt::Match(x, ..) => {
+            let (match_expr, arms) = &mut **x;
+
+            optimize_expr(match_expr, state, false);
+
+            for arm in arms.as_mut() {
+                // Variables bound by the pattern shadow any constants of the same names
+                let orig_constants_len = state.variables.len();
+
+                arm.pattern.for_each_binding(&mut |name| {
+                    state.push_var(name.name.as_str(), AccessMode::ReadWrite, None)
+                });
+
+                optimize_expr(&mut arm.body.condition, state, false);
+                optimize_expr(&mut arm.body.expr, state, false);
+
+                state.restore_var(orig_constants_len);
+            }
+        }
+
         // while false { block } -> Noop
         Stmt::While(x, ..) if matches!(x.0, Expr::BoolConstant(false, ..)) => match x.0 {
             Expr::BoolConstant(false, pos) => {
@@ -780,7 +805,7 @@ fn optimize_stmt(stmt: &mut Stmt, state: &mut OptimizerState, preserve_result: b
             if body.len() == 1 {
                 match body[0] {
                     // while expr { break; } -> { expr; }
-                    Stmt::BreakLoop(options, pos) if options.contains(ASTFlags::BREAK) => {
+                    Stmt::BreakLoop(None, options, pos) if options.contains(ASTFlags::BREAK) => {
                         // Only a single break statement - turn into running the guard expression once
                         state.set_dirty();
                         if condition.is_unit() {
@@ -914,8 +939,10 @@ fn optimize_stmt(stmt: &mut Stmt, state: &mut OptimizerState, preserve_result: b
             }
         }
 
-        // return expr;
-        Stmt::Return(Some(ref mut expr), ..) => optimize_expr(expr, state, false),
+        // break expr; or return expr;
+        Stmt::BreakLoop(Some(ref mut expr), ..) | Stmt::Return(Some(ref mut expr), ..) => {
+            optimize_expr(expr, state, false)
+        }
 
         // All other statements - skip
         _ => (),
@@ -1078,7 +1105,7 @@ fn optimize_expr(expr: &mut Expr, state: &mut OptimizerState, _chaining: bool) {
         }
         // [ constant .. ]
         #[cfg(not(feature = "no_index"))]
-        Expr::Array(..) if expr.is_constant() => {
+        Expr::Array(..) if state.engine.optimize_constant_array_folding && expr.is_constant() => {
             state.set_dirty();
             *expr = Expr::DynamicConstant(expr.get_literal_value().unwrap().into(), expr.position());
         }
@@ -1087,7 +1114,7 @@ fn optimize_expr(expr: &mut Expr, state: &mut OptimizerState, _chaining: bool) {
         Expr::Array(x, ..) => x.iter_mut().for_each(|expr| optimize_expr(expr, state, false)),
         // #{ key:constant, .. }
         #[cfg(not(feature = "no_object"))]
-        Expr::Map(..) if expr.is_constant() => {
+        Expr::Map(..) if state.engine.optimize_constant_array_folding && expr.is_constant() => {
             state.set_dirty();
             *expr = Expr::DynamicConstant(expr.get_literal_value().unwrap().into(), expr.position());
         }
@@ -1240,6 +1267,8 @@ fn optimize_expr(expr: &mut Expr, state: &mut OptimizerState, _chaining: bool) {
                     KEYWORD_TYPE_OF if arg_values.len() == 1 => Some(state.engine.map_type_name(arg_values[0].type_name()).into()),
                     #[cfg(not(feature = "no_closure"))]
                     crate::engine::KEYWORD_IS_SHARED if arg_values.len() == 1 => Some(Dynamic::FALSE),
+                    // Logging has side effects
+                    "log" if arg_values.len() == 2 && arg_values[0].is::<ImmutableString>() => None,
                     _ => state.call_fn_with_constant_arguments(&x.name, arg_values)
                 };
 
@@ -1383,10 +1412,13 @@ pub fn optimize_into_ast(
             for fn_def in functions {
                 let mut fn_def = crate::func::shared_take_or_clone(fn_def);
 
-                // Optimize the function body
-                let body = mem::take(&mut *fn_def.body);
+                // Optimize the function body, unless excluded
+                if !engine.is_optimize_excluded(&fn_def.name) {
+                    let body = mem::take(&mut *fn_def.body);
 
-                *fn_def.body = optimize_top_level(body, engine, scope, lib2, optimization_level);
+                    *fn_def.body =
+                        optimize_top_level(body, engine, scope, lib2, optimization_level);
+                }
 
                 module.set_script_fn(fn_def);
             }
@@ -1401,19 +1433,151 @@ pub fn optimize_into_ast(
 
     statements.shrink_to_fit();
 
+    let statements = match optimization_level {
+        OptimizationLevel::None => statements,
+        OptimizationLevel::Simple | OptimizationLevel::Full => optimize_top_level(
+            statements,
+            engine,
+            scope,
+            #[cfg(not(feature = "no_function"))]
+            &[&lib],
+            optimization_level,
+        ),
+    };
+
+    #[cfg(not(feature = "no_function"))]
+    let lib = match optimization_level {
+        OptimizationLevel::Full if engine.optimize_remove_unused_functions => {
+            remove_unused_functions(&statements, lib)
+        }
+        _ => lib,
+    };
+
     AST::new(
-        match optimization_level {
-            OptimizationLevel::None => statements,
-            OptimizationLevel::Simple | OptimizationLevel::Full => optimize_top_level(
-                statements,
-                engine,
-                scope,
-                #[cfg(not(feature = "no_function"))]
-                &[&lib],
-                optimization_level,
-            ),
-        },
+        statements,
         #[cfg(not(feature = "no_function"))]
         lib,
     )
 }
+
+/// Remove private script-defined functions that cannot be reached from the top-level statements
+/// or from any public function.
+///
+/// A function is considered reachable when its name appears anywhere in reachable code as the
+/// name of a function call, a string literal or a function pointer. This keeps functions called
+/// indirectly via `Fn`, `call` or `is_def_fn` with a literal name. All functions are kept if
+/// `eval` is called anywhere.
+#[cfg(not(feature = "no_function"))]
+fn remove_unused_functions(statements: &[Stmt], lib: crate::Module) -> crate::Module {
+    use crate::ast::{ASTNode, FnAccess};
+    use std::collections::BTreeSet;
+
+    /// Collect all names referenced in the statements.
+    /// Returns `false` if `eval` is called.
+    fn collect_names<'a>(
+        statements: impl Iterator<Item = &'a Stmt>,
+        names: &mut BTreeSet<ImmutableString>,
+    ) -> bool {
+        let path = &mut Vec::new();
+
+        let on_node = &mut |path: &[ASTNode]| match path.last() {
+            Some(ASTNode::Stmt(Stmt::FnCall(x, ..)))
+            | Some(ASTNode::Expr(Expr::FnCall(x, ..)))
+            | Some(ASTNode::Expr(Expr::MethodCall(x, ..))) => {
+                names.insert(x.name.clone());
+                x.name != KEYWORD_EVAL
+            }
+            Some(ASTNode::Expr(Expr::StringConstant(s, ..))) => {
+                names.insert(s.clone());
+                true
+            }
+            Some(ASTNode::Expr(Expr::DynamicConstant(value, ..))) => {
+                collect_value_names(value, names);
+                true
+            }
+            _ => true,
+        };
+
+        statements.fold(true, |result, stmt| result && stmt.walk(path, on_node))
+    }
+
+    /// Collect all strings and function pointer names inside a constant value.
+    fn collect_value_names(value: &Dynamic, names: &mut BTreeSet<ImmutableString>) {
+        if let Some(s) = value.read_lock::<ImmutableString>() {
+            names.insert(s.clone());
+        } else if let Some(fn_ptr) = value.read_lock::<FnPtr>() {
+            names.insert(fn_ptr.fn_name().into());
+            fn_ptr
+                .curry()
+                .iter()
+                .for_each(|v| collect_value_names(v, names));
+        }
+        #[cfg(not(feature = "no_index"))]
+        if let Some(array) = value.read_lock::<crate::Array>() {
+            array.iter().for_each(|v| collect_value_names(v, names));
+        }
+        #[cfg(not(feature = "no_object"))]
+        if let Some(map) = value.read_lock::<crate::Map>() {
+            map.values().for_each(|v| collect_value_names(v, names));
+        }
+    }
+
+    let fn_defs: StaticVec<_> = lib.iter_script_fn().map(|(.., f)| f.clone()).collect();
+    let mut reachable: StaticVec<_> = fn_defs
+        .iter()
+        .map(|f| f.access != FnAccess::Private)
+        .collect();
+
+    if reachable.iter().all(|&r| r) {
+        return lib;
+    }
+
+    let mut names = BTreeSet::new();
+
+    // Start from the top-level statements and all public functions
+    let roots = fn_defs
+        .iter()
+        .zip(reachable.iter())
+        .filter(|(.., r)| **r)
+        .flat_map(|(f, ..)| f.body.iter());
+
+    if !collect_names(statements.iter().chain(roots), &mut names) {
+        return lib;
+    }
+
+    // Keep adding private functions that are referenced until nothing changes
+    loop {
+        let mut changed = false;
+
+        for (f, r) in fn_defs.iter().zip(reachable.iter_mut()) {
+            if !*r && names.contains(f.name.as_str()) {
+                *r = true;
+                changed = true;
+
+                if !collect_names(f.body.iter(), &mut names) {
+                    return lib;
+                }
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    if reachable.iter().all(|&r| r) {
+        return lib;
+    }
+
+    let mut module = crate::Module::new();
+
+    fn_defs
+        .into_iter()
+        .zip(reachable)
+        .filter(|(.., r)| *r)
+        .for_each(|(f, ..)| {
+            module.set_script_fn(f);
+        });
+
+    module
+}
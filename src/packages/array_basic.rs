@@ -11,6 +11,9 @@ use crate::{
 use std::prelude::v1::*;
 use std::{any::TypeId, cmp::Ordering, mem};
 
+/// Maximum number of arrays that can be combined in a single call to `concat`.
+const MAX_CONCAT_ARRAYS: usize = 8;
+
 def_package! {
     /// Package of basic array utilities.
     pub BasicArrayPackage(lib) {
@@ -20,6 +23,68 @@ def_package! {
 
         // Register array iterator
         lib.set_iterable::<Array>();
+
+        // Register variadic `concat`
+        for num_arrays in 2..=MAX_CONCAT_ARRAYS {
+            let arg_types = std::iter::repeat(TypeId::of::<Array>())
+                .take(num_arrays)
+                .collect::<StaticVec<_>>();
+
+            let _hash = lib.set_raw_fn(
+                "concat",
+                FnNamespace::Internal,
+                FnAccess::Public,
+                arg_types,
+                |_, args| {
+                    let len = args
+                        .iter()
+                        .map(|a| a.read_lock::<Array>().expect("`Array`").len())
+                        .sum();
+                    let mut result = Array::with_capacity(len);
+
+                    // The first argument may be a reference (e.g. in method-call style), so do not consume it
+                    let (first, rest) = args.split_first_mut().expect("at least two arguments");
+                    result.extend(first.read_lock::<Array>().expect("`Array`").iter().cloned());
+
+                    for arg in rest {
+                        result.extend(mem::take(*arg).cast::<Array>());
+                    }
+
+                    Ok(result)
+                },
+            );
+
+            #[cfg(feature = "metadata")]
+            {
+                let mut params = (1..=num_arrays)
+                    .map(|n| format!("array{n}: Array"))
+                    .collect::<StaticVec<_>>();
+                params.push("Array".into());
+
+                let limit = format!(
+                    "/// Between 2 and {MAX_CONCAT_ARRAYS} arrays can be combined in a single call."
+                );
+
+                lib.update_fn_metadata_with_comments(
+                    _hash,
+                    params,
+                    [
+                        "/// Combine all the arrays, in order, into a new array and return it.",
+                        "///",
+                        limit.as_str(),
+                        "/// To combine more arrays, chain multiple calls or use the `+` operator.",
+                        "///",
+                        "/// # Example",
+                        "///",
+                        "/// ```rhai",
+                        "/// let x = concat([1, 2], [true], ['x', 'y']);",
+                        "///",
+                        "/// print(x);      // prints \"[1, 2, true, 'x', 'y']\"",
+                        "/// ```",
+                    ],
+                );
+            }
+        }
     }
 }
 
@@ -160,6 +225,111 @@ pub mod array_functions {
             array
         }
     }
+    /// Return a new array with the elements of the two arrays alternating, starting with the first
+    /// array, stopping when the shorter array runs out.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4];
+    /// let y = ['a', 'b'];
+    ///
+    /// print(x.interleave(y));     // prints "[1, 'a', 2, 'b']"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn interleave(array1: &mut Array, array2: Array) -> Array {
+        let mut result = Array::with_capacity(usize::min(array1.len(), array2.len()) * 2);
+
+        for (x, y) in array1.iter().cloned().zip(array2) {
+            result.push(x);
+            result.push(y);
+        }
+
+        result
+    }
+    /// Return a new array with the elements of the two arrays alternating, starting with the first
+    /// array. When the shorter array runs out, the remaining elements of the longer array are
+    /// appended.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4];
+    /// let y = ['a', 'b'];
+    ///
+    /// print(x.interleave_longest(y));     // prints "[1, 'a', 2, 'b', 3, 4]"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn interleave_longest(array1: &mut Array, array2: Array) -> Array {
+        let mut result = Array::with_capacity(array1.len() + array2.len());
+        let mut iter1 = array1.iter().cloned();
+        let mut iter2 = array2.into_iter();
+
+        loop {
+            match (iter1.next(), iter2.next()) {
+                (None, None) => break,
+                (x, y) => result.extend(x.into_iter().chain(y)),
+            }
+        }
+
+        result
+    }
+    /// Treat the array as a matrix of rows, each row being an array, and return its transpose
+    /// as a new array, turning an `M`×`N` matrix into an `N`×`M` one.
+    ///
+    /// An error is raised if any element is not an array, or if the rows are not all of the same
+    /// length (i.e. the matrix is jagged).
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    ///
+    /// print(m.transpose());       // prints "[[1, 4], [2, 5], [3, 6]]"
+    ///
+    /// [[1, 2], [3]].transpose();  // error: row 1 has 1 element(s) instead of 2
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn transpose(ctx: NativeCallContext, matrix: &mut Array) -> RhaiResultOf<Array> {
+        let mut rows = StaticVec::<crate::DynamicReadLock<Array>>::new_const();
+
+        for (i, row) in matrix.iter().enumerate() {
+            let row = row.read_lock::<Array>().ok_or_else(|| {
+                ERR::ErrorMismatchDataType(
+                    ctx.engine()
+                        .map_type_name(std::any::type_name::<Array>())
+                        .into(),
+                    format!(
+                        "{} at index {i}",
+                        ctx.engine().map_type_name(row.type_name())
+                    ),
+                    Position::NONE,
+                )
+            })?;
+
+            if let Some(first) = rows.first().map(|r| r.len()) {
+                if row.len() != first {
+                    return Err(ERR::ErrorRuntime(
+                        format!(
+                            "Cannot transpose a jagged matrix: row {i} has {} element(s) instead of {first}",
+                            row.len()
+                        )
+                        .into(),
+                        Position::NONE,
+                    )
+                    .into());
+                }
+            }
+
+            rows.push(row);
+        }
+
+        let num_cols = rows.first().map_or(0, |r| r.len());
+
+        Ok((0..num_cols)
+            .map(|c| rows.iter().map(|r| r[c].clone()).collect::<Array>().into())
+            .collect())
+    }
     /// Add a new element into the array at a particular `index` position.
     ///
     /// * If `index` < 0, position counts from the end of the array (`-1` is the last element).
@@ -193,6 +363,42 @@ pub mod array_functions {
             array.insert(index, item);
         }
     }
+    /// Add all the elements of another array into the array at a particular `index` position.
+    ///
+    /// * If `index` < 0, position counts from the end of the array (`-1` is the last element).
+    /// * If `index` < -length of array, the elements are added to the beginning of the array.
+    /// * If `index` ≥ length of array, the elements are appended to the end of the array.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3];
+    ///
+    /// x.insert_all(1, [true, 'x']);
+    ///
+    /// print(x);       // prints "[1, true, 'x', 2, 3]"
+    ///
+    /// x.insert_all(-1, [42]);
+    ///
+    /// print(x);       // prints "[1, true, 'x', 2, 42, 3]"
+    /// ```
+    pub fn insert_all(array: &mut Array, index: INT, items: Array) {
+        if items.is_empty() {
+            return;
+        }
+        if array.is_empty() {
+            *array = items;
+            return;
+        }
+
+        let (index, ..) = calc_offset_len(array.len(), index, 0);
+
+        if index >= array.len() {
+            array.extend(items);
+        } else {
+            array.splice(index..index, items);
+        }
+    }
     /// Pad the array to at least the specified length with copies of a specified element.
     ///
     /// If `len` ≤ length of array, no padding is done.
@@ -230,9 +436,11 @@ pub mod array_functions {
         #[cfg(not(feature = "unchecked"))]
         {
             if _ctx.engine().max_array_size() > 0 && len > _ctx.engine().max_array_size() {
-                return Err(
-                    ERR::ErrorDataTooLarge("Size of array".to_string(), Position::NONE).into(),
-                );
+                return Err(ERR::ErrorDataTooLarge(
+                    format!("Size of array ({len})"),
+                    Position::NONE,
+                )
+                .into());
             }
 
             let check_sizes = match item.0 {
@@ -278,6 +486,123 @@ pub mod array_functions {
 
         Ok(())
     }
+    /// Set every element of the array to a copy of the specified value.
+    ///
+    /// Each element receives its own copy of `value`, so modifying one element afterwards
+    /// does not affect the others.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3];
+    ///
+    /// x.fill(0);
+    ///
+    /// print(x);       // prints "[0, 0, 0]"
+    /// ```
+    pub fn fill(array: &mut Array, value: Dynamic) {
+        array.iter_mut().for_each(|item| *item = value.clone());
+    }
+    /// Resize the array to the specified length, padding with copies of a specified element.
+    ///
+    /// * If `len` ≤ 0, the array is cleared.
+    /// * If `len` < length of array, trailing elements are removed.
+    /// * If `len` > length of array, the array is padded with copies of `item`, so modifying one
+    ///   padded element afterwards does not affect the others.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3];
+    ///
+    /// x.resize(5, 42);
+    ///
+    /// print(x);       // prints "[1, 2, 3, 42, 42]"
+    ///
+    /// x.resize(2, 0);
+    ///
+    /// print(x);       // prints "[1, 2]"
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn resize(
+        ctx: NativeCallContext,
+        array: &mut Array,
+        len: INT,
+        item: Dynamic,
+    ) -> RhaiResultOf<()> {
+        truncate(array, len);
+        pad(ctx, array, len, item)
+    }
+    /// Resize the array to the specified length, calling the `generator` function to create each
+    /// new element.
+    ///
+    /// * If `len` ≤ 0, the array is cleared.
+    /// * If `len` < length of array, trailing elements are removed.
+    /// * If `len` > length of array, `generator` is called once for each new element.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `index`: index of the new element in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3];
+    ///
+    /// x.resize_with(6, |i| i * i);
+    ///
+    /// print(x);       // prints "[1, 2, 3, 9, 16, 25]"
+    ///
+    /// let grid = [];
+    ///
+    /// grid.resize_with(2, |i| [0, 0]);
+    ///
+    /// print(grid);    // prints "[[0, 0], [0, 0]]"
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn resize_with(
+        ctx: NativeCallContext,
+        array: &mut Array,
+        len: INT,
+        generator: FnPtr,
+    ) -> RhaiResultOf<()> {
+        truncate(array, len);
+
+        let len = len.min(MAX_USIZE_INT);
+
+        if len <= 0 || (len as usize) <= array.len() {
+            return Ok(());
+        }
+
+        let len = len as usize;
+
+        // Check if array will be over max size limit
+        #[cfg(not(feature = "unchecked"))]
+        if ctx.engine().max_array_size() > 0 && len > ctx.engine().max_array_size() {
+            return Err(
+                ERR::ErrorDataTooLarge(format!("Size of array ({len})"), Position::NONE).into(),
+            );
+        }
+
+        array.reserve(len - array.len());
+
+        for i in array.len()..len {
+            let item = generator
+                .call_raw(&ctx, None, [(i as INT).into()])
+                .map_err(|err| {
+                    Box::new(ERR::ErrorInFunctionCall(
+                        "resize_with".to_string(),
+                        ctx.source().unwrap_or("").to_string(),
+                        err,
+                        Position::NONE,
+                    ))
+                })?;
+
+            array.push(item);
+        }
+
+        Ok(())
+    }
     /// Remove the last element from the array and return it.
     ///
     /// If the array is empty, `()` is returned.
@@ -345,6 +670,77 @@ pub mod array_functions {
 
         array.remove(index)
     }
+    /// Remove all elements in the array within an exclusive `range` and return them as a new
+    /// array.
+    ///
+    /// Unlike `drain`, the range is not clamped: an error is raised if any part of the range is
+    /// out of bounds. If the range is empty, no element is removed.
+    ///
+    /// Unlike positions passed to other array functions, a negative range start does not count
+    /// from the end of the array and always raises an error.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4, 5];
+    ///
+    /// let y = x.remove_range(1..3);
+    ///
+    /// print(x);       // prints "[1, 4, 5]"
+    ///
+    /// print(y);       // prints "[2, 3]"
+    ///
+    /// x.remove_range(2..5);       // error: array index out of bounds
+    /// ```
+    #[rhai_fn(name = "remove_range", return_raw)]
+    pub fn remove_exclusive_range(array: &mut Array, range: ExclusiveRange) -> RhaiResultOf<Array> {
+        remove_checked_range(array, range.start, range.end)
+    }
+    /// Remove all elements in the array within an inclusive `range` and return them as a new
+    /// array.
+    ///
+    /// Unlike `drain`, the range is not clamped: an error is raised if any part of the range is
+    /// out of bounds. If the range is empty, no element is removed.
+    ///
+    /// Unlike positions passed to other array functions, a negative range start does not count
+    /// from the end of the array and always raises an error.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4, 5];
+    ///
+    /// let y = x.remove_range(1..=2);
+    ///
+    /// print(x);       // prints "[1, 4, 5]"
+    ///
+    /// print(y);       // prints "[2, 3]"
+    ///
+    /// x.remove_range(2..=3);      // error: array index out of bounds
+    /// ```
+    #[rhai_fn(name = "remove_range", return_raw)]
+    pub fn remove_inclusive_range(array: &mut Array, range: InclusiveRange) -> RhaiResultOf<Array> {
+        remove_checked_range(array, *range.start(), range.end().saturating_add(1))
+    }
+    /// Remove the elements from `start` up to (but not including) `end`, raising an error if any
+    /// part of a non-empty range is out of bounds.
+    ///
+    /// A negative `start` is always out of bounds.
+    fn remove_checked_range(array: &mut Array, start: INT, end: INT) -> RhaiResultOf<Array> {
+        let len = array.len();
+
+        if start < 0 || start > len as INT {
+            return Err(ERR::ErrorArrayBounds(len, start, Position::NONE).into());
+        }
+        if end <= start {
+            return Ok(Array::new());
+        }
+        if end > len as INT {
+            return Err(ERR::ErrorArrayBounds(len, end - 1, Position::NONE).into());
+        }
+
+        Ok(array.drain(start as usize..end as usize).collect())
+    }
     /// Clear the array.
     pub fn clear(array: &mut Array) {
         if !array.is_empty() {
@@ -426,6 +822,7 @@ pub mod array_functions {
         }
     }
     /// Replace an exclusive range of the array with another array.
+    /// Return the removed elements as a new array.
     ///
     /// # Example
     ///
@@ -433,17 +830,20 @@ pub mod array_functions {
     /// let x = [1, 2, 3, 4, 5];
     /// let y = [7, 8, 9, 10];
     ///
-    /// x.splice(1..3, y);
+    /// let z = x.splice(1..3, y);
     ///
     /// print(x);       // prints "[1, 7, 8, 9, 10, 4, 5]"
+    ///
+    /// print(z);       // prints "[2, 3]"
     /// ```
     #[rhai_fn(name = "splice")]
-    pub fn splice_range(array: &mut Array, range: ExclusiveRange, replace: Array) {
+    pub fn splice_range(array: &mut Array, range: ExclusiveRange, replace: Array) -> Array {
         let start = INT::max(range.start, 0);
         let end = INT::max(range.end, start);
-        splice(array, start, end - start, replace);
+        splice(array, start, end - start, replace)
     }
     /// Replace an inclusive range of the array with another array.
+    /// Return the removed elements as a new array.
     ///
     /// # Example
     ///
@@ -451,17 +851,24 @@ pub mod array_functions {
     /// let x = [1, 2, 3, 4, 5];
     /// let y = [7, 8, 9, 10];
     ///
-    /// x.splice(1..=3, y);
+    /// let z = x.splice(1..=3, y);
     ///
     /// print(x);       // prints "[1, 7, 8, 9, 10, 5]"
+    ///
+    /// print(z);       // prints "[2, 3, 4]"
     /// ```
     #[rhai_fn(name = "splice")]
-    pub fn splice_inclusive_range(array: &mut Array, range: InclusiveRange, replace: Array) {
+    pub fn splice_inclusive_range(
+        array: &mut Array,
+        range: InclusiveRange,
+        replace: Array,
+    ) -> Array {
         let start = INT::max(*range.start(), 0);
         let end = INT::max(*range.end(), start);
-        splice(array, start, end - start + 1, replace);
+        splice(array, start, end - start + 1, replace)
     }
     /// Replace a portion of the array with another array.
+    /// Return the removed elements as a new array.
     ///
     /// * If `start` < 0, position counts from the end of the array (`-1` is the last element).
     /// * If `start` < -length of array, position counts from the beginning of the array.
@@ -475,26 +882,31 @@ pub mod array_functions {
     /// let x = [1, 2, 3, 4, 5];
     /// let y = [7, 8, 9, 10];
     ///
-    /// x.splice(1, 2, y);
+    /// let z = x.splice(1, 2, y);
     ///
     /// print(x);       // prints "[1, 7, 8, 9, 10, 4, 5]"
     ///
-    /// x.splice(-5, 4, y);
+    /// print(z);       // prints "[2, 3]"
+    ///
+    /// let z = x.splice(-5, 4, y);
     ///
     /// print(x);       // prints "[1, 7, 7, 8, 9, 10, 5]"
+    ///
+    /// print(z);       // prints "[8, 9, 10, 4]"
     /// ```
-    pub fn splice(array: &mut Array, start: INT, len: INT, replace: Array) {
+    pub fn splice(array: &mut Array, start: INT, len: INT, replace: Array) -> Array {
         if array.is_empty() {
             *array = replace;
-            return;
+            return Array::new();
         }
 
         let (start, len) = calc_offset_len(array.len(), start, len);
 
         if start >= array.len() {
             array.extend(replace);
+            Array::new()
         } else {
-            array.splice(start..start + len, replace);
+            array.splice(start..start + len, replace).collect()
         }
     }
     /// Copy an exclusive range of the array and return it as a new array.
@@ -585,83 +997,295 @@ pub mod array_functions {
     pub fn extract_tail(array: &mut Array, start: INT) -> Array {
         extract(array, start, INT::MAX)
     }
-    /// Cut off the array at `index` and return it as a new array.
+    /// Copy the first `n` elements of the array and return them as a new array.
     ///
-    /// * If `index` < 0, position counts from the end of the array (`-1` is the last element).
-    /// * If `index` is zero, the entire array is cut and returned.
-    /// * If `index` < -length of array, the entire array is cut and returned.
-    /// * If `index` ≥ length of array, nothing is cut from the array and an empty array is returned.
+    /// * If `n` ≤ 0, an empty array is returned.
+    /// * If `n` ≥ length of array, the entire array is copied and returned.
     ///
     /// # Example
     ///
     /// ```rhai
     /// let x = [1, 2, 3, 4, 5];
     ///
-    /// let y = x.split(2);
+    /// print(x.take(2));           // prints "[1, 2]"
     ///
-    /// print(y);           // prints "[3, 4, 5]"
+    /// print(x.take(10));          // prints "[1, 2, 3, 4, 5]"
     ///
-    /// print(x);           // prints "[1, 2]"
+    /// print(x);                   // prints "[1, 2, 3, 4, 5]"
     /// ```
-    #[rhai_fn(name = "split")]
-    pub fn split_at(array: &mut Array, index: INT) -> Array {
-        if array.is_empty() {
+    #[rhai_fn(pure)]
+    pub fn take(array: &mut Array, n: INT) -> Array {
+        if array.is_empty() || n <= 0 {
             return Array::new();
         }
 
-        let (start, len) = calc_offset_len(array.len(), index, INT::MAX);
-
-        if start == 0 {
-            if len >= array.len() {
-                mem::take(array)
-            } else {
-                let mut result = Array::new();
-                result.extend(array.drain(array.len() - len..));
-                result
-            }
-        } else if start >= array.len() {
-            Array::new()
-        } else {
-            let mut result = Array::new();
-            result.extend(array.drain(start as usize..));
-            result
-        }
+        let n = n.min(MAX_USIZE_INT) as usize;
+        array.iter().take(n).cloned().collect()
     }
-    /// Iterate through all the elements in the array, applying a `mapper` function to each element
-    /// in turn, and return the results as a new array.
-    ///
-    /// # Function Parameters
+    /// Copy all elements of the array except the first `n` and return them as a new array.
     ///
-    /// * `element`: copy of array element
-    /// * `index` _(optional)_: current index in the array
+    /// * If `n` ≤ 0, the entire array is copied and returned.
+    /// * If `n` ≥ length of array, an empty array is returned.
     ///
     /// # Example
     ///
     /// ```rhai
     /// let x = [1, 2, 3, 4, 5];
     ///
-    /// let y = x.map(|v| v * v);
-    ///
-    /// print(y);       // prints "[1, 4, 9, 16, 25]"
+    /// print(x.skip(2));           // prints "[3, 4, 5]"
     ///
-    /// let y = x.map(|v, i| v * i);
+    /// print(x.skip(10));          // prints "[]"
     ///
-    /// print(y);       // prints "[0, 2, 6, 12, 20]"
+    /// print(x);                   // prints "[1, 2, 3, 4, 5]"
     /// ```
-    #[rhai_fn(return_raw)]
-    pub fn map(ctx: NativeCallContext, array: Array, mapper: FnPtr) -> RhaiResultOf<Array> {
+    #[rhai_fn(pure)]
+    pub fn skip(array: &mut Array, n: INT) -> Array {
         if array.is_empty() {
-            return Ok(array);
+            return Array::new();
         }
 
-        let mut ar = Array::with_capacity(array.len());
-
-        for (i, item) in array.into_iter().enumerate() {
-            ar.push(
-                mapper
-                    .call_raw(&ctx, None, [item.clone()])
-                    .or_else(|err| match *err {
-                        ERR::ErrorFunctionNotFound(fn_sig, ..)
+        let n = n.clamp(0, MAX_USIZE_INT) as usize;
+        array.iter().skip(n).cloned().collect()
+    }
+    /// Return the number of leading elements in the array that return `true` when applied the
+    /// `filter` function.
+    fn count_while(
+        ctx: &NativeCallContext,
+        array: &Array,
+        filter: &FnPtr,
+        fn_name: &str,
+    ) -> RhaiResultOf<usize> {
+        for (i, item) in array.iter().enumerate() {
+            if !filter
+                .call_raw(ctx, None, [item.clone()])
+                .or_else(|err| match *err {
+                    ERR::ErrorFunctionNotFound(fn_sig, ..)
+                        if fn_sig.starts_with(filter.fn_name()) =>
+                    {
+                        filter.call_raw(ctx, None, [item.clone(), (i as INT).into()])
+                    }
+                    _ => Err(err),
+                })
+                .map_err(|err| {
+                    Box::new(ERR::ErrorInFunctionCall(
+                        fn_name.to_string(),
+                        ctx.source().unwrap_or("").to_string(),
+                        err,
+                        Position::NONE,
+                    ))
+                })?
+                .as_bool()
+                .unwrap_or(false)
+            {
+                return Ok(i);
+            }
+        }
+
+        Ok(array.len())
+    }
+    /// Copy the leading elements of the array that return `true` when applied the `filter`
+    /// function and return them as a new array.
+    ///
+    /// Elements after the first one that does not return `true` are not tested.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4, 1, 2];
+    ///
+    /// print(x.take_while(|v| v < 3));         // prints "[1, 2]"
+    ///
+    /// print(x.take_while(|v, i| v > i));      // prints "[1, 2, 3, 4]"
+    ///
+    /// print(x);                               // prints "[1, 2, 3, 4, 1, 2]"
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn take_while(
+        ctx: NativeCallContext,
+        array: &mut Array,
+        filter: FnPtr,
+    ) -> RhaiResultOf<Array> {
+        if array.is_empty() {
+            return Ok(Array::new());
+        }
+
+        let n = count_while(&ctx, array, &filter, "take_while")?;
+        Ok(array[..n].to_vec())
+    }
+    /// Copy the leading elements of the array that return `true` when applied a function named
+    /// by `filter` and return them as a new array.
+    ///
+    /// Elements after the first one that does not return `true` are not tested.
+    ///
+    /// # Function Parameters
+    ///
+    /// A function with the same name as the value of `filter` must exist taking these parameters:
+    ///
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// fn small(x) { x < 3 }
+    ///
+    /// let x = [1, 2, 3, 4, 1, 2];
+    ///
+    /// print(x.take_while("small"));           // prints "[1, 2]"
+    /// ```
+    #[rhai_fn(name = "take_while", return_raw, pure)]
+    pub fn take_while_by_fn_name(
+        ctx: NativeCallContext,
+        array: &mut Array,
+        filter: &str,
+    ) -> RhaiResultOf<Array> {
+        take_while(ctx, array, FnPtr::new(filter)?)
+    }
+    /// Copy all elements of the array except the leading elements that return `true` when
+    /// applied the `filter` function, and return them as a new array.
+    ///
+    /// Elements after the first one that does not return `true` are not tested.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4, 1, 2];
+    ///
+    /// print(x.skip_while(|v| v < 3));         // prints "[3, 4, 1, 2]"
+    ///
+    /// print(x.skip_while(|v, i| v > i));      // prints "[1, 2]"
+    ///
+    /// print(x);                               // prints "[1, 2, 3, 4, 1, 2]"
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn skip_while(
+        ctx: NativeCallContext,
+        array: &mut Array,
+        filter: FnPtr,
+    ) -> RhaiResultOf<Array> {
+        if array.is_empty() {
+            return Ok(Array::new());
+        }
+
+        let n = count_while(&ctx, array, &filter, "skip_while")?;
+        Ok(array[n..].to_vec())
+    }
+    /// Copy all elements of the array except the leading elements that return `true` when
+    /// applied a function named by `filter`, and return them as a new array.
+    ///
+    /// Elements after the first one that does not return `true` are not tested.
+    ///
+    /// # Function Parameters
+    ///
+    /// A function with the same name as the value of `filter` must exist taking these parameters:
+    ///
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// fn small(x) { x < 3 }
+    ///
+    /// let x = [1, 2, 3, 4, 1, 2];
+    ///
+    /// print(x.skip_while("small"));           // prints "[3, 4, 1, 2]"
+    /// ```
+    #[rhai_fn(name = "skip_while", return_raw, pure)]
+    pub fn skip_while_by_fn_name(
+        ctx: NativeCallContext,
+        array: &mut Array,
+        filter: &str,
+    ) -> RhaiResultOf<Array> {
+        skip_while(ctx, array, FnPtr::new(filter)?)
+    }
+    /// Cut off the array at `index` and return it as a new array.
+    ///
+    /// * If `index` < 0, position counts from the end of the array (`-1` is the last element).
+    /// * If `index` is zero, the entire array is cut and returned.
+    /// * If `index` < -length of array, the entire array is cut and returned.
+    /// * If `index` ≥ length of array, nothing is cut from the array and an empty array is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4, 5];
+    ///
+    /// let y = x.split(2);
+    ///
+    /// print(y);           // prints "[3, 4, 5]"
+    ///
+    /// print(x);           // prints "[1, 2]"
+    /// ```
+    #[rhai_fn(name = "split")]
+    pub fn split_at(array: &mut Array, index: INT) -> Array {
+        if array.is_empty() {
+            return Array::new();
+        }
+
+        let (start, len) = calc_offset_len(array.len(), index, INT::MAX);
+
+        if start == 0 {
+            if len >= array.len() {
+                mem::take(array)
+            } else {
+                let mut result = Array::new();
+                result.extend(array.drain(array.len() - len..));
+                result
+            }
+        } else if start >= array.len() {
+            Array::new()
+        } else {
+            let mut result = Array::new();
+            result.extend(array.drain(start as usize..));
+            result
+        }
+    }
+    /// Iterate through all the elements in the array, applying a `mapper` function to each element
+    /// in turn, and return the results as a new array.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4, 5];
+    ///
+    /// let y = x.map(|v| v * v);
+    ///
+    /// print(y);       // prints "[1, 4, 9, 16, 25]"
+    ///
+    /// let y = x.map(|v, i| v * i);
+    ///
+    /// print(y);       // prints "[0, 2, 6, 12, 20]"
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn map(ctx: NativeCallContext, array: Array, mapper: FnPtr) -> RhaiResultOf<Array> {
+        if array.is_empty() {
+            return Ok(array);
+        }
+
+        let mut ar = Array::with_capacity(array.len());
+
+        for (i, item) in array.into_iter().enumerate() {
+            ar.push(
+                mapper
+                    .call_raw(&ctx, None, [item.clone()])
+                    .or_else(|err| match *err {
+                        ERR::ErrorFunctionNotFound(fn_sig, ..)
                             if fn_sig.starts_with(mapper.fn_name()) =>
                         {
                             mapper.call_raw(&ctx, None, [item, (i as INT).into()])
@@ -1031,32 +1655,284 @@ pub mod array_functions {
     /// ```rhai
     /// let x = [1, 2, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4, 5];
     ///
-    /// print(x.index_of(|v| v > 1, 3));    // prints 5: 2 > 1
-    ///
-    /// print(x.index_of(|v| v < 2, 9));    // prints -1: nothing < 2 past index 9
-    ///
-    /// print(x.index_of(|v| v > 1, 15));   // prints -1: nothing found past end of array
-    ///
-    /// print(x.index_of(|v| v > 1, -5));   // prints 9: -5 = start from index 8
+    /// print(x.index_of(|v| v > 1, 3));    // prints 5: 2 > 1
+    ///
+    /// print(x.index_of(|v| v < 2, 9));    // prints -1: nothing < 2 past index 9
+    ///
+    /// print(x.index_of(|v| v > 1, 15));   // prints -1: nothing found past end of array
+    ///
+    /// print(x.index_of(|v| v > 1, -5));   // prints 9: -5 = start from index 8
+    ///
+    /// print(x.index_of(|v| v > 1, -99));  // prints 1: -99 = start from beginning
+    ///
+    /// print(x.index_of(|v, i| v * i > 20, 8));    // prints 10: 3 * 10 > 20
+    /// ```
+    #[rhai_fn(name = "index_of", return_raw, pure)]
+    pub fn index_of_filter_starting_from(
+        ctx: NativeCallContext,
+        array: &mut Array,
+        filter: FnPtr,
+        start: INT,
+    ) -> RhaiResultOf<INT> {
+        if array.is_empty() {
+            return Ok(-1);
+        }
+
+        let (start, ..) = calc_offset_len(array.len(), start, 0);
+
+        for (i, item) in array.iter().enumerate().skip(start) {
+            if filter
+                .call_raw(&ctx, None, [item.clone()])
+                .or_else(|err| match *err {
+                    ERR::ErrorFunctionNotFound(fn_sig, ..)
+                        if fn_sig.starts_with(filter.fn_name()) =>
+                    {
+                        filter.call_raw(&ctx, None, [item.clone(), (i as INT).into()])
+                    }
+                    _ => Err(err),
+                })
+                .map_err(|err| {
+                    Box::new(ERR::ErrorInFunctionCall(
+                        "index_of".to_string(),
+                        ctx.source().unwrap_or("").to_string(),
+                        err,
+                        Position::NONE,
+                    ))
+                })?
+                .as_bool()
+                .unwrap_or(false)
+            {
+                return Ok(i as INT);
+            }
+        }
+
+        Ok(-1 as INT)
+    }
+    /// Iterate through all the elements in the array, starting from a particular `start` position,
+    /// applying a function named by `filter` to each element in turn, and return the index of the
+    /// first element that returns `true`. If no element returns `true`, `-1` is returned.
+    ///
+    /// * If `start` < 0, position counts from the end of the array (`-1` is the last element).
+    /// * If `start` < -length of array, position counts from the beginning of the array.
+    /// * If `start` ≥ length of array, `-1` is returned.
+    ///
+    /// # Function Parameters
+    ///
+    /// A function with the same name as the value of `filter` must exist taking these parameters:
+    ///
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// fn plural(x) { x > 1 }
+    ///
+    /// fn singular(x) { x < 2 }
+    ///
+    /// fn screen(x, i) { x * i > 20 }
+    ///
+    /// let x = [1, 2, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4, 5];
+    ///
+    /// print(x.index_of("plural", 3));     // prints 5: 2 > 1
+    ///
+    /// print(x.index_of("singular", 9));   // prints -1: nothing < 2 past index 9
+    ///
+    /// print(x.index_of("plural", 15));    // prints -1: nothing found past end of array
+    ///
+    /// print(x.index_of("plural", -5));    // prints 9: -5 = start from index 8
+    ///
+    /// print(x.index_of("plural", -99));   // prints 1: -99 = start from beginning
+    ///
+    /// print(x.index_of("screen", 8));     // prints 10: 3 * 10 > 20
+    /// ```
+    #[rhai_fn(name = "index_of", return_raw, pure)]
+    pub fn index_of_by_fn_name_starting_from(
+        ctx: NativeCallContext,
+        array: &mut Array,
+        filter: &str,
+        start: INT,
+    ) -> RhaiResultOf<INT> {
+        index_of_filter_starting_from(ctx, array, FnPtr::new(filter)?, start)
+    }
+    /// Iterate through all the elements in the array, applying a `filter` function to each element
+    /// in turn, and return the index of the first element that returns `true`.
+    /// If no element returns `true`, `()` is returned.
+    ///
+    /// This is similar to `index_of` with a `filter` function, except that `()` (instead of `-1`)
+    /// is returned when no element matches.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4, 5];
+    ///
+    /// print(x.position(|v| v > 3));           // prints 3: 4 > 3
+    ///
+    /// print(x.position(|v| v > 8));           // prints (): nothing is > 8
+    ///
+    /// print(x.position(|v, i| v * i > 6));    // prints 3: 4 * 3 > 6
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn position(ctx: NativeCallContext, array: &mut Array, filter: FnPtr) -> RhaiResult {
+        if array.is_empty() {
+            return Ok(Dynamic::UNIT);
+        }
+
+        Ok(
+            match index_of_filter_starting_from(ctx, array, filter, 0)? {
+                -1 => Dynamic::UNIT,
+                index => index.into(),
+            },
+        )
+    }
+    /// Iterate through all the elements in the array, applying a function named by `filter` to each
+    /// element in turn, and return the index of the first element that returns `true`.
+    /// If no element returns `true`, `()` is returned.
+    ///
+    /// # Function Parameters
+    ///
+    /// A function with the same name as the value of `filter` must exist taking these parameters:
+    ///
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// fn is_special(x) { x > 3 }
+    ///
+    /// let x = [1, 2, 3, 4, 5];
+    ///
+    /// print(x.position("is_special"));    // prints 3
+    /// ```
+    #[rhai_fn(name = "position", return_raw, pure)]
+    pub fn position_by_fn_name(
+        ctx: NativeCallContext,
+        array: &mut Array,
+        filter: &str,
+    ) -> RhaiResult {
+        position(ctx, array, FnPtr::new(filter)?)
+    }
+    /// Iterate through all the elements in the array, applying a `mapper` function to each element
+    /// in turn, and return the first result that is not `()`.
+    /// If all results are `()`, `()` is returned.
+    ///
+    /// A `()` returned by `mapper` always means "no match", so a `()` result from `find_map`
+    /// unambiguously means that nothing is found.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [#{alice: 1}, #{bob: 2}, #{clara: 3}];
+    ///
+    /// print(x.find_map(|v| v.bob));           // prints 2
+    ///
+    /// print(x.find_map(|v| v.dave));          // prints ()
+    ///
+    /// print(x.find_map(|v, i| if i > 0 { i })); // prints 1
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn find_map(ctx: NativeCallContext, array: &mut Array, mapper: FnPtr) -> RhaiResult {
+        if array.is_empty() {
+            return Ok(Dynamic::UNIT);
+        }
+
+        for (i, item) in array.iter().enumerate() {
+            let value = mapper
+                .call_raw(&ctx, None, [item.clone()])
+                .or_else(|err| match *err {
+                    ERR::ErrorFunctionNotFound(fn_sig, ..)
+                        if fn_sig.starts_with(mapper.fn_name()) =>
+                    {
+                        mapper.call_raw(&ctx, None, [item.clone(), (i as INT).into()])
+                    }
+                    _ => Err(err),
+                })
+                .map_err(|err| {
+                    Box::new(ERR::ErrorInFunctionCall(
+                        "find_map".to_string(),
+                        ctx.source().unwrap_or("").to_string(),
+                        err,
+                        Position::NONE,
+                    ))
+                })?;
+
+            if !value.is::<()>() {
+                return Ok(value);
+            }
+        }
+
+        Ok(Dynamic::UNIT)
+    }
+    /// Iterate through all the elements in the array, applying a function named by `mapper` to each
+    /// element in turn, and return the first result that is not `()`.
+    /// If all results are `()`, `()` is returned.
+    ///
+    /// # Function Parameters
+    ///
+    /// A function with the same name as the value of `mapper` must exist taking these parameters:
+    ///
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// fn big_square(x) { if x > 2 { x * x } }
+    ///
+    /// let x = [1, 2, 3, 4, 5];
+    ///
+    /// print(x.find_map("big_square"));    // prints 9
+    /// ```
+    #[rhai_fn(name = "find_map", return_raw, pure)]
+    pub fn find_map_by_fn_name(
+        ctx: NativeCallContext,
+        array: &mut Array,
+        mapper: &str,
+    ) -> RhaiResult {
+        find_map(ctx, array, FnPtr::new(mapper)?)
+    }
+    /// Return the number of elements in the array that return `true` when applied the `filter`
+    /// function.
+    ///
+    /// Unlike `filter(...).len()`, no intermediate array is created.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4, 5];
+    ///
+    /// print(x.count(|v| v > 3));      // prints 4
     ///
-    /// print(x.index_of(|v| v > 1, -99));  // prints 1: -99 = start from beginning
+    /// print(x.count(|v| v > 10));     // prints 0
     ///
-    /// print(x.index_of(|v, i| v * i > 20, 8));    // prints 10: 3 * 10 > 20
+    /// print(x.count(|v, i| i > v));   // prints 9
     /// ```
-    #[rhai_fn(name = "index_of", return_raw, pure)]
-    pub fn index_of_filter_starting_from(
-        ctx: NativeCallContext,
-        array: &mut Array,
-        filter: FnPtr,
-        start: INT,
-    ) -> RhaiResultOf<INT> {
+    #[rhai_fn(return_raw, pure)]
+    pub fn count(ctx: NativeCallContext, array: &mut Array, filter: FnPtr) -> RhaiResultOf<INT> {
         if array.is_empty() {
-            return Ok(-1);
+            return Ok(0);
         }
 
-        let (start, ..) = calc_offset_len(array.len(), start, 0);
+        let mut count = 0;
 
-        for (i, item) in array.iter().enumerate().skip(start) {
+        for (i, item) in array.iter().enumerate() {
             if filter
                 .call_raw(&ctx, None, [item.clone()])
                 .or_else(|err| match *err {
@@ -1069,7 +1945,7 @@ pub mod array_functions {
                 })
                 .map_err(|err| {
                     Box::new(ERR::ErrorInFunctionCall(
-                        "index_of".to_string(),
+                        "count".to_string(),
                         ctx.source().unwrap_or("").to_string(),
                         err,
                         Position::NONE,
@@ -1078,19 +1954,14 @@ pub mod array_functions {
                 .as_bool()
                 .unwrap_or(false)
             {
-                return Ok(i as INT);
+                count += 1;
             }
         }
 
-        Ok(-1 as INT)
+        Ok(count)
     }
-    /// Iterate through all the elements in the array, starting from a particular `start` position,
-    /// applying a function named by `filter` to each element in turn, and return the index of the
-    /// first element that returns `true`. If no element returns `true`, `-1` is returned.
-    ///
-    /// * If `start` < 0, position counts from the end of the array (`-1` is the last element).
-    /// * If `start` < -length of array, position counts from the beginning of the array.
-    /// * If `start` ≥ length of array, `-1` is returned.
+    /// Return the number of elements in the array that return `true` when applied a function named
+    /// by `filter`.
     ///
     /// # Function Parameters
     ///
@@ -1102,37 +1973,30 @@ pub mod array_functions {
     /// # Example
     ///
     /// ```rhai
-    /// fn plural(x) { x > 1 }
-    ///
-    /// fn singular(x) { x < 2 }
+    /// fn large(x) { x > 3 }
     ///
-    /// fn screen(x, i) { x * i > 20 }
+    /// fn screen(x, i) { i > x }
     ///
     /// let x = [1, 2, 3, 4, 1, 2, 3, 4, 1, 2, 3, 4, 5];
     ///
-    /// print(x.index_of("plural", 3));     // prints 5: 2 > 1
-    ///
-    /// print(x.index_of("singular", 9));   // prints -1: nothing < 2 past index 9
-    ///
-    /// print(x.index_of("plural", 15));    // prints -1: nothing found past end of array
-    ///
-    /// print(x.index_of("plural", -5));    // prints 9: -5 = start from index 8
-    ///
-    /// print(x.index_of("plural", -99));   // prints 1: -99 = start from beginning
+    /// print(x.count("large"));    // prints 4
     ///
-    /// print(x.index_of("screen", 8));     // prints 10: 3 * 10 > 20
+    /// print(x.count("screen"));   // prints 9
     /// ```
-    #[rhai_fn(name = "index_of", return_raw, pure)]
-    pub fn index_of_by_fn_name_starting_from(
+    #[rhai_fn(name = "count", return_raw, pure)]
+    pub fn count_by_fn_name(
         ctx: NativeCallContext,
         array: &mut Array,
         filter: &str,
-        start: INT,
     ) -> RhaiResultOf<INT> {
-        index_of_filter_starting_from(ctx, array, FnPtr::new(filter)?, start)
+        count(ctx, array, FnPtr::new(filter)?)
     }
     /// Return `true` if any element in the array that returns `true` when applied the `filter` function.
     ///
+    /// Elements after the first one that returns `true` are not tested.
+    ///
+    /// `any` is an alias of this function.
+    ///
     /// # Function Parameters
     ///
     /// * `element`: copy of array element
@@ -1149,7 +2013,7 @@ pub mod array_functions {
     ///
     /// print(x.some(|v, i| i > v));    // prints true
     /// ```
-    #[rhai_fn(return_raw, pure)]
+    #[rhai_fn(name = "some", name = "any", return_raw, pure)]
     pub fn some(ctx: NativeCallContext, array: &mut Array, filter: FnPtr) -> RhaiResultOf<bool> {
         if array.is_empty() {
             return Ok(false);
@@ -1210,7 +2074,7 @@ pub mod array_functions {
     ///
     /// print(x.some("screen"));    // prints true
     /// ```
-    #[rhai_fn(name = "some", return_raw, pure)]
+    #[rhai_fn(name = "some", name = "any", return_raw, pure)]
     pub fn some_by_fn_name(
         ctx: NativeCallContext,
         array: &mut Array,
@@ -1220,6 +2084,8 @@ pub mod array_functions {
     }
     /// Return `true` if all elements in the array return `true` when applied the `filter` function.
     ///
+    /// Elements after the first one that does not return `true` are not tested.
+    ///
     /// # Function Parameters
     ///
     /// * `element`: copy of array element
@@ -1544,6 +2410,162 @@ pub mod array_functions {
     ) -> RhaiResult {
         reduce_with_initial(ctx, array, FnPtr::new(reducer)?, initial)
     }
+    /// Iterate through all elements while applying the `reducer` function, returning a new array
+    /// with the accumulated result after each element (i.e. a running total).
+    ///
+    /// The returned array has the same length as the original array.
+    /// The initial value `()` is _not_ included.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `result`: accumulated result, initially `()`
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4, 5];
+    ///
+    /// let y = x.scan(|r, v| v + (r ?? 0));
+    ///
+    /// print(y);       // prints "[1, 3, 6, 10, 15]"
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn scan(ctx: NativeCallContext, array: &mut Array, reducer: FnPtr) -> RhaiResultOf<Array> {
+        scan_with_initial(ctx, array, reducer, Dynamic::UNIT)
+    }
+    /// Iterate through all elements while applying a function named by `reducer`, returning a new
+    /// array with the accumulated result after each element (i.e. a running total).
+    ///
+    /// The returned array has the same length as the original array.
+    /// The initial value `()` is _not_ included.
+    ///
+    /// # Function Parameters
+    ///
+    /// A function with the same name as the value of `reducer` must exist taking these parameters:
+    ///
+    /// * `result`: accumulated result, initially `()`
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// fn process(r, x) {
+    ///     x + (r ?? 0)
+    /// }
+    ///
+    /// let x = [1, 2, 3, 4, 5];
+    ///
+    /// let y = x.scan("process");
+    ///
+    /// print(y);       // prints "[1, 3, 6, 10, 15]"
+    /// ```
+    #[rhai_fn(name = "scan", return_raw, pure)]
+    pub fn scan_by_fn_name(
+        ctx: NativeCallContext,
+        array: &mut Array,
+        reducer: &str,
+    ) -> RhaiResultOf<Array> {
+        scan(ctx, array, FnPtr::new(reducer)?)
+    }
+    /// Iterate through all elements while applying the `reducer` function, returning a new array
+    /// with the accumulated result after each element (i.e. a running total).
+    ///
+    /// The returned array has the same length as the original array.
+    /// The value of `initial` is _not_ included.
+    ///
+    /// # Function Parameters
+    ///
+    /// * `result`: accumulated result, starting with the value of `initial`
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4, 5];
+    ///
+    /// let y = x.scan(|r, v| v + r, 100);
+    ///
+    /// print(y);       // prints "[101, 103, 106, 110, 115]"
+    ///
+    /// let y = x.scan(|r, v, i| v + i + r, 0);
+    ///
+    /// print(y);       // prints "[1, 4, 9, 16, 25]"
+    /// ```
+    #[rhai_fn(name = "scan", return_raw, pure)]
+    pub fn scan_with_initial(
+        ctx: NativeCallContext,
+        array: &mut Array,
+        reducer: FnPtr,
+        initial: Dynamic,
+    ) -> RhaiResultOf<Array> {
+        let mut result = initial;
+        let mut ar = Array::with_capacity(array.len());
+
+        for (i, item) in array.iter().enumerate() {
+            let item = item.clone();
+
+            result = reducer
+                .call_raw(&ctx, None, [result.clone(), item.clone()])
+                .or_else(|err| match *err {
+                    ERR::ErrorFunctionNotFound(fn_sig, ..)
+                        if fn_sig.starts_with(reducer.fn_name()) =>
+                    {
+                        reducer.call_raw(&ctx, None, [result, item, (i as INT).into()])
+                    }
+                    _ => Err(err),
+                })
+                .map_err(|err| {
+                    Box::new(ERR::ErrorInFunctionCall(
+                        "scan".to_string(),
+                        ctx.source().unwrap_or("").to_string(),
+                        err,
+                        Position::NONE,
+                    ))
+                })?;
+
+            ar.push(result.clone());
+        }
+
+        Ok(ar)
+    }
+    /// Iterate through all elements while applying a function named by `reducer`, returning a new
+    /// array with the accumulated result after each element (i.e. a running total).
+    ///
+    /// The returned array has the same length as the original array.
+    /// The value of `initial` is _not_ included.
+    ///
+    /// # Function Parameters
+    ///
+    /// A function with the same name as the value of `reducer` must exist taking these parameters:
+    ///
+    /// * `result`: accumulated result, starting with the value of `initial`
+    /// * `element`: copy of array element
+    /// * `index` _(optional)_: current index in the array
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// fn process(r, x) { x + r }
+    ///
+    /// let x = [1, 2, 3, 4, 5];
+    ///
+    /// let y = x.scan("process", 100);
+    ///
+    /// print(y);       // prints "[101, 103, 106, 110, 115]"
+    /// ```
+    #[rhai_fn(name = "scan", return_raw, pure)]
+    pub fn scan_by_fn_name_with_initial(
+        ctx: NativeCallContext,
+        array: &mut Array,
+        reducer: &str,
+        initial: Dynamic,
+    ) -> RhaiResultOf<Array> {
+        scan_with_initial(ctx, array, FnPtr::new(reducer)?, initial)
+    }
     /// Reduce an array by iterating through all elements, in _reverse_ order,
     /// while applying the `reducer` function.
     ///
@@ -1707,6 +2729,188 @@ pub mod array_functions {
     ) -> RhaiResult {
         reduce_rev_with_initial(ctx, array, FnPtr::new(reducer)?, initial)
     }
+    /// Return the smallest element in an array of numbers.
+    ///
+    /// All elements must be integers, or all must be floating-point numbers.
+    /// If the array is empty, `()` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [3, 1, 4, 1, 5];
+    ///
+    /// print(x.min());         // prints 1
+    ///
+    /// print([].min());        // prints ""
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn min(ctx: NativeCallContext, array: &mut Array) -> RhaiResult {
+        if array.is_empty() {
+            return Ok(Dynamic::UNIT);
+        }
+
+        #[cfg(not(feature = "no_float"))]
+        if !check_numbers(&ctx, array)? {
+            return Ok(array
+                .iter()
+                .map(|x| x.as_float().expect("`FLOAT`"))
+                .fold(crate::FLOAT::INFINITY, crate::FLOAT::min)
+                .into());
+        }
+        #[cfg(feature = "no_float")]
+        check_numbers(&ctx, array)?;
+
+        Ok(array
+            .iter()
+            .map(|x| x.as_int().expect("`INT`"))
+            .min()
+            .expect("not empty")
+            .into())
+    }
+    /// Return the largest element in an array of numbers.
+    ///
+    /// All elements must be integers, or all must be floating-point numbers.
+    /// If the array is empty, `()` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [3, 1, 4, 1, 5];
+    ///
+    /// print(x.max());         // prints 5
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn max(ctx: NativeCallContext, array: &mut Array) -> RhaiResult {
+        if array.is_empty() {
+            return Ok(Dynamic::UNIT);
+        }
+
+        #[cfg(not(feature = "no_float"))]
+        if !check_numbers(&ctx, array)? {
+            return Ok(array
+                .iter()
+                .map(|x| x.as_float().expect("`FLOAT`"))
+                .fold(crate::FLOAT::NEG_INFINITY, crate::FLOAT::max)
+                .into());
+        }
+        #[cfg(feature = "no_float")]
+        check_numbers(&ctx, array)?;
+
+        Ok(array
+            .iter()
+            .map(|x| x.as_int().expect("`INT`"))
+            .max()
+            .expect("not empty")
+            .into())
+    }
+    /// Return the sum of all elements in an array of numbers.
+    ///
+    /// All elements must be integers, or all must be floating-point numbers.
+    /// If the array is empty, zero is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4, 5];
+    ///
+    /// print(x.sum());         // prints 15
+    /// ```
+    #[rhai_fn(return_raw, pure)]
+    pub fn sum(ctx: NativeCallContext, array: &mut Array) -> RhaiResult {
+        if array.is_empty() {
+            return Ok((0 as INT).into());
+        }
+
+        #[cfg(not(feature = "no_float"))]
+        if !check_numbers(&ctx, array)? {
+            return Ok(array
+                .iter()
+                .map(|x| x.as_float().expect("`FLOAT`"))
+                .sum::<crate::FLOAT>()
+                .into());
+        }
+        #[cfg(feature = "no_float")]
+        check_numbers(&ctx, array)?;
+
+        array
+            .iter()
+            .map(|x| x.as_int().expect("`INT`"))
+            .try_fold(0 as INT, |sum, x| -> RhaiResultOf<INT> {
+                #[cfg(not(feature = "unchecked"))]
+                return sum.checked_add(x).ok_or_else(|| {
+                    ERR::ErrorArithmetic(format!("Addition overflow: {sum} + {x}"), Position::NONE)
+                        .into()
+                });
+                #[cfg(feature = "unchecked")]
+                return Ok(sum.wrapping_add(x));
+            })
+            .map(Into::into)
+    }
+    /// Return the average of all elements in an array of numbers, as a floating-point number.
+    ///
+    /// All elements must be integers, or all must be floating-point numbers.
+    /// If the array is empty, `()` is returned.
+    ///
+    /// Not available under `no_float`.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = [1, 2, 3, 4];
+    ///
+    /// print(x.avg());         // prints 2.5
+    /// ```
+    #[cfg(not(feature = "no_float"))]
+    #[rhai_fn(return_raw, pure)]
+    pub fn avg(ctx: NativeCallContext, array: &mut Array) -> RhaiResult {
+        if array.is_empty() {
+            return Ok(Dynamic::UNIT);
+        }
+
+        let sum: crate::FLOAT = if check_numbers(&ctx, array)? {
+            array
+                .iter()
+                .map(|x| x.as_int().expect("`INT`") as crate::FLOAT)
+                .sum()
+        } else {
+            array.iter().map(|x| x.as_float().expect("`FLOAT`")).sum()
+        };
+
+        Ok((sum / array.len() as crate::FLOAT).into())
+    }
+    /// Check that all elements of a non-empty array are integers, or all are floating-point
+    /// numbers, returning `true` for integers.
+    fn check_numbers(ctx: &NativeCallContext, array: &Array) -> RhaiResultOf<bool> {
+        let type_id = array[0].type_id();
+
+        #[cfg(not(feature = "no_float"))]
+        let type_id = if type_id == TypeId::of::<crate::FLOAT>() {
+            type_id
+        } else {
+            TypeId::of::<INT>()
+        };
+        #[cfg(feature = "no_float")]
+        let type_id = TypeId::of::<INT>();
+
+        let type_name = if type_id == TypeId::of::<INT>() {
+            std::any::type_name::<INT>()
+        } else {
+            std::any::type_name::<crate::FLOAT>()
+        };
+
+        match array.iter().position(|x| x.type_id() != type_id) {
+            Some(i) => Err(ERR::ErrorMismatchDataType(
+                ctx.engine().map_type_name(type_name).into(),
+                format!(
+                    "{} at index {i}",
+                    ctx.engine().map_type_name(array[i].type_name())
+                ),
+                Position::NONE,
+            )
+            .into()),
+            None => Ok(type_id == TypeId::of::<INT>()),
+        }
+    }
     /// Sort the array based on applying the `comparer` function.
     ///
     /// # Function Parameters
@@ -1893,6 +3097,10 @@ pub mod array_functions {
     /// Remove all elements in the array that returns `true` when applied the `filter` function and
     /// return them as a new array.
     ///
+    /// Removed elements are returned in their original order.
+    ///
+    /// Also available as `drain_filter`.
+    ///
     /// # Function Parameters
     ///
     /// * `element`: copy of array element
@@ -1915,7 +3123,7 @@ pub mod array_functions {
     ///
     /// print(z);       // prints "[5]"
     /// ```
-    #[rhai_fn(return_raw)]
+    #[rhai_fn(name = "drain", name = "drain_filter", return_raw)]
     pub fn drain(ctx: NativeCallContext, array: &mut Array, filter: FnPtr) -> RhaiResultOf<Array> {
         if array.is_empty() {
             return Ok(Array::new());
@@ -1961,6 +3169,10 @@ pub mod array_functions {
     /// Remove all elements in the array that returns `true` when applied a function named by `filter`
     /// and return them as a new array.
     ///
+    /// Removed elements are returned in their original order.
+    ///
+    /// Also available as `drain_filter`.
+    ///
     /// # Function Parameters
     ///
     /// A function with the same name as the value of `filter` must exist taking these parameters:
@@ -1989,7 +3201,7 @@ pub mod array_functions {
     ///
     /// print(z);       // prints "[5]"
     /// ```
-    #[rhai_fn(name = "drain", return_raw)]
+    #[rhai_fn(name = "drain", name = "drain_filter", return_raw)]
     pub fn drain_by_fn_name(
         ctx: NativeCallContext,
         array: &mut Array,
@@ -1999,6 +3211,9 @@ pub mod array_functions {
     }
     /// Remove all elements in the array within an exclusive `range` and return them as a new array.
     ///
+    /// The range is clamped to the bounds of the array, so no error is raised for a range that is
+    /// out of bounds.
+    ///
     /// # Example
     ///
     /// ```rhai
@@ -2024,6 +3239,9 @@ pub mod array_functions {
     }
     /// Remove all elements in the array within an inclusive `range` and return them as a new array.
     ///
+    /// The range is clamped to the bounds of the array, so no error is raised for a range that is
+    /// out of bounds.
+    ///
     /// # Example
     ///
     /// ```rhai
@@ -23,6 +23,9 @@ def_package! {
         combine_with_exported_module!(lib, "write_int", write_int_functions);
         combine_with_exported_module!(lib, "write_string", write_string_functions);
 
+        #[cfg(feature = "encoding")]
+        combine_with_exported_module!(lib, "encoding", encoding_functions);
+
         #[cfg(not(feature = "no_float"))]
         {
             combine_with_exported_module!(lib, "parse_float", parse_float_functions);
@@ -81,9 +84,11 @@ pub mod blob_functions {
         // Check if blob will be over max size limit
         #[cfg(not(feature = "unchecked"))]
         if _ctx.engine().max_array_size() > 0 && len > _ctx.engine().max_array_size() {
-            return Err(
-                crate::ERR::ErrorDataTooLarge("Size of BLOB".to_string(), Position::NONE).into(),
-            );
+            return Err(crate::ERR::ErrorDataTooLarge(
+                format!("Size of BLOB ({len})"),
+                Position::NONE,
+            )
+            .into());
         }
 
         let mut blob = Blob::new();
@@ -257,7 +262,12 @@ pub mod blob_functions {
     /// b1.push(b2);
     ///
     /// print(b1);      // prints "[4242424242111111]"
+    ///
+    /// b1.extend(b2);
+    ///
+    /// print(b1);      // prints "[4242424242111111 111111]"
     /// ```
+    #[rhai_fn(name = "append", name = "extend")]
     pub fn append(blob1: &mut Blob, blob2: Blob) {
         if !blob2.is_empty() {
             if blob1.is_empty() {
@@ -366,9 +376,11 @@ pub mod blob_functions {
         // Check if blob will be over max size limit
         #[cfg(not(feature = "unchecked"))]
         if _ctx.engine().max_array_size() > 0 && len > _ctx.engine().max_array_size() {
-            return Err(
-                crate::ERR::ErrorDataTooLarge("Size of BLOB".to_string(), Position::NONE).into(),
-            );
+            return Err(crate::ERR::ErrorDataTooLarge(
+                format!("Size of BLOB ({len})"),
+                Position::NONE,
+            )
+            .into());
         }
 
         if len > blob.len() {
@@ -610,6 +622,9 @@ pub mod blob_functions {
     }
     /// Copy an exclusive `range` of the BLOB and return it as a new BLOB.
     ///
+    /// Unlike indexing with a range (e.g. `b[1..3]`), which raises an error when the range is out
+    /// of bounds, only the part of the range within the BLOB is copied.
+    ///
     /// # Example
     ///
     /// ```rhai
@@ -1606,3 +1621,151 @@ mod write_string_functions {
         write_string(blob, start, len, string, true);
     }
 }
+
+#[cfg(feature = "encoding")]
+#[export_module]
+mod encoding_functions {
+    use crate::ERR;
+    use base64::engine::general_purpose::{STANDARD, URL_SAFE};
+    use base64::Engine as _;
+
+    /// Make an error for a string that cannot be decoded.
+    #[cold]
+    #[inline(never)]
+    fn decode_error(
+        ctx: &NativeCallContext,
+        format: &str,
+        err: impl std::fmt::Display,
+    ) -> Box<ERR> {
+        ERR::ErrorRuntime(
+            format!("Invalid {format} string: {err}").into(),
+            ctx.position(),
+        )
+        .into()
+    }
+
+    /// Return the base64 representation of the BLOB, using the standard alphabet with padding.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let b = blob();
+    ///
+    /// b += 1; b += 2; b += 3; b += 4;
+    ///
+    /// print(b.to_base64());       // prints "AQIDBA=="
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn to_base64(blob: &mut Blob) -> String {
+        STANDARD.encode(blob)
+    }
+    /// Return the base64 representation of the BLOB, using the URL-safe alphabet with padding.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let b = blob(3, 0xff);
+    ///
+    /// print(b.to_base64_url());   // prints "____"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn to_base64_url(blob: &mut Blob) -> String {
+        URL_SAFE.encode(blob)
+    }
+    /// Decode a base64 string, using the standard alphabet with padding, into a BLOB.
+    ///
+    /// An error is raised if the string is not valid base64.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let b = from_base64("AQIDBA==");
+    ///
+    /// print(b);                   // prints "[01020304]"
+    ///
+    /// from_base64("AQ!D");        // error: invalid base64 string
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn from_base64(ctx: NativeCallContext, string: &str) -> RhaiResultOf<Blob> {
+        STANDARD
+            .decode(string)
+            .map_err(|err| decode_error(&ctx, "base64", err))
+    }
+    /// Decode a base64 string, using the URL-safe alphabet with padding, into a BLOB.
+    ///
+    /// An error is raised if the string is not valid URL-safe base64.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let b = from_base64_url("____");
+    ///
+    /// print(b);                   // prints "[ffffff]"
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn from_base64_url(ctx: NativeCallContext, string: &str) -> RhaiResultOf<Blob> {
+        URL_SAFE
+            .decode(string)
+            .map_err(|err| decode_error(&ctx, "base64", err))
+    }
+    /// Return the hexadecimal representation of the BLOB, in lower-case.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let b = blob(3, 0xab);
+    ///
+    /// print(b.to_hex());          // prints "ababab"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn to_hex(blob: &mut Blob) -> String {
+        const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        let mut result = String::with_capacity(blob.len() * 2);
+
+        for &byte in blob.iter() {
+            result.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+            result.push(HEX_DIGITS[(byte & 0x0f) as usize] as char);
+        }
+
+        result
+    }
+    /// Decode a hexadecimal string, in either upper-case or lower-case, into a BLOB.
+    ///
+    /// An error is raised if the string does not contain an even number of hexadecimal digits.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let b = from_hex("01aBff");
+    ///
+    /// print(b);                   // prints "[01abff]"
+    ///
+    /// from_hex("123");            // error: odd number of digits
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn from_hex(ctx: NativeCallContext, string: &str) -> RhaiResultOf<Blob> {
+        let digits = string.as_bytes();
+
+        if digits.len() % 2 != 0 {
+            return Err(decode_error(&ctx, "hexadecimal", "odd number of digits"));
+        }
+
+        digits
+            .chunks(2)
+            .map(|pair| {
+                let hi = (pair[0] as char).to_digit(16);
+                let lo = (pair[1] as char).to_digit(16);
+
+                match (hi, lo) {
+                    (Some(hi), Some(lo)) => Ok((hi * 16 + lo) as u8),
+                    _ => Err(decode_error(
+                        &ctx,
+                        "hexadecimal",
+                        format!("invalid digits '{}{}'", pair[0] as char, pair[1] as char),
+                    )),
+                }
+            })
+            .collect()
+    }
+}
@@ -359,6 +359,10 @@ def_package! {
         #[cfg(feature = "decimal")]
         reg_range!(lib | step "range" => Decimal);
 
+        // Register character range iterators
+        lib.set_iterator::<Range<char>>();
+        lib.set_iterator::<RangeInclusive<char>>();
+
         // Register string iterator
         lib.set_iterator::<CharsStream>();
 
@@ -638,6 +642,7 @@ def_package! {
         }
 
         combine_with_exported_module!(lib, "range", range_functions);
+        combine_with_exported_module!(lib, "lazy_sequence", lazy_sequence_functions);
     }
 }
 
@@ -709,3 +714,41 @@ mod range_functions {
         range.contains(&value)
     }
 }
+
+#[export_module]
+mod lazy_sequence_functions {
+    use crate::{FnPtr, LazySequence};
+
+    /// Return a lazy sequence yielding at most `n` values of the sequence.
+    ///
+    /// If `n` ≤ 0, the sequence yields no values.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// // 'numbers' is a native function returning an endless lazy sequence
+    /// for x in numbers().take(3) {
+    ///     print(x);
+    /// }
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn take(seq: &mut LazySequence, n: INT) -> LazySequence {
+        seq.take(n.clamp(0, MAX_USIZE_INT) as usize)
+    }
+    /// Return a lazy sequence mapping each value of the sequence with the `mapper` function.
+    ///
+    /// The function is only called when the sequence is iterated.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// // 'numbers' is a native function returning an endless lazy sequence
+    /// for x in numbers().map(|x| x * x).take(3) {
+    ///     print(x);
+    /// }
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn map(seq: &mut LazySequence, mapper: FnPtr) -> LazySequence {
+        seq.map(mapper)
+    }
+}
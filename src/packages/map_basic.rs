@@ -1,8 +1,13 @@
 #![cfg(not(feature = "no_object"))]
 
+use super::string_basic::{print_with_func, FUNC_TO_STRING};
+use crate::api::json::format_map_as_json_pretty;
 use crate::engine::OP_EQUALS;
 use crate::plugin::*;
-use crate::{def_package, format_map_as_json, Dynamic, ImmutableString, Map, RhaiResultOf, INT};
+use crate::{
+    def_package, format_map_as_json, Dynamic, FnPtr, ImmutableString, Map, Position, RhaiResultOf,
+    ERR, INT,
+};
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 
@@ -15,6 +20,7 @@ def_package! {
         lib.standard = true;
 
         combine_with_exported_module!(lib, "map", map_functions);
+        combine_with_exported_module!(lib, "typed_map", typed_map_functions);
     }
 }
 
@@ -88,6 +94,83 @@ mod map_functions {
             map.insert(property.into(), value);
         }
     }
+    /// Get the value of the `property` in the object map and return a copy.
+    ///
+    /// If `property` does not exist in the object map, it is first added with the `default` value.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = #{a: 1};
+    ///
+    /// print(m.get_or_insert("a", 42));    // prints 1
+    ///
+    /// print(m.get_or_insert("b", 42));    // prints 42
+    ///
+    /// print(m);                           // prints "#{a: 1, b: 42}"
+    /// ```
+    pub fn get_or_insert(map: &mut Map, property: &str, default: Dynamic) -> Dynamic {
+        map.entry(property.into()).or_insert(default).clone()
+    }
+    /// Get the value of the `property` in the object map and return a copy.
+    ///
+    /// If `property` does not exist in the object map, it is first added with the value returned
+    /// by the `generator` function, which is not called otherwise.
+    ///
+    /// # Function Parameters
+    ///
+    /// A `generator` function takes no parameters, or the following parameter:
+    ///
+    /// * `property`: name of the property
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let counts = #{};
+    ///
+    /// for word in ["a", "b", "a"] {
+    ///     counts.get_or_insert_with(word, || 0);
+    ///     counts[word] += 1;
+    /// }
+    ///
+    /// print(counts);      // prints "#{a: 2, b: 1}"
+    ///
+    /// print(counts.get_or_insert_with("hello", |p| p.len()));    // prints 5
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn get_or_insert_with(
+        ctx: NativeCallContext,
+        map: &mut Map,
+        property: &str,
+        generator: FnPtr,
+    ) -> RhaiResultOf<Dynamic> {
+        if let Some(value) = map.get(property) {
+            return Ok(value.clone());
+        }
+
+        let value = generator
+            .call_raw(&ctx, None, [] as [Dynamic; 0])
+            .or_else(|err| match *err {
+                ERR::ErrorFunctionNotFound(fn_sig, ..)
+                    if fn_sig.starts_with(generator.fn_name()) =>
+                {
+                    generator.call_raw(&ctx, None, [property.into()])
+                }
+                _ => Err(err),
+            })
+            .map_err(|err| {
+                Box::new(ERR::ErrorInFunctionCall(
+                    "get_or_insert_with".to_string(),
+                    ctx.source().unwrap_or("").to_string(),
+                    err,
+                    Position::NONE,
+                ))
+            })?;
+
+        map.insert(property.into(), value.clone());
+
+        Ok(value)
+    }
     /// Clear the object map.
     pub fn clear(map: &mut Map) {
         if !map.is_empty() {
@@ -248,7 +331,7 @@ mod map_functions {
         equals(ctx, map1, map2).map(|r| !r)
     }
 
-    /// Return an array with all the property names in the object map.
+    /// Return an array with all the property names in the object map, sorted by name.
     ///
     /// # Example
     ///
@@ -266,7 +349,8 @@ mod map_functions {
             map.keys().cloned().map(Into::into).collect()
         }
     }
-    /// Return an array with all the property values in the object map.
+    /// Return an array with all the property values in the object map, in the order of their
+    /// sorted property names.
     ///
     /// # Example
     ///
@@ -284,6 +368,78 @@ mod map_functions {
             map.values().cloned().collect()
         }
     }
+    /// Return an array with all the properties in the object map as `[name, value]` pairs,
+    /// sorted by name.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = #{b:2, a:1, c:3};
+    ///
+    /// print(m.entries());     // prints "[["a", 1], ["b", 2], ["c", 3]]"
+    ///
+    /// let x = m.entries().filter(|e| e[1] > 1).map(|e| e[0]);
+    ///
+    /// print(x);               // prints "["b", "c"]"
+    /// ```
+    #[cfg(not(feature = "no_index"))]
+    #[rhai_fn(pure)]
+    pub fn entries(map: &mut Map) -> Array {
+        if map.is_empty() {
+            Array::new()
+        } else {
+            map.iter()
+                .map(|(k, v)| Dynamic::from_array(vec![k.clone().into(), v.clone()]))
+                .collect()
+        }
+    }
+    /// Return a new object map with all nested object maps flattened into properties with
+    /// dotted names (e.g. `a.b.c`).
+    ///
+    /// Values that are not object maps, as well as empty object maps, are kept as they are.
+    ///
+    /// # Lossy Flattening
+    ///
+    /// Flattening is lossy when property names already contain dots, as `#{"a.b": 1}` and
+    /// `#{a: #{b: 1}}` both flatten into `#{"a.b": 1}`.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = #{a: #{b: 1, c: #{d: true}}, e: [1, 2]};
+    ///
+    /// print(m.flatten_keys());    // prints "#{"a.b": 1, "a.c.d": true, "e": [1, 2]}"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn flatten_keys(map: &mut Map) -> Map {
+        let mut result = Map::new();
+        flatten_into(&mut result, None, map);
+        result
+    }
+    /// Return a new object map with all properties with dotted names (e.g. `a.b.c`) expanded
+    /// into nested object maps.
+    ///
+    /// This reverses `flatten_keys`.
+    ///
+    /// If a property name is also the prefix of another property name (e.g. `a` and `a.b`),
+    /// the value of the shorter property is replaced by an object map, unless it is already
+    /// an object map, in which case the properties are merged into it.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = #{"a.b": 1, "a.c.d": true, e: 42};
+    ///
+    /// print(m.unflatten_keys());  // prints "#{"a": #{"b": 1, "c": #{"d": true}}, "e": 42}"
+    /// ```
+    #[rhai_fn(pure)]
+    pub fn unflatten_keys(map: &mut Map) -> Map {
+        let mut result = Map::new();
+        for (key, value) in map.iter() {
+            unflatten_into(&mut result, key, value.clone());
+        }
+        result
+    }
     /// Return the JSON representation of the object map.
     ///
     /// # Data types
@@ -306,4 +462,291 @@ mod map_functions {
     pub fn to_json(map: &mut Map) -> String {
         format_map_as_json(map)
     }
+    /// Return the pretty-printed JSON representation of the object map, with `indent` spaces
+    /// per nesting level.
+    ///
+    /// Keys are always in sorted order. If `indent` is zero or negative, the JSON text is compact
+    /// and on a single line.
+    ///
+    /// Arrays and object maps are serialized recursively. Values not supported by JSON are
+    /// serialized as strings via their `to_string` function.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = #{b:2, a:[1, "x"]};
+    ///
+    /// print(m.to_json_pretty(2));
+    /// ```
+    ///
+    /// prints:
+    ///
+    /// ```json
+    /// {
+    ///   "a": [
+    ///     1,
+    ///     "x"
+    ///   ],
+    ///   "b": 2
+    /// }
+    /// ```
+    #[rhai_fn(name = "to_json_pretty", return_raw)]
+    pub fn to_json_pretty(
+        ctx: NativeCallContext,
+        map: &mut Map,
+        indent: INT,
+    ) -> RhaiResultOf<String> {
+        to_json_pretty_with_strict(ctx, map, indent, false)
+    }
+    /// Return the pretty-printed JSON representation of the object map, with `indent` spaces
+    /// per nesting level.
+    ///
+    /// Keys are always in sorted order. If `indent` is zero or negative, the JSON text is compact
+    /// and on a single line.
+    ///
+    /// If `strict` is `true`, an error is raised for any value not supported by JSON (e.g. a
+    /// custom type). Otherwise, such values are serialized as strings via their `to_string`
+    /// function.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = #{a:1, b:timestamp()};
+    ///
+    /// print(m.to_json_pretty(0, false));  // prints {"a":1,"b":"<timestamp>"}
+    ///
+    /// m.to_json_pretty(0, true);          // error: 'timestamp' cannot be serialized into JSON
+    /// ```
+    #[rhai_fn(name = "to_json_pretty", return_raw)]
+    pub fn to_json_pretty_with_strict(
+        ctx: NativeCallContext,
+        map: &mut Map,
+        indent: INT,
+        strict: bool,
+    ) -> RhaiResultOf<String> {
+        let indent = if indent <= 0 { 0 } else { indent as usize };
+
+        format_map_as_json_pretty(map, indent, &|value| {
+            if strict {
+                Err(ERR::ErrorRuntime(
+                    format!(
+                        "Value of type '{}' cannot be serialized into JSON",
+                        ctx.engine().map_type_name(value.type_name())
+                    )
+                    .into(),
+                    Position::NONE,
+                )
+                .into())
+            } else {
+                Ok(print_with_func(FUNC_TO_STRING, &ctx, &mut value.clone()))
+            }
+        })
+    }
+}
+
+#[export_module]
+mod typed_map_functions {
+    use crate::{RhaiResult, TypedMap};
+
+    /// Create a new empty typed map.
+    ///
+    /// Unlike an object map, a typed map accepts `()`, booleans, characters, strings, integers and
+    /// custom types registered as map keys as its keys.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = typed_map();
+    ///
+    /// m[1] = "one";
+    /// m["1"] = "string one";
+    ///
+    /// print(m[1]);        // prints "one"
+    ///
+    /// print(m.len());     // prints 2
+    /// ```
+    pub fn typed_map() -> TypedMap {
+        TypedMap::new()
+    }
+    /// Return the number of entries in the typed map.
+    #[rhai_fn(pure)]
+    pub fn len(map: &mut TypedMap) -> INT {
+        map.len() as INT
+    }
+    /// Return true if the typed map is empty.
+    #[rhai_fn(pure)]
+    pub fn is_empty(map: &mut TypedMap) -> bool {
+        map.is_empty()
+    }
+    /// Returns `true` if the typed map contains a specified key.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = typed_map();
+    /// m[42] = 1;
+    ///
+    /// print(m.contains(42));  // prints true
+    ///
+    /// print(m.contains(0));   // prints false
+    /// ```
+    #[rhai_fn(pure, return_raw)]
+    pub fn contains(
+        ctx: NativeCallContext,
+        map: &mut TypedMap,
+        key: Dynamic,
+    ) -> RhaiResultOf<bool> {
+        map.contains_key(ctx.engine(), &key)
+    }
+    /// Get the value of the `key` in the typed map.
+    ///
+    /// If `key` is not found, `()` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = typed_map();
+    /// m['x'] = 42;
+    ///
+    /// print(m.get('x'));      // prints 42
+    ///
+    /// print(m.get('y'));      // prints empty (for '()')
+    /// ```
+    #[rhai_fn(name = "get", index_get, pure, return_raw)]
+    pub fn get(ctx: NativeCallContext, map: &mut TypedMap, key: Dynamic) -> RhaiResult {
+        Ok(map
+            .get(ctx.engine(), &key)?
+            .cloned()
+            .unwrap_or(Dynamic::UNIT))
+    }
+    /// Set the value of the `key` in the typed map to a new `value`.
+    ///
+    /// If `key` is not found, it is added to the typed map.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = typed_map();
+    /// m.set(true, "yes");
+    ///
+    /// print(m[true]);         // prints "yes"
+    /// ```
+    #[rhai_fn(name = "set", index_set, return_raw)]
+    pub fn set(
+        ctx: NativeCallContext,
+        map: &mut TypedMap,
+        key: Dynamic,
+        value: Dynamic,
+    ) -> RhaiResultOf<()> {
+        map.insert(ctx.engine(), key, value).map(|_| ())
+    }
+    /// Remove the `key` and its value from the typed map and return the value.
+    ///
+    /// If `key` is not found, `()` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = typed_map();
+    /// m[1] = "one";
+    ///
+    /// print(m.remove(1));     // prints "one"
+    ///
+    /// print(m.len());         // prints 0
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn remove(ctx: NativeCallContext, map: &mut TypedMap, key: Dynamic) -> RhaiResult {
+        Ok(map.remove(ctx.engine(), &key)?.unwrap_or(Dynamic::UNIT))
+    }
+    /// Clear the typed map.
+    pub fn clear(map: &mut TypedMap) {
+        map.clear();
+    }
+    /// Return an array with all the keys in the typed map, in no particular order.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = typed_map();
+    /// m[1] = "one";
+    ///
+    /// print(m.keys());        // prints "[1]"
+    /// ```
+    #[cfg(not(feature = "no_index"))]
+    #[rhai_fn(pure)]
+    pub fn keys(map: &mut TypedMap) -> Array {
+        map.iter().map(|(k, ..)| k.clone()).collect()
+    }
+    /// Return an array with all the values in the typed map, in no particular order.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = typed_map();
+    /// m[1] = "one";
+    ///
+    /// print(m.values());      // prints "[one]"
+    /// ```
+    #[cfg(not(feature = "no_index"))]
+    #[rhai_fn(pure)]
+    pub fn values(map: &mut TypedMap) -> Array {
+        map.iter().map(|(.., v)| v.clone()).collect()
+    }
+    /// Return an array with all the entries in the typed map as `[key, value]` pairs, in no
+    /// particular order.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let m = typed_map();
+    /// m[1] = "one";
+    ///
+    /// print(m.entries());     // prints "[[1, "one"]]"
+    /// ```
+    #[cfg(not(feature = "no_index"))]
+    #[rhai_fn(pure)]
+    pub fn entries(map: &mut TypedMap) -> Array {
+        map.iter()
+            .map(|(k, v)| Dynamic::from_array(vec![k.clone(), v.clone()]))
+            .collect()
+    }
+}
+
+/// Add all properties of an object map into `result`, with nested object maps flattened into
+/// dotted property names starting with `prefix`.
+fn flatten_into(result: &mut Map, prefix: Option<&str>, map: &Map) {
+    for (key, value) in map {
+        let path = match prefix {
+            Some(prefix) => format!("{prefix}.{key}").into(),
+            None => key.clone(),
+        };
+
+        match value.read_lock::<Map>() {
+            Some(inner) if !inner.is_empty() => flatten_into(result, Some(path.as_str()), &inner),
+            _ => {
+                result.insert(path, value.clone());
+            }
+        }
+    }
+}
+
+/// Insert a value into an object map under a dotted property name, creating nested object maps
+/// as necessary.
+fn unflatten_into(map: &mut Map, path: &str, value: Dynamic) {
+    match path.split_once('.') {
+        Some((key, rest)) => {
+            let entry = map
+                .entry(key.into())
+                .or_insert_with(|| Dynamic::from_map(Map::new()));
+
+            if entry.read_lock::<Map>().is_none() {
+                *entry = Dynamic::from_map(Map::new());
+            }
+
+            unflatten_into(&mut entry.write_lock::<Map>().unwrap(), rest, value);
+        }
+        None => {
+            map.insert(path.into(), value);
+        }
+    }
 }
@@ -1,7 +1,7 @@
 #![allow(non_snake_case)]
 
 use crate::plugin::*;
-use crate::{def_package, Position, RhaiResultOf, ERR, INT};
+use crate::{def_package, Position, RhaiError, RhaiResultOf, ERR, INT};
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 
@@ -69,6 +69,10 @@ def_package! {
             #[cfg(not(target_family = "wasm"))]
 
             reg_functions!(lib += num_128_to_int::to_int(i128, u128));
+
+            #[cfg(not(target_family = "wasm"))]
+
+            reg_functions!(lib += basic_to_i128::to_i128(INT));
         }
 
         #[cfg(not(feature = "no_float"))]
@@ -125,6 +129,11 @@ mod int_functions {
     ///
     /// `radix` must be between 2 and 36.
     ///
+    /// * A leading `+` or `-` sign is allowed.
+    /// * A `0b`, `0o` or `0x` prefix is allowed when `radix` is 2, 8 or 16 respectively; a prefix
+    ///   that does not match `radix` is an error.
+    /// * An error is raised if the number does not fit into an integer.
+    ///
     /// # Example
     ///
     /// ```rhai
@@ -135,22 +144,108 @@ mod int_functions {
     /// let y = parse_int("123abc", 16);
     ///
     /// print(y);       // prints 1194684 (0x123abc)
+    ///
+    /// let z = parse_int("-0b1010", 2);
+    ///
+    /// print(z);       // prints -10
     /// ```
     #[rhai_fn(name = "parse_int", return_raw)]
     pub fn parse_int_radix(string: &str, radix: INT) -> RhaiResultOf<INT> {
-        if !(2..=36).contains(&radix) {
-            return Err(
-                ERR::ErrorArithmetic(format!("Invalid radix: '{radix}'"), Position::NONE).into(),
-            );
-        }
+        check_radix(radix)?;
 
-        INT::from_str_radix(string.trim(), radix as u32).map_err(|err| {
+        let parse_err = |err: &dyn std::fmt::Display| -> RhaiError {
             ERR::ErrorArithmetic(
                 format!("Error parsing integer number '{string}': {err}"),
                 Position::NONE,
             )
             .into()
-        })
+        };
+
+        let s = string.trim();
+
+        let (negative, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let digits = match (radix, s.get(..2)) {
+            (2, Some("0b" | "0B")) | (8, Some("0o" | "0O")) | (16, Some("0x" | "0X")) => &s[2..],
+            (_, Some(prefix @ ("0b" | "0B" | "0o" | "0O" | "0x" | "0X")))
+                if !prefix[1..].chars().all(|c| c.is_digit(radix as u32)) =>
+            {
+                return Err(parse_err(&format!(
+                    "prefix '{prefix}' is not valid for radix {radix}"
+                )));
+            }
+            _ => s,
+        };
+
+        if digits.starts_with(|c| c == '+' || c == '-') {
+            return Err(parse_err(&"invalid digit found in string"));
+        }
+
+        if negative {
+            INT::from_str_radix(&format!("-{digits}"), radix as u32)
+        } else {
+            INT::from_str_radix(digits, radix as u32)
+        }
+        .map_err(|err| parse_err(&err))
+    }
+    /// Convert the integer number into a string in the specified `radix`.
+    ///
+    /// `radix` must be between 2 and 36. Digits above 9 are in lower-case.
+    ///
+    /// No prefix (e.g. `0x`) is added. Negative numbers are prefixed by a `-` sign.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let x = to_radix_string(255, 16);
+    ///
+    /// print(x);       // prints "ff"
+    ///
+    /// let y = to_radix_string(-10, 2);
+    ///
+    /// print(y);       // prints "-1010"
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn to_radix_string(value: INT, radix: INT) -> RhaiResultOf<ImmutableString> {
+        check_radix(radix)?;
+
+        let radix = radix as u32;
+        let mut n = value.unsigned_abs();
+        let mut digits = Vec::new();
+
+        loop {
+            let digit = (n % (radix as crate::UNSIGNED_INT)) as u32;
+            digits.push(std::char::from_digit(digit, radix).expect("digit < radix"));
+            n /= radix as crate::UNSIGNED_INT;
+
+            if n == 0 {
+                break;
+            }
+        }
+
+        if value < 0 {
+            digits.push('-');
+        }
+
+        Ok(digits.into_iter().rev().collect::<String>().into())
+    }
+    /// Return the integer number unchanged.
+    #[cfg(any(feature = "only_i32", feature = "only_i64"))]
+    #[rhai_fn(name = "to_int")]
+    pub fn int_to_int(x: INT) -> INT {
+        x
+    }
+
+    fn check_radix(radix: INT) -> RhaiResultOf<()> {
+        if (2..=36).contains(&radix) {
+            Ok(())
+        } else {
+            Err(ERR::ErrorArithmetic(format!("Invalid radix: '{radix}'"), Position::NONE).into())
+        }
     }
 }
 
@@ -358,6 +453,11 @@ mod float_functions {
     pub fn f32_to_f64(x: f32) -> f64 {
         x as f64
     }
+    /// Return the floating-point number unchanged.
+    #[rhai_fn(name = "to_float")]
+    pub fn float_to_float(x: FLOAT) -> FLOAT {
+        x
+    }
 }
 
 #[cfg(feature = "decimal")]
@@ -639,6 +739,11 @@ mod decimal_functions {
             .into()
         })
     }
+    /// Return the decimal number unchanged.
+    #[rhai_fn(name = "to_decimal")]
+    pub fn decimal_to_decimal(x: Decimal) -> Decimal {
+        x
+    }
 }
 
 #[cfg(not(feature = "no_float"))]
@@ -668,6 +773,12 @@ gen_conversion_as_functions!(numbers_to_int => to_int (i8, u8, i16, u16, i32, u3
 
 gen_conversion_as_functions!(num_128_to_int => to_int (i128, u128) -> INT);
 
+#[cfg(not(feature = "only_i32"))]
+#[cfg(not(feature = "only_i64"))]
+#[cfg(not(target_family = "wasm"))]
+
+gen_conversion_as_functions!(basic_to_i128 => to_i128 (INT) -> i128);
+
 #[cfg(feature = "decimal")]
 gen_conversion_into_functions!(basic_to_decimal => to_decimal (INT) -> Decimal);
 
@@ -15,6 +15,7 @@ pub(crate) mod map_basic;
 pub(crate) mod math_basic;
 pub(crate) mod pkg_core;
 pub(crate) mod pkg_std;
+pub(crate) mod regex_basic;
 pub(crate) mod string_basic;
 pub(crate) mod string_more;
 pub(crate) mod time_basic;
@@ -36,6 +37,8 @@ pub use map_basic::BasicMapPackage;
 pub use math_basic::BasicMathPackage;
 pub use pkg_core::CorePackage;
 pub use pkg_std::StandardPackage;
+#[cfg(feature = "regex")]
+pub use regex_basic::BasicRegexPackage;
 pub use string_basic::BasicStringPackage;
 pub use string_more::MoreStringPackage;
 #[cfg(not(feature = "no_std"))]
@@ -18,6 +18,7 @@ def_package! {
     /// * [`BasicMapPackage`][super::BasicMapPackage]
     /// * [`BasicTimePackage`][super::BasicTimePackage]
     /// * [`MoreStringPackage`][super::MoreStringPackage]
+    /// * [`BasicRegexPackage`][super::BasicRegexPackage]
     pub StandardPackage(lib) :
             CorePackage,
             BitFieldPackage,
@@ -27,7 +28,8 @@ def_package! {
             #[cfg(not(feature = "no_index"))] BasicBlobPackage,
             #[cfg(not(feature = "no_object"))] BasicMapPackage,
             #[cfg(not(feature = "no_std"))] BasicTimePackage,
-            MoreStringPackage
+            MoreStringPackage,
+            #[cfg(feature = "regex")] BasicRegexPackage
     {
         lib.standard = true;
     }
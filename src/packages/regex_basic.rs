@@ -0,0 +1,163 @@
+#![cfg(feature = "regex")]
+
+use crate::func::{locked_read, locked_write};
+use crate::plugin::*;
+use crate::{def_package, RhaiResultOf, ERR};
+use regex::Regex;
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+/// Maximum number of compiled regular expressions cached in an [`Engine`].
+///
+/// When the cache is full, it is cleared before the next pattern is added.
+const MAX_REGEX_CACHE_SIZE: usize = 256;
+
+def_package! {
+    /// Package of basic regular expression utilities.
+    pub BasicRegexPackage(lib) {
+        lib.standard = true;
+
+        combine_with_exported_module!(lib, "regex", regex_functions);
+    }
+}
+
+/// Get the compiled [`Regex`] for a pattern.
+///
+/// Compiled patterns are cached inside the [`Engine`] so that the same pattern is never compiled
+/// twice.
+fn get_regex(ctx: &NativeCallContext, pattern: &str) -> RhaiResultOf<Regex> {
+    let engine = ctx.engine();
+
+    if let Some(re) = locked_read(&engine.regex_cache).get(pattern) {
+        return Ok(re.clone());
+    }
+
+    let re = Regex::new(pattern).map_err(|err| {
+        ERR::ErrorRuntime(
+            format!("Invalid regular expression '{pattern}': {err}").into(),
+            ctx.position(),
+        )
+    })?;
+
+    let mut cache = locked_write(&engine.regex_cache);
+
+    if cache.len() >= MAX_REGEX_CACHE_SIZE {
+        cache.clear();
+    }
+    cache.insert(pattern.into(), re.clone());
+
+    Ok(re)
+}
+
+#[export_module]
+mod regex_functions {
+    /// Return `true` if the string contains a match of the regular expression `pattern`.
+    ///
+    /// An error is raised if `pattern` is not a valid regular expression.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "ERROR 2022-10-14: disk full";
+    ///
+    /// print(text.regex_match(`\d{4}-\d{2}-\d{2}`));    // prints true
+    ///
+    /// print(text.regex_match("^WARN"));                // prints false
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn regex_match(ctx: NativeCallContext, string: &str, pattern: &str) -> RhaiResultOf<bool> {
+        Ok(get_regex(&ctx, pattern)?.is_match(string))
+    }
+    /// Replace all matches of the regular expression `pattern` in the string with `replacement`,
+    /// returning the new string.
+    ///
+    /// Capture groups can be referred to in `replacement` via `$1`, `$2` etc. or `${name}` for
+    /// named groups. Use `$$` for a literal `$`.
+    ///
+    /// An error is raised if `pattern` is not a valid regular expression.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "2022-10-14";
+    ///
+    /// let x = text.regex_replace(`(\d+)-(\d+)-(\d+)`, "$3/$2/$1");
+    ///
+    /// print(x);       // prints "14/10/2022"
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn regex_replace(
+        ctx: NativeCallContext,
+        string: ImmutableString,
+        pattern: &str,
+        replacement: &str,
+    ) -> RhaiResultOf<ImmutableString> {
+        let re = get_regex(&ctx, pattern)?;
+
+        Ok(match re.replace_all(&string, replacement) {
+            std::borrow::Cow::Borrowed(_) => string,
+            std::borrow::Cow::Owned(s) => s.into(),
+        })
+    }
+
+    #[cfg(not(feature = "no_index"))]
+    pub mod arrays {
+        use crate::Array;
+
+        /// Return an array containing all non-overlapping matches of the regular expression
+        /// `pattern` in the string.
+        ///
+        /// An error is raised if `pattern` is not a valid regular expression.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// let text = "a1 b22 c333";
+        ///
+        /// print(text.regex_find_all(`\d+`));      // prints ["1", "22", "333"]
+        /// ```
+        #[rhai_fn(return_raw)]
+        pub fn regex_find_all(
+            ctx: NativeCallContext,
+            string: &str,
+            pattern: &str,
+        ) -> RhaiResultOf<Array> {
+            let re = get_regex(&ctx, pattern)?;
+
+            Ok(re.find_iter(string).map(|m| m.as_str().into()).collect())
+        }
+        /// Return an array containing the capture groups of the first match of the regular
+        /// expression `pattern` in the string, or `()` if there is no match.
+        ///
+        /// The first element of the array is always the entire match, followed by each capture
+        /// group in order. Capture groups that did not participate in the match are `()`.
+        ///
+        /// An error is raised if `pattern` is not a valid regular expression.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// let text = "key = value";
+        ///
+        /// print(text.regex_captures(`(\w+)\s*=\s*(\w+)`));    // prints ["key = value", "key", "value"]
+        ///
+        /// print(text.regex_captures(`\d+`));                  // prints ()
+        /// ```
+        #[rhai_fn(return_raw)]
+        pub fn regex_captures(
+            ctx: NativeCallContext,
+            string: &str,
+            pattern: &str,
+        ) -> RhaiResultOf<Dynamic> {
+            let re = get_regex(&ctx, pattern)?;
+
+            Ok(re.captures(string).map_or(Dynamic::UNIT, |captures| {
+                captures
+                    .iter()
+                    .map(|m| m.map_or(Dynamic::UNIT, |m| m.as_str().into()))
+                    .collect::<Array>()
+                    .into()
+            }))
+        }
+    }
+}
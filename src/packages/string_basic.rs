@@ -1,5 +1,7 @@
+#[cfg(any(not(feature = "no_index"), not(feature = "no_object")))]
+use crate::engine::KEYWORD_DEBUG;
 use crate::plugin::*;
-use crate::{def_package, FnPtr, INT};
+use crate::{def_package, FnPtr, RhaiResultOf, ERR, INT};
 use std::any::TypeId;
 use std::fmt::{Binary, LowerHex, Octal};
 #[cfg(feature = "no_std")]
@@ -47,6 +49,70 @@ pub fn print_with_func(
     }
 }
 
+/// Format a value in debug format, spreading arrays and object maps over multiple lines with
+/// nested values indented by `level` levels.
+#[cfg(any(not(feature = "no_index"), not(feature = "no_object")))]
+fn format_debug_pretty(ctx: &NativeCallContext, value: &mut Dynamic, level: usize) -> String {
+    #[cfg(not(feature = "no_index"))]
+    if let Some(mut array) = value.write_lock::<Array>() {
+        return format_array_pretty(ctx, &mut array, level);
+    }
+    #[cfg(not(feature = "no_object"))]
+    if let Some(mut map) = value.write_lock::<Map>() {
+        return format_map_pretty(ctx, &mut map, level);
+    }
+    print_with_func(FUNC_TO_DEBUG, ctx, value).into()
+}
+
+/// Format an array in debug format over multiple lines.
+#[cfg(not(feature = "no_index"))]
+fn format_array_pretty(ctx: &NativeCallContext, array: &mut Array, level: usize) -> String {
+    if array.is_empty() {
+        return "[]".into();
+    }
+
+    let indent = ctx.engine().debug_indent();
+    let mut result = String::from("[\n");
+
+    for x in array.iter_mut() {
+        result.push_str(&" ".repeat((level + 1) * indent));
+        result.push_str(&format_debug_pretty(ctx, x, level + 1));
+        result.push_str(",\n");
+    }
+
+    result.push_str(&" ".repeat(level * indent));
+    result.push(']');
+    result
+}
+
+/// Format an object map in debug format over multiple lines.
+#[cfg(not(feature = "no_object"))]
+fn format_map_pretty(ctx: &NativeCallContext, map: &mut Map, level: usize) -> String {
+    if map.is_empty() {
+        return "#{}".into();
+    }
+
+    let indent = ctx.engine().debug_indent();
+    let mut result = String::from("#{\n");
+
+    for (k, v) in map.iter_mut() {
+        use std::fmt::Write;
+
+        writeln!(
+            result,
+            "{}{:?}: {},",
+            " ".repeat((level + 1) * indent),
+            k,
+            format_debug_pretty(ctx, v, level + 1)
+        )
+        .unwrap();
+    }
+
+    result.push_str(&" ".repeat(level * indent));
+    result.push('}');
+    result
+}
+
 #[export_module]
 mod print_debug_functions {
     use crate::ImmutableString;
@@ -72,6 +138,29 @@ mod print_debug_functions {
         ctx.engine().map_type_name(&format!("{item:?}")).into()
     }
 
+    /// Log the value of the `message` at the specified `level`.
+    ///
+    /// The level must be one of `"trace"`, `"debug"`, `"info"`, `"warn"` or `"error"`
+    /// (case-insensitive).
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// log("warn", "something is not right");
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn log(ctx: NativeCallContext, level: &str, mut message: Dynamic) -> RhaiResultOf<()> {
+        let level = crate::LogLevel::from_name(level).ok_or_else(|| {
+            ERR::ErrorRuntime(
+                format!("Invalid log level: '{level}'").into(),
+                Position::NONE,
+            )
+        })?;
+        let text = print_with_func(FUNC_TO_STRING, &ctx, &mut message);
+        (ctx.engine().log)(level, &text);
+        Ok(())
+    }
+
     /// Return the empty string.
     #[rhai_fn(name = "print", name = "debug")]
     pub fn print_empty_string(ctx: NativeCallContext) -> ImmutableString {
@@ -167,6 +256,10 @@ mod print_debug_functions {
         pure
     )]
     pub fn format_array(ctx: NativeCallContext, array: &mut Array) -> ImmutableString {
+        if ctx.fn_name() == KEYWORD_DEBUG && ctx.engine().debug_pretty() {
+            return format_array_pretty(&ctx, array, 0).into();
+        }
+
         let len = array.len();
         let mut result = String::with_capacity(len * 5 + 2);
         result.push('[');
@@ -192,6 +285,10 @@ mod print_debug_functions {
         pure
     )]
     pub fn format_map(ctx: NativeCallContext, map: &mut Map) -> ImmutableString {
+        if ctx.fn_name() == KEYWORD_DEBUG && ctx.engine().debug_pretty() {
+            return format_map_pretty(&ctx, map, 0).into();
+        }
+
         let len = map.len();
         let mut result = String::with_capacity(len * 5 + 3);
         result.push_str("#{");
@@ -1,7 +1,8 @@
+use crate::eval::calc_index;
 use crate::plugin::*;
 use crate::{
-    def_package, Dynamic, ExclusiveRange, InclusiveRange, RhaiResultOf, StaticVec, INT,
-    MAX_USIZE_INT,
+    def_package, Dynamic, ExclusiveRange, InclusiveRange, Position, RhaiResultOf, StaticVec, ERR,
+    INT, MAX_USIZE_INT,
 };
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
@@ -151,6 +152,8 @@ mod string_functions {
 
     /// Return the length of the string, in number of characters.
     ///
+    /// Use `bytes` to get the number of bytes used to store the string instead.
+    ///
     /// # Example
     ///
     /// ```rhai
@@ -287,19 +290,64 @@ mod string_functions {
     /// print(text);    // prints "hello"
     /// ```
     pub fn trim(string: &mut ImmutableString) {
-        if let Some(s) = string.get_mut() {
-            let trimmed = s.trim();
-
-            if trimmed != s {
-                *s = trimmed.into();
-            }
-        } else {
-            let trimmed = string.trim();
-
-            if trimmed != string {
-                *string = trimmed.into();
-            }
-        }
+        trim_with(string, str::trim);
+    }
+    /// Remove whitespace characters from the start of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "   hello     ";
+    ///
+    /// text.trim_start();
+    ///
+    /// print(text);    // prints "hello     "
+    /// ```
+    pub fn trim_start(string: &mut ImmutableString) {
+        trim_with(string, str::trim_start);
+    }
+    /// Remove whitespace characters from the end of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "   hello     ";
+    ///
+    /// text.trim_end();
+    ///
+    /// print(text);    // prints "   hello"
+    /// ```
+    pub fn trim_end(string: &mut ImmutableString) {
+        trim_with(string, str::trim_end);
+    }
+    /// Remove any of the characters in `chars` from both ends of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "--==hello==--";
+    ///
+    /// text.trim_matches("-=");
+    ///
+    /// print(text);    // prints "hello"
+    /// ```
+    pub fn trim_matches(string: &mut ImmutableString, chars: &str) {
+        trim_with(string, |s| s.trim_matches(|ch| chars.contains(ch)));
+    }
+    /// Remove the character `character` from both ends of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "***hello***";
+    ///
+    /// text.trim_matches('*');
+    ///
+    /// print(text);    // prints "hello"
+    /// ```
+    #[rhai_fn(name = "trim_matches")]
+    pub fn trim_matches_char(string: &mut ImmutableString, character: char) {
+        trim_with(string, |s| s.trim_matches(character));
     }
     /// Remove the last character from the string and return it.
     ///
@@ -518,7 +566,7 @@ mod string_functions {
     /// print(text.contains("hey"));    // prints false
     /// ```
     pub fn contains(string: &str, match_string: &str) -> bool {
-        string.contains(match_string)
+        find_str(string, match_string).is_some()
     }
 
     /// Return `true` if the string contains a specified character.
@@ -704,11 +752,9 @@ mod string_functions {
                 .len()
         };
 
-        string[start..]
-            .find(find_string)
-            .map_or(-1 as INT, |index| {
-                string[0..start + index].chars().count() as INT
-            })
+        find_str(&string[start..], find_string).map_or(-1 as INT, |index| {
+            string[0..start + index].chars().count() as INT
+        })
     }
     /// Find the specified `character` in the string and return the first index where it is found.
     /// If the `character` is not found, `-1` is returned.
@@ -727,11 +773,44 @@ mod string_functions {
         if string.is_empty() {
             -1
         } else {
-            string
-                .find(find_string)
+            find_str(string, find_string)
                 .map_or(-1 as INT, |index| string[0..index].chars().count() as INT)
         }
     }
+    /// Find the specified `character` in the string and return the last index where it is found.
+    /// If the `character` is not found, `-1` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "hello, world!";
+    ///
+    /// print(text.rindex_of('l'));     // prints 10 (last index)
+    ///
+    /// print(text.rindex_of('x'));     // prints -1
+    /// ```
+    #[rhai_fn(name = "rindex_of")]
+    pub fn rindex_of_char(string: &str, character: char) -> INT {
+        string
+            .rfind(character)
+            .map_or(-1 as INT, |index| string[0..index].chars().count() as INT)
+    }
+    /// Find the specified sub-string in the string and return the last index where it is found.
+    /// If the sub-string is not found, `-1` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "hello, world! hello, foobar!";
+    ///
+    /// print(text.rindex_of("ll"));    // prints 16 (last index)
+    ///
+    /// print(text.rindex_of("xx"));    // prints -1
+    /// ```
+    pub fn rindex_of(string: &str, find_string: &str) -> INT {
+        rfind_str(string, find_string)
+            .map_or(-1 as INT, |index| string[0..index].chars().count() as INT)
+    }
 
     /// Get the character at the `index` position in the string.
     ///
@@ -775,6 +854,61 @@ mod string_functions {
                 .map_or_else(|| Dynamic::UNIT, Into::into)
         }
     }
+    /// Get the character at the `index` position (in number of characters) in the string.
+    ///
+    /// * If `index` < 0, position counts from the end of the string (`-1` is the last character).
+    /// * If `index` is out of bounds, an error is raised, just like indexing the string with `[]`.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "朝には紅顔";
+    ///
+    /// print(text.char_at(1));     // prints 'に'
+    ///
+    /// print(text.char_at(-1));    // prints '顔'
+    ///
+    /// text.char_at(99);           // error: index out of bounds
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn char_at(string: &str, index: INT) -> RhaiResultOf<char> {
+        let len = string.chars().count();
+
+        let offset = calc_index(len, index, true, || {
+            ERR::ErrorStringBounds(len, index, Position::NONE).into()
+        })?;
+
+        Ok(string.chars().nth(offset).expect("`offset` < `len`"))
+    }
+    /// Get the byte at the `index` position (in number of bytes) in the UTF-8 encoding of the
+    /// string.
+    ///
+    /// * If `index` < 0, position counts from the end of the string (`-1` is the last byte).
+    /// * If `index` is out of bounds, an error is raised, just like indexing the string with `[]`.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "héllo";
+    ///
+    /// print(text.byte_at(0));     // prints 104
+    ///
+    /// print(text.byte_at(1));     // prints 195
+    ///
+    /// print(text.byte_at(-1));    // prints 111
+    ///
+    /// text.byte_at(99);           // error: index out of bounds
+    /// ```
+    #[rhai_fn(return_raw)]
+    pub fn byte_at(string: &str, index: INT) -> RhaiResultOf<INT> {
+        let len = string.len();
+
+        let offset = calc_index(len, index, true, || {
+            ERR::ErrorStringBounds(len, index, Position::NONE).into()
+        })?;
+
+        Ok(string.as_bytes()[offset] as INT)
+    }
     /// Set the `index` position in the string to a new `character`.
     ///
     /// * If `index` < 0, position counts from the end of the string (`-1` is the last character).
@@ -1316,6 +1450,167 @@ mod string_functions {
 
         Ok(())
     }
+    /// Pad the start of the string to at least `width` characters with the specified `character`.
+    ///
+    /// If `width` ≤ length of string, no padding is done.
+    /// An error is raised if `width` < 0.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "42";
+    ///
+    /// text.pad_start(5, '0');
+    ///
+    /// print(text);        // prints "00042"
+    ///
+    /// text.pad_start(3, '*');
+    ///
+    /// print(text);        // prints "00042"
+    /// ```
+    #[rhai_fn(name = "pad_start", return_raw)]
+    pub fn pad_start_with_char(
+        ctx: NativeCallContext,
+        string: &mut ImmutableString,
+        width: INT,
+        character: char,
+    ) -> RhaiResultOf<()> {
+        pad_with(&ctx, string, width, &character.to_string(), true)
+    }
+    /// Pad the start of the string to at least `width` characters by repeating the specified
+    /// string, truncating the last repetition if needed.
+    ///
+    /// If `width` ≤ length of string, or if `padding` is empty, no padding is done.
+    /// An error is raised if `width` < 0.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "hello";
+    ///
+    /// text.pad_start(10, "-=");
+    ///
+    /// print(text);        // prints "-=-=-hello"
+    /// ```
+    #[rhai_fn(name = "pad_start", return_raw)]
+    pub fn pad_start_with_string(
+        ctx: NativeCallContext,
+        string: &mut ImmutableString,
+        width: INT,
+        padding: &str,
+    ) -> RhaiResultOf<()> {
+        pad_with(&ctx, string, width, padding, true)
+    }
+    /// Pad the end of the string to at least `width` characters with the specified `character`.
+    ///
+    /// If `width` ≤ length of string, no padding is done.
+    /// An error is raised if `width` < 0.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "hello";
+    ///
+    /// text.pad_end(8, '.');
+    ///
+    /// print(text);        // prints "hello..."
+    /// ```
+    #[rhai_fn(name = "pad_end", return_raw)]
+    pub fn pad_end_with_char(
+        ctx: NativeCallContext,
+        string: &mut ImmutableString,
+        width: INT,
+        character: char,
+    ) -> RhaiResultOf<()> {
+        pad_with(&ctx, string, width, &character.to_string(), false)
+    }
+    /// Pad the end of the string to at least `width` characters by repeating the specified
+    /// string, truncating the last repetition if needed.
+    ///
+    /// If `width` ≤ length of string, or if `padding` is empty, no padding is done.
+    /// An error is raised if `width` < 0.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "hello";
+    ///
+    /// text.pad_end(10, "-=");
+    ///
+    /// print(text);        // prints "hello-=-=-"
+    /// ```
+    #[rhai_fn(name = "pad_end", return_raw)]
+    pub fn pad_end_with_string(
+        ctx: NativeCallContext,
+        string: &mut ImmutableString,
+        width: INT,
+        padding: &str,
+    ) -> RhaiResultOf<()> {
+        pad_with(&ctx, string, width, padding, false)
+    }
+    /// Return the string repeated the specified number of times.
+    ///
+    /// If `count` ≤ 0, an empty string is returned.
+    ///
+    /// An error is raised if the result would exceed the maximum string size. If there is no such
+    /// limit, a very large `count` tries to allocate the whole string (which may fail).
+    ///
+    /// This function also drives the `*` operator for a string and an integer.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let text = "ab";
+    ///
+    /// print(text.repeat(3));      // prints "ababab"
+    ///
+    /// print("-" * 10);            // prints "----------"
+    /// ```
+    #[rhai_fn(name = "repeat", name = "*", return_raw)]
+    pub fn repeat(
+        ctx: NativeCallContext,
+        string: ImmutableString,
+        count: INT,
+    ) -> RhaiResultOf<ImmutableString> {
+        if count == 1 {
+            return Ok(string);
+        }
+        if string.is_empty() || count <= 0 {
+            return Ok(ctx.engine().get_interned_string(""));
+        }
+
+        repeat_str(&ctx, &string, count)
+    }
+    /// Return a string made of the character repeated the specified number of times.
+    ///
+    /// If `count` ≤ 0, an empty string is returned.
+    ///
+    /// An error is raised if the result would exceed the maximum string size. If there is no such
+    /// limit, a very large `count` tries to allocate the whole string (which may fail).
+    ///
+    /// This function also drives the `*` operator for a character and an integer.
+    ///
+    /// # Example
+    ///
+    /// ```rhai
+    /// let ch = '=';
+    ///
+    /// print(ch.repeat(5));        // prints "====="
+    ///
+    /// print('-' * 10);            // prints "----------"
+    /// ```
+    #[rhai_fn(name = "repeat", name = "*", return_raw)]
+    pub fn repeat_char(
+        ctx: NativeCallContext,
+        character: char,
+        count: INT,
+    ) -> RhaiResultOf<ImmutableString> {
+        if count <= 0 {
+            return Ok(ctx.engine().get_interned_string(""));
+        }
+
+        repeat_str(&ctx, character.encode_utf8(&mut [0; 4]), count)
+    }
 
     #[cfg(not(feature = "no_index"))]
     pub mod arrays {
@@ -1395,6 +1690,59 @@ mod string_functions {
                 string.chars().map(Into::into).collect()
             }
         }
+        /// Return an array containing the byte offset and the character of each character in the
+        /// string, as `[offset, character]` pairs.
+        ///
+        /// The offsets are the same positions used by `byte_at`.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// let text = "h❤llo";
+        ///
+        /// print(text.char_indices());     // prints "[[0, 'h'], [1, '❤'], [4, 'l'], [5, 'l'], [6, 'o']]"
+        /// ```
+        pub fn char_indices(string: &str) -> Array {
+            string
+                .char_indices()
+                .map(|(offset, ch)| Dynamic::from_array(vec![(offset as INT).into(), ch.into()]))
+                .collect()
+        }
+        /// Return an array containing the extended grapheme clusters of the string (i.e. what
+        /// is displayed as a single character, such as an emoji or a letter with combining marks).
+        ///
+        /// Only available under the `unicode` feature.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// let text = "e\u0301👍🏽!";
+        ///
+        /// print(text.graphemes());        // prints "["é", "👍🏽", "!"]"
+        ///
+        /// print(text.to_chars().len);     // prints 5
+        /// ```
+        #[cfg(feature = "unicode")]
+        pub fn graphemes(string: &str) -> Array {
+            use unicode_segmentation::UnicodeSegmentation;
+
+            string.graphemes(true).map(Into::into).collect()
+        }
+        /// Split the string into lines, returning an array of the lines.
+        ///
+        /// Lines are separated by either `\n` or `\r\n`. A trailing line ending does not produce
+        /// an empty final line.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// let text = "hello\r\nworld\n!\n";
+        ///
+        /// print(text.lines());        // prints ["hello", "world", "!"]
+        /// ```
+        pub fn lines(string: &str) -> Array {
+            string.lines().map(Into::into).collect()
+        }
         /// Split the string into segments based on whitespaces, returning an array of the segments.
         ///
         /// # Example
@@ -1422,7 +1770,7 @@ mod string_functions {
         /// print(text.split("ll"));    // prints ["he", "o, world! he", "o, foo!"]
         /// ```
         pub fn split(string: &str, delimiter: &str) -> Array {
-            string.split(delimiter).map(Into::into).collect()
+            split_str(string, delimiter)
         }
         /// Split the string into at most the specified number of `segments` based on a `delimiter` string,
         /// returning an array of the segments.
@@ -1537,5 +1885,360 @@ mod string_functions {
             let pieces: usize = if segments < 1 { 1 } else { segments };
             string.rsplitn(pieces, delimiter).map(Into::into).collect()
         }
+        /// Format the `template` string by replacing each `{index}` placeholder with the element
+        /// at that index in `args`. An empty placeholder `{}` takes the next element in turn.
+        ///
+        /// Use `{{` and `}}` for literal braces. Placeholders without a value are left as-is.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// print("{0} + {0} = {1}".format([21, 42]));     // prints "21 + 21 = 42"
+        ///
+        /// print("{} is {}".format(["x", 1]));           // prints "x is 1"
+        ///
+        /// print("{{{2}}}".format([1]));                 // prints "{{2}}"
+        /// ```
+        #[rhai_fn(name = "format", return_raw)]
+        pub fn format_array(
+            ctx: NativeCallContext,
+            template: &str,
+            args: Array,
+        ) -> RhaiResultOf<ImmutableString> {
+            format_array_with_strict(ctx, template, args, false)
+        }
+        /// Format the `template` string by replacing each `{index}` placeholder with the element
+        /// at that index in `args`. An empty placeholder `{}` takes the next element in turn.
+        ///
+        /// Use `{{` and `}}` for literal braces.
+        ///
+        /// If `strict` is `true`, an error is raised for any placeholder without a value.
+        /// Otherwise, such placeholders are left as-is.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// print("{0} and {1}".format([42], false));     // prints "42 and {1}"
+        ///
+        /// "{0} and {1}".format([42], true);             // error: no value for placeholder '1'
+        /// ```
+        #[rhai_fn(name = "format", return_raw)]
+        pub fn format_array_with_strict(
+            ctx: NativeCallContext,
+            template: &str,
+            args: Array,
+            strict: bool,
+        ) -> RhaiResultOf<ImmutableString> {
+            format_template(&ctx, template, strict, |key| {
+                key.parse::<usize>()
+                    .ok()
+                    .and_then(|index| args.get(index))
+                    .cloned()
+            })
+        }
     }
+
+    #[cfg(not(feature = "no_object"))]
+    pub mod maps {
+        use crate::{ImmutableString, Map};
+
+        /// Format the `template` string by replacing each `{key}` placeholder with the value of
+        /// that property in `named`.
+        ///
+        /// Use `{{` and `}}` for literal braces. Placeholders without a value are left as-is.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// let m = #{name: "Bob", age: 42};
+        ///
+        /// print("{name} is {age}".format(m));           // prints "Bob is 42"
+        ///
+        /// print("{name} is {height}".format(m));        // prints "Bob is {height}"
+        /// ```
+        #[rhai_fn(name = "format", return_raw)]
+        pub fn format_map(
+            ctx: NativeCallContext,
+            template: &str,
+            named: Map,
+        ) -> RhaiResultOf<ImmutableString> {
+            format_map_with_strict(ctx, template, named, false)
+        }
+        /// Format the `template` string by replacing each `{key}` placeholder with the value of
+        /// that property in `named`.
+        ///
+        /// Use `{{` and `}}` for literal braces.
+        ///
+        /// If `strict` is `true`, an error is raised for any placeholder without a value.
+        /// Otherwise, such placeholders are left as-is.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// let m = #{name: "Bob"};
+        ///
+        /// print("{name} is {age}".format(m, false));    // prints "Bob is {age}"
+        ///
+        /// "{name} is {age}".format(m, true);            // error: no value for placeholder 'age'
+        /// ```
+        #[rhai_fn(name = "format", return_raw)]
+        pub fn format_map_with_strict(
+            ctx: NativeCallContext,
+            template: &str,
+            named: Map,
+            strict: bool,
+        ) -> RhaiResultOf<ImmutableString> {
+            format_template(&ctx, template, strict, |key| named.get(key).cloned())
+        }
+        /// Format the `template` string by replacing each `{index}` placeholder with the element
+        /// at that index in `args` and each `{key}` placeholder with the value of that property
+        /// in `named`. An empty placeholder `{}` takes the next element of `args` in turn.
+        ///
+        /// Use `{{` and `}}` for literal braces. Placeholders without a value are left as-is.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// print("{0} is {name}".format([42], #{name: "answer"}));   // prints "42 is answer"
+        /// ```
+        #[cfg(not(feature = "no_index"))]
+        #[rhai_fn(name = "format", return_raw)]
+        pub fn format_array_map(
+            ctx: NativeCallContext,
+            template: &str,
+            args: crate::Array,
+            named: Map,
+        ) -> RhaiResultOf<ImmutableString> {
+            format_array_map_with_strict(ctx, template, args, named, false)
+        }
+        /// Format the `template` string by replacing each `{index}` placeholder with the element
+        /// at that index in `args` and each `{key}` placeholder with the value of that property
+        /// in `named`. An empty placeholder `{}` takes the next element of `args` in turn.
+        ///
+        /// Use `{{` and `}}` for literal braces.
+        ///
+        /// If `strict` is `true`, an error is raised for any placeholder without a value.
+        /// Otherwise, such placeholders are left as-is.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// "{0} is {name}".format([], #{name: "answer"}, true);  // error: no value for placeholder '0'
+        /// ```
+        #[cfg(not(feature = "no_index"))]
+        #[rhai_fn(name = "format", return_raw)]
+        pub fn format_array_map_with_strict(
+            ctx: NativeCallContext,
+            template: &str,
+            args: crate::Array,
+            named: Map,
+            strict: bool,
+        ) -> RhaiResultOf<ImmutableString> {
+            format_template(&ctx, template, strict, |key| match key.parse::<usize>() {
+                Ok(index) => args.get(index).cloned(),
+                Err(..) => named.get(key).cloned(),
+            })
+        }
+    }
+}
+
+/// Find the byte offset of the first occurrence of a sub-string in a string.
+///
+/// Uses SIMD-accelerated search under the `memchr` feature.
+#[inline(always)]
+fn find_str(string: &str, find_string: &str) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    return memchr::memmem::find(string.as_bytes(), find_string.as_bytes());
+    #[cfg(not(feature = "memchr"))]
+    return string.find(find_string);
+}
+
+/// Find the byte offset of the last occurrence of a sub-string in a string.
+///
+/// Uses SIMD-accelerated search under the `memchr` feature.
+#[inline(always)]
+fn rfind_str(string: &str, find_string: &str) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    return memchr::memmem::rfind(string.as_bytes(), find_string.as_bytes());
+    #[cfg(not(feature = "memchr"))]
+    return string.rfind(find_string);
+}
+
+/// Split a string into segments based on a delimiter string.
+///
+/// Uses SIMD-accelerated search under the `memchr` feature.
+#[cfg(not(feature = "no_index"))]
+fn split_str(string: &str, delimiter: &str) -> crate::Array {
+    #[cfg(feature = "memchr")]
+    if !delimiter.is_empty() {
+        let mut segments = crate::Array::new();
+        let mut start = 0;
+
+        for index in memchr::memmem::find_iter(string.as_bytes(), delimiter.as_bytes()) {
+            segments.push(string[start..index].into());
+            start = index + delimiter.len();
+        }
+        segments.push(string[start..].into());
+
+        return segments;
+    }
+
+    string.split(delimiter).map(Into::into).collect()
+}
+
+/// Pad a string at the start or the end to at least `width` characters by repeating `padding`.
+fn pad_with(
+    _ctx: &NativeCallContext,
+    string: &mut crate::ImmutableString,
+    width: INT,
+    padding: &str,
+    at_start: bool,
+) -> RhaiResultOf<()> {
+    if width < 0 {
+        return Err(ERR::ErrorRuntime(
+            format!("Invalid width for padding: {width}").into(),
+            Position::NONE,
+        )
+        .into());
+    }
+    let width = width.min(MAX_USIZE_INT) as usize;
+
+    // Check if string will be over max size limit
+    #[cfg(not(feature = "unchecked"))]
+    if _ctx.engine().max_string_size() > 0 && width > _ctx.engine().max_string_size() {
+        return Err(ERR::ErrorDataTooLarge("Length of string".to_string(), Position::NONE).into());
+    }
+
+    let str_len = string.chars().count();
+
+    if padding.is_empty() || width <= str_len {
+        return Ok(());
+    }
+
+    let fill = padding.chars().cycle().take(width - str_len);
+
+    if at_start {
+        *string = fill
+            .chain(string.chars())
+            .collect::<crate::SmartString>()
+            .into();
+    } else {
+        string.make_mut().extend(fill);
+    }
+
+    #[cfg(not(feature = "unchecked"))]
+    if _ctx.engine().max_string_size() > 0 && string.len() > _ctx.engine().max_string_size() {
+        return Err(ERR::ErrorDataTooLarge("Length of string".to_string(), Position::NONE).into());
+    }
+
+    Ok(())
+}
+
+/// Repeat a non-empty string `count` (> 0) times.
+fn repeat_str(
+    _ctx: &NativeCallContext,
+    string: &str,
+    count: INT,
+) -> RhaiResultOf<crate::ImmutableString> {
+    let count = count.min(MAX_USIZE_INT) as usize;
+
+    // Check if the string would be too large to allocate, or over max size limit
+    let too_large = string.len().checked_mul(count).map_or(true, |len| {
+        #[cfg(not(feature = "unchecked"))]
+        if _ctx.engine().max_string_size() > 0 && len > _ctx.engine().max_string_size() {
+            return true;
+        }
+        len > isize::MAX as usize
+    });
+
+    if too_large {
+        return Err(ERR::ErrorDataTooLarge("Length of string".to_string(), Position::NONE).into());
+    }
+
+    Ok(string.repeat(count).into())
+}
+
+/// Replace a string with a trimmed slice of itself, avoiding an allocation if nothing is trimmed.
+fn trim_with(string: &mut crate::ImmutableString, trim: impl Fn(&str) -> &str) {
+    if let Some(s) = string.get_mut() {
+        let trimmed = trim(s);
+
+        if trimmed != s {
+            *s = trimmed.into();
+        }
+    } else {
+        let trimmed = trim(string);
+
+        if trimmed != string {
+            *string = trimmed.into();
+        }
+    }
+}
+
+/// Substitute the `{...}` placeholders in a format template with values returned by `lookup`.
+///
+/// An empty placeholder `{}` is replaced by the next positional index in turn.
+/// `{{` and `}}` are escapes for literal braces.
+#[cfg(any(not(feature = "no_index"), not(feature = "no_object")))]
+fn format_template(
+    ctx: &NativeCallContext,
+    template: &str,
+    strict: bool,
+    lookup: impl Fn(&str) -> Option<Dynamic>,
+) -> RhaiResultOf<crate::ImmutableString> {
+    let mut result = String::with_capacity(template.len());
+    let mut chars = template.chars();
+    let mut next_index = 0_usize;
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' | '}' if chars.as_str().starts_with(ch) => {
+                chars.next();
+                result.push(ch);
+            }
+            '{' => {
+                let rest = chars.as_str();
+                let end = rest.find('}').ok_or_else(|| {
+                    ERR::ErrorRuntime("Unclosed '{' in format template".into(), Position::NONE)
+                })?;
+                let placeholder = &rest[..end];
+                chars = rest[end + 1..].chars();
+
+                let key = if placeholder.is_empty() {
+                    next_index += 1;
+                    (next_index - 1).to_string()
+                } else {
+                    placeholder.trim().to_string()
+                };
+
+                match lookup(&key) {
+                    Some(mut value) => {
+                        result.push_str(&print_with_func(FUNC_TO_STRING, ctx, &mut value));
+                    }
+                    None if strict => {
+                        return Err(ERR::ErrorRuntime(
+                            format!("No value for placeholder '{key}' in format template").into(),
+                            Position::NONE,
+                        )
+                        .into())
+                    }
+                    None => {
+                        result.push('{');
+                        result.push_str(placeholder);
+                        result.push('}');
+                    }
+                }
+            }
+            '}' => {
+                return Err(ERR::ErrorRuntime(
+                    "Unmatched '}' in format template".into(),
+                    Position::NONE,
+                )
+                .into())
+            }
+            _ => result.push(ch),
+        }
+    }
+
+    Ok(result.into())
 }
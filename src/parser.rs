@@ -1,11 +1,11 @@
 //! Main module defining the lexer and parser.
 
 use crate::api::events::VarDefInfo;
-use crate::api::options::LangOptions;
+use crate::api::options::{ExpressionOptions, LangOptions};
 use crate::ast::{
     ASTFlags, BinaryExpr, CaseBlocksList, ConditionalExpr, Expr, FnCallExpr, FnCallHashes, Ident,
-    OpAssignment, RangeCase, ScriptFnDef, Stmt, StmtBlock, StmtBlockContainer,
-    SwitchCasesCollection, TryCatchBlock,
+    MatchArm, MatchPattern, OpAssignment, RangeCase, ScriptFnDef, Stmt, StmtBlock,
+    StmtBlockContainer, SwitchCasesCollection, TryCatchBlock,
 };
 use crate::engine::{Precedence, KEYWORD_THIS, OP_CONTAINS};
 use crate::eval::GlobalRuntimeState;
@@ -256,6 +256,13 @@ impl<'e> ParseState<'e> {
         self.interned_strings.get(text)
     }
 
+    /// Take the strings interned so far, leaving an empty [`StringsInterner`] in place.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn take_interned_strings(&mut self) -> StringsInterner<'e> {
+        std::mem::take(&mut self.interned_strings)
+    }
+
     /// Get an interned property getter, creating one if it is not yet interned.
     #[cfg(not(feature = "no_object"))]
     #[inline(always)]
@@ -301,8 +308,15 @@ struct ParseSettings {
     in_closure: bool,
     /// Is the construct being parsed located inside a breakable loop?
     is_breakable: bool,
+    /// Is the construct being parsed located inside a `loop`, where `break` can carry a value?
+    is_break_with_value: bool,
     /// Allow statements in blocks?
     allow_statements: bool,
+    /// Allow a closure block argument (i.e. `{ |...| ... }`) following a function call?
+    ///
+    /// Not allowed at the top level of an expression followed by a statement block (e.g. the guard
+    /// of an `if` statement), where it would be ambiguous.
+    allow_block_arg: bool,
     /// Language options in effect (overrides Engine options).
     options: LangOptions,
     /// Current expression nesting level.
@@ -520,6 +534,7 @@ impl Engine {
         // ( ...
         let mut settings = settings;
         settings.pos = eat_token(input, Token::LeftParen);
+        settings.allow_block_arg = true;
 
         let expr = self.parse_expr(input, state, lib, settings.level_up())?;
 
@@ -579,9 +594,15 @@ impl Engine {
                     eat_token(input, Token::RightParen);
                 }
 
+                // id() { |...| ... }
+                #[cfg(not(feature = "no_function"))]
+                if let Some(arg) = self.parse_block_arg(input, state, lib, settings.level_up())? {
+                    args.push(arg);
+                }
+
                 #[cfg(not(feature = "no_module"))]
                 let hash = if namespace.is_empty() {
-                    calc_fn_hash(None, &id, 0)
+                    calc_fn_hash(None, &id, args.len())
                 } else {
                     let root = namespace.root();
                     let index = state.find_module(root);
@@ -597,6 +618,7 @@ impl Engine {
                         && !is_global
                         && !state.global_imports.iter().any(|m| m.as_str() == root)
                         && !self.global_sub_modules.contains_key(root)
+                        && !self.lazy_modules.contains_key(root)
                     {
                         return Err(
                             PERR::ModuleUndefined(root.to_string()).into_err(namespace.position())
@@ -605,10 +627,10 @@ impl Engine {
 
                     namespace.set_index(index);
 
-                    crate::calc_fn_hash(namespace.iter().map(Ident::as_str), &id, 0)
+                    crate::calc_fn_hash(namespace.iter().map(Ident::as_str), &id, args.len())
                 };
                 #[cfg(feature = "no_module")]
-                let hash = calc_fn_hash(None, &id, 0);
+                let hash = calc_fn_hash(None, &id, args.len());
 
                 let hashes = if is_valid_function_name(&id) {
                     hash.into()
@@ -640,7 +662,13 @@ impl Engine {
             match input.peek().expect(NEVER_ENDS) {
                 // id(...args, ) - handle trailing comma
                 (Token::RightParen, ..) => (),
-                _ => args.push(self.parse_expr(input, state, lib, settings)?),
+                _ => {
+                    let arg_settings = ParseSettings {
+                        allow_block_arg: true,
+                        ..settings
+                    };
+                    args.push(self.parse_expr(input, state, lib, arg_settings)?);
+                }
             }
 
             match input.peek().expect(NEVER_ENDS) {
@@ -648,6 +676,12 @@ impl Engine {
                 (Token::RightParen, ..) => {
                     eat_token(input, Token::RightParen);
 
+                    // id(...args) { |...| ... }
+                    #[cfg(not(feature = "no_function"))]
+                    if let Some(arg) = self.parse_block_arg(input, state, lib, settings)? {
+                        args.push(arg);
+                    }
+
                     #[cfg(not(feature = "no_module"))]
                     let hash = if namespace.is_empty() {
                         calc_fn_hash(None, &id, args.len())
@@ -666,6 +700,7 @@ impl Engine {
                             && !is_global
                             && !state.global_imports.iter().any(|m| m.as_str() == root)
                             && !self.global_sub_modules.contains_key(root)
+                            && !self.lazy_modules.contains_key(root)
                         {
                             return Err(PERR::ModuleUndefined(root.to_string())
                                 .into_err(namespace.position()));
@@ -742,7 +777,11 @@ impl Engine {
 
         let mut settings = settings;
 
-        let idx_expr = self.parse_expr(input, state, lib, settings.level_up())?;
+        let idx_settings = ParseSettings {
+            allow_block_arg: true,
+            ..settings.level_up()
+        };
+        let idx_expr = self.parse_expr(input, state, lib, idx_settings)?;
 
         // Check types of indexing that cannot be overridden
         // - arrays, maps, strings, bit-fields
@@ -906,6 +945,7 @@ impl Engine {
         // [ ...
         let mut settings = settings;
         settings.pos = eat_token(input, Token::LeftBracket);
+        settings.allow_block_arg = true;
 
         let mut array = StaticVec::new_const();
 
@@ -982,6 +1022,7 @@ impl Engine {
         // #{ ...
         let mut settings = settings;
         settings.pos = eat_token(input, Token::MapStart);
+        settings.allow_block_arg = true;
 
         let mut map = StaticVec::<(Ident, Expr)>::new();
         let mut template = BTreeMap::<Identifier, crate::Dynamic>::new();
@@ -1103,7 +1144,11 @@ impl Engine {
         let mut settings = settings;
         settings.pos = eat_token(input, Token::Switch);
 
-        let item = self.parse_expr(input, state, lib, settings.level_up())?;
+        let item_settings = ParseSettings {
+            allow_block_arg: false,
+            ..settings.level_up()
+        };
+        let item = self.parse_expr(input, state, lib, item_settings)?;
 
         match input.next().expect(NEVER_ENDS) {
             (Token::LeftBrace, ..) => (),
@@ -1298,6 +1343,10 @@ impl Engine {
             }
         }
 
+        if def_case.is_none() && settings.options.contains(LangOptions::STRICT_SWITCH) {
+            return Err(PERR::MissingSwitchDefaultCase.into_err(settings.pos));
+        }
+
         let cases = SwitchCasesCollection {
             expressions,
             cases,
@@ -1308,6 +1357,451 @@ impl Engine {
         Ok(Stmt::Switch((item, cases).into(), settings.pos))
     }
 
+    /// Parse a match expression.
+    fn parse_match(
+        &self,
+        input: &mut TokenStream,
+        state: &mut ParseState,
+        lib: &mut FnLib,
+        settings: ParseSettings,
+    ) -> ParseResult<Stmt> {
+        #[cfg(not(feature = "unchecked"))]
+        settings.ensure_level_within_max_limit(state.max_expr_depth)?;
+
+        // match ...
+        let mut settings = settings;
+        settings.pos = eat_token(input, Token::Match);
+
+        let item_settings = ParseSettings {
+            allow_block_arg: false,
+            ..settings.level_up()
+        };
+        let item = self.parse_expr(input, state, lib, item_settings)?;
+
+        match input.next().expect(NEVER_ENDS) {
+            (Token::LeftBrace, ..) => (),
+            (Token::LexError(err), pos) => return Err(err.into_err(pos)),
+            (.., pos) => {
+                return Err(PERR::MissingToken(
+                    Token::LeftBrace.into(),
+                    "to start a match block".into(),
+                )
+                .into_err(pos))
+            }
+        }
+
+        let mut arms = StaticVec::<MatchArm>::new();
+
+        loop {
+            const MISSING_RBRACE: &str = "to end this match block";
+
+            match input.peek().expect(NEVER_ENDS) {
+                (Token::RightBrace, ..) => {
+                    eat_token(input, Token::RightBrace);
+                    break;
+                }
+                (Token::EOF, pos) => {
+                    return Err(
+                        PERR::MissingToken(Token::RightBrace.into(), MISSING_RBRACE.into())
+                            .into_err(*pos),
+                    )
+                }
+                _ => (),
+            }
+
+            let pattern = self.parse_match_pattern(input, state, lib, settings.level_up())?;
+
+            // Bring all variables bound by the pattern into scope
+            let prev_stack_len = state.stack.len();
+            let mut bindings = StaticVec::<&Ident>::new();
+            let mut duplicated = None;
+
+            pattern.for_each_binding(&mut |name| {
+                if duplicated.is_none() && bindings.iter().any(|n| n.name == name.name) {
+                    duplicated = Some(name);
+                }
+                bindings.push(name);
+            });
+
+            if let Some(Ident { name, pos }) = duplicated {
+                return Err(PERR::DuplicatedVariable(name.to_string()).into_err(*pos));
+            }
+            for Ident { name, .. } in bindings {
+                state.stack.push(name.clone(), ());
+            }
+
+            let condition = if match_token(input, Token::If).0 {
+                ensure_not_statement_expr(input, "a boolean")?;
+                let guard = self
+                    .parse_expr(input, state, lib, settings.level_up())?
+                    .ensure_bool_expr()?;
+                ensure_not_assignment(input)?;
+                guard
+            } else {
+                Expr::BoolConstant(true, Position::NONE)
+            };
+
+            match input.next().expect(NEVER_ENDS) {
+                (Token::DoubleArrow, ..) => (),
+                (Token::LexError(err), pos) => return Err(err.into_err(pos)),
+                (.., pos) => {
+                    return Err(PERR::MissingToken(
+                        Token::DoubleArrow.into(),
+                        "in this match arm".to_string(),
+                    )
+                    .into_err(pos))
+                }
+            };
+
+            let (action_expr, need_comma) = if settings.allow_statements {
+                let stmt = self.parse_stmt(input, state, lib, settings.level_up())?;
+                let need_comma = !stmt.is_self_terminated();
+
+                let stmt_block: StmtBlock = stmt.into();
+                (Expr::Stmt(stmt_block.into()), need_comma)
+            } else {
+                (
+                    self.parse_expr(input, state, lib, settings.level_up())?,
+                    true,
+                )
+            };
+
+            state.stack.rewind(prev_stack_len);
+
+            arms.push(MatchArm {
+                pattern,
+                body: (condition, action_expr).into(),
+            });
+
+            match input.peek().expect(NEVER_ENDS) {
+                (Token::Comma, ..) => {
+                    eat_token(input, Token::Comma);
+                }
+                (Token::RightBrace, ..) => (),
+                (Token::EOF, pos) => {
+                    return Err(
+                        PERR::MissingToken(Token::RightBrace.into(), MISSING_RBRACE.into())
+                            .into_err(*pos),
+                    )
+                }
+                (Token::LexError(err), pos) => return Err(err.clone().into_err(*pos)),
+                (.., pos) if need_comma => {
+                    return Err(PERR::MissingToken(
+                        Token::Comma.into(),
+                        "to separate the arms in this match block".into(),
+                    )
+                    .into_err(*pos))
+                }
+                _ => (),
+            }
+        }
+
+        Ok(Stmt::Match((item, arms).into(), settings.pos))
+    }
+
+    /// Parse a pattern in an arm of a match expression.
+    fn parse_match_pattern(
+        &self,
+        input: &mut TokenStream,
+        state: &mut ParseState,
+        lib: &mut FnLib,
+        settings: ParseSettings,
+    ) -> ParseResult<MatchPattern> {
+        #[cfg(not(feature = "unchecked"))]
+        settings.ensure_level_within_max_limit(state.max_expr_depth)?;
+
+        match input.peek().expect(NEVER_ENDS) {
+            // _
+            (Token::Underscore, ..) => {
+                eat_token(input, Token::Underscore);
+                Ok(MatchPattern::Wildcard)
+            }
+            // name
+            (Token::Identifier(..), ..) => {
+                let (name, pos) = parse_var_name(input)?;
+                let name = state.get_interned_string(name);
+                Ok(MatchPattern::Bind(Ident { name, pos }))
+            }
+            // [ pattern, ... ]
+            #[cfg(not(feature = "no_index"))]
+            (Token::LeftBracket, ..) => {
+                eat_token(input, Token::LeftBracket);
+
+                let mut patterns = StaticVec::new_const();
+                let mut has_rest = false;
+
+                loop {
+                    const MISSING_RBRACKET: &str = "to end this array pattern";
+
+                    match input.peek().expect(NEVER_ENDS) {
+                        (Token::RightBracket, ..) => {
+                            eat_token(input, Token::RightBracket);
+                            break;
+                        }
+                        (Token::EOF, pos) => {
+                            return Err(PERR::MissingToken(
+                                Token::RightBracket.into(),
+                                MISSING_RBRACKET.into(),
+                            )
+                            .into_err(*pos))
+                        }
+                        // .. ]
+                        (Token::ExclusiveRange, ..) => {
+                            eat_token(input, Token::ExclusiveRange);
+                            has_rest = true;
+
+                            let (has_rbracket, pos) = match_token(input, Token::RightBracket);
+                            if !has_rbracket {
+                                return Err(PERR::MissingToken(
+                                    Token::RightBracket.into(),
+                                    "after '..' in this array pattern".into(),
+                                )
+                                .into_err(pos));
+                            }
+                            break;
+                        }
+                        _ => {
+                            let pattern =
+                                self.parse_match_pattern(input, state, lib, settings.level_up())?;
+                            patterns.push(pattern);
+                        }
+                    }
+
+                    match input.next().expect(NEVER_ENDS) {
+                        (Token::Comma, ..) => (),
+                        (Token::RightBracket, ..) => break,
+                        (Token::LexError(err), pos) => return Err(err.into_err(pos)),
+                        (.., pos) => {
+                            return Err(PERR::MissingToken(
+                                Token::Comma.into(),
+                                "to separate the items in this array pattern".into(),
+                            )
+                            .into_err(pos))
+                        }
+                    }
+                }
+
+                patterns.shrink_to_fit();
+
+                Ok(MatchPattern::Array(patterns, has_rest))
+            }
+            // #{ key: pattern, ... }
+            #[cfg(not(feature = "no_object"))]
+            (Token::MapStart, ..) => {
+                eat_token(input, Token::MapStart);
+
+                let mut patterns = StaticVec::<(Ident, MatchPattern)>::new_const();
+
+                loop {
+                    const MISSING_RBRACE: &str = "to end this object map pattern";
+
+                    let (name, pos, is_string) = match input.next().expect(NEVER_ENDS) {
+                        (Token::RightBrace, ..) => break,
+                        (Token::Identifier(s), pos) => (*s, pos, false),
+                        (Token::StringConstant(s), pos) => (*s, pos, true),
+                        (Token::EOF, pos) => {
+                            return Err(PERR::MissingToken(
+                                Token::RightBrace.into(),
+                                MISSING_RBRACE.into(),
+                            )
+                            .into_err(pos))
+                        }
+                        (Token::LexError(err), pos) => return Err(err.into_err(pos)),
+                        (.., pos) => return Err(PERR::PropertyExpected.into_err(pos)),
+                    };
+
+                    if patterns.iter().any(|(k, ..)| k.name == name) {
+                        return Err(PERR::DuplicatedProperty(name.to_string()).into_err(pos));
+                    }
+
+                    let name = state.get_interned_string(name);
+                    let key = Ident { name, pos };
+
+                    let pattern = if match_token(input, Token::Colon).0 {
+                        // key: pattern
+                        self.parse_match_pattern(input, state, lib, settings.level_up())?
+                    } else if is_string {
+                        return Err(PERR::MissingToken(
+                            Token::Colon.into(),
+                            "after a quoted property name in this object map pattern".into(),
+                        )
+                        .into_err(input.peek().expect(NEVER_ENDS).1));
+                    } else {
+                        // key - shorthand for key: key
+                        MatchPattern::Bind(key.clone())
+                    };
+
+                    patterns.push((key, pattern));
+
+                    match input.next().expect(NEVER_ENDS) {
+                        (Token::Comma, ..) => (),
+                        (Token::RightBrace, ..) => break,
+                        (Token::LexError(err), pos) => return Err(err.into_err(pos)),
+                        (.., pos) => {
+                            return Err(PERR::MissingToken(
+                                Token::Comma.into(),
+                                "to separate the items in this object map pattern".into(),
+                            )
+                            .into_err(pos))
+                        }
+                    }
+                }
+
+                patterns.shrink_to_fit();
+
+                Ok(MatchPattern::Map(patterns))
+            }
+            // literal
+            _ => {
+                let expr = self.parse_expr(input, state, lib, settings.level_up())?;
+
+                let value = expr.get_literal_value().ok_or_else(|| {
+                    PERR::ExprExpected("a pattern".to_string()).into_err(expr.start_position())
+                })?;
+
+                if let Some(range) = value.read_lock::<ExclusiveRange>() {
+                    return Ok(MatchPattern::Range(range.clone().into()));
+                }
+                if let Some(range) = value.read_lock::<InclusiveRange>() {
+                    return Ok(MatchPattern::Range(range.clone().into()));
+                }
+                if !value.is_hashable() {
+                    return Err(
+                        PERR::ExprExpected("a pattern".to_string()).into_err(expr.start_position())
+                    );
+                }
+
+                Ok(MatchPattern::Literal(value))
+            }
+        }
+    }
+
+    /// Parse a closure.
+    ///
+    /// If `is_block_arg` is `true`, the closure is a block argument (i.e. `{ |...| ... }` following
+    /// a function call) whose opening `{` is already consumed.
+    #[cfg(not(feature = "no_function"))]
+    fn parse_closure(
+        &self,
+        input: &mut TokenStream,
+        state: &mut ParseState,
+        lib: &mut FnLib,
+        settings: ParseSettings,
+        is_block_arg: bool,
+    ) -> ParseResult<Expr> {
+        // Build new parse state
+        let interned_strings = std::mem::take(&mut state.interned_strings);
+
+        let mut new_state = ParseState::new(
+            self,
+            state.scope,
+            interned_strings,
+            state.tokenizer_control.clone(),
+        );
+
+        #[cfg(not(feature = "no_module"))]
+        {
+            // Do not allow storing an index to a globally-imported module
+            // just in case the function is separated from this `AST`.
+            //
+            // Keep them in `global_imports` instead so that strict variables
+            // mode will not complain.
+            new_state.global_imports.clone_from(&state.global_imports);
+            new_state
+                .global_imports
+                .extend(state.imports.iter().cloned());
+        }
+
+        #[cfg(not(feature = "unchecked"))]
+        {
+            new_state.max_expr_depth = self.max_function_expr_depth();
+        }
+
+        let mut options = self.options;
+        options.set(
+            LangOptions::STRICT_VAR,
+            if cfg!(feature = "no_closure") {
+                settings.options.contains(LangOptions::STRICT_VAR)
+            } else {
+                // A capturing closure can access variables not defined locally
+                false
+            },
+        );
+
+        let new_settings = ParseSettings {
+            at_global_level: false,
+            in_fn_scope: true,
+            #[cfg(not(feature = "no_closure"))]
+            in_closure: true,
+            is_breakable: false,
+            allow_block_arg: true,
+            level: 0,
+            options,
+            ..settings
+        };
+
+        let result = self.parse_anon_fn(input, &mut new_state, lib, new_settings, is_block_arg);
+
+        // Restore parse state
+        state.interned_strings = new_state.interned_strings;
+
+        let (expr, func) = result?;
+
+        #[cfg(not(feature = "no_closure"))]
+        new_state
+            .external_vars
+            .iter()
+            .try_for_each(|crate::ast::Ident { name, pos }| {
+                let (index, is_func) = state.access_var(name, lib, *pos);
+
+                if !is_func
+                    && index.is_none()
+                    && !settings.in_closure
+                    && settings.options.contains(LangOptions::STRICT_VAR)
+                    && !state.scope.contains(name)
+                {
+                    // If the parent scope is not inside another capturing closure
+                    // then we can conclude that the captured variable doesn't exist.
+                    // Under Strict Variables mode, this is not allowed.
+                    Err(PERR::VariableUndefined(name.to_string()).into_err(*pos))
+                } else {
+                    Ok::<_, ParseError>(())
+                }
+            })?;
+
+        let hash_script = calc_fn_hash(None, &func.name, func.params.len());
+        lib.insert(hash_script, func.into());
+
+        Ok(expr)
+    }
+
+    /// Parse a closure block argument (i.e. `{ |...| ... }`) following a function call, if any.
+    #[cfg(not(feature = "no_function"))]
+    fn parse_block_arg(
+        &self,
+        input: &mut TokenStream,
+        state: &mut ParseState,
+        lib: &mut FnLib,
+        settings: ParseSettings,
+    ) -> ParseResult<Option<Expr>> {
+        if !settings.allow_block_arg
+            || input.peek().expect(NEVER_ENDS).0 != Token::LeftBrace
+            || !state.tokenizer_control.borrow().is_closure_block
+        {
+            return Ok(None);
+        }
+
+        let mut settings = settings;
+        settings.pos = eat_token(input, Token::LeftBrace);
+
+        if !settings.options.contains(LangOptions::ANON_FN) {
+            return Err(PERR::ForbiddenAnonymousFn.into_err(settings.pos));
+        }
+
+        self.parse_closure(input, state, lib, settings, true)
+            .map(Some)
+    }
+
     /// Parse a primary expression.
     fn parse_primary(
         &self,
@@ -1365,6 +1859,14 @@ impl Engine {
                 input.next();
                 Expr::DynamicConstant(Box::new(x), settings.pos)
             }
+            #[cfg(not(feature = "only_i32"))]
+            #[cfg(not(feature = "only_i64"))]
+            #[cfg(not(target_family = "wasm"))]
+            Token::Int128Constant(x) => {
+                let x = Dynamic::from(**x);
+                input.next();
+                Expr::DynamicConstant(Box::new(x), settings.pos)
+            }
 
             // { - block statement as expression
             Token::LeftBrace if settings.options.contains(LangOptions::STMT_EXPR) => {
@@ -1389,92 +1891,27 @@ impl Engine {
                         .into(),
                 ))
             }
+            // Match statement is allowed to act as expressions
+            Token::Match if settings.options.contains(LangOptions::SWITCH_EXPR) => {
+                Expr::Stmt(Box::new(
+                    self.parse_match(input, state, lib, settings.level_up())?
+                        .into(),
+                ))
+            }
+            // Loop is allowed to act as expressions when looping is allowed
+            Token::Loop if settings.options.contains(LangOptions::LOOPING) => Expr::Stmt(Box::new(
+                self.parse_while_loop(input, state, lib, settings.level_up())?
+                    .into(),
+            )),
 
             // | ...
             #[cfg(not(feature = "no_function"))]
             Token::Pipe | Token::Or if settings.options.contains(LangOptions::ANON_FN) => {
-                // Build new parse state
-                let interned_strings = std::mem::take(&mut state.interned_strings);
-
-                let mut new_state = ParseState::new(
-                    self,
-                    state.scope,
-                    interned_strings,
-                    state.tokenizer_control.clone(),
-                );
-
-                #[cfg(not(feature = "no_module"))]
-                {
-                    // Do not allow storing an index to a globally-imported module
-                    // just in case the function is separated from this `AST`.
-                    //
-                    // Keep them in `global_imports` instead so that strict variables
-                    // mode will not complain.
-                    new_state.global_imports.clone_from(&state.global_imports);
-                    new_state
-                        .global_imports
-                        .extend(state.imports.iter().cloned());
-                }
-
-                #[cfg(not(feature = "unchecked"))]
-                {
-                    new_state.max_expr_depth = self.max_function_expr_depth();
-                }
-
-                let mut options = self.options;
-                options.set(
-                    LangOptions::STRICT_VAR,
-                    if cfg!(feature = "no_closure") {
-                        settings.options.contains(LangOptions::STRICT_VAR)
-                    } else {
-                        // A capturing closure can access variables not defined locally
-                        false
-                    },
-                );
-
-                let new_settings = ParseSettings {
-                    at_global_level: false,
-                    in_fn_scope: true,
-                    #[cfg(not(feature = "no_closure"))]
-                    in_closure: true,
-                    is_breakable: false,
-                    level: 0,
-                    options,
-                    ..settings
-                };
-
-                let result = self.parse_anon_fn(input, &mut new_state, lib, new_settings);
-
-                // Restore parse state
-                state.interned_strings = new_state.interned_strings;
-
-                let (expr, func) = result?;
-
-                #[cfg(not(feature = "no_closure"))]
-                new_state.external_vars.iter().try_for_each(
-                    |crate::ast::Ident { name, pos }| {
-                        let (index, is_func) = state.access_var(name, lib, *pos);
-
-                        if !is_func
-                            && index.is_none()
-                            && !settings.in_closure
-                            && settings.options.contains(LangOptions::STRICT_VAR)
-                            && !state.scope.contains(name)
-                        {
-                            // If the parent scope is not inside another capturing closure
-                            // then we can conclude that the captured variable doesn't exist.
-                            // Under Strict Variables mode, this is not allowed.
-                            Err(PERR::VariableUndefined(name.to_string()).into_err(*pos))
-                        } else {
-                            Ok::<_, ParseError>(())
-                        }
-                    },
-                )?;
-
-                let hash_script = calc_fn_hash(None, &func.name, func.params.len());
-                lib.insert(hash_script, func.into());
-
-                expr
+                self.parse_closure(input, state, lib, settings, false)?
+            }
+            #[cfg(not(feature = "no_function"))]
+            Token::Pipe | Token::Or => {
+                return Err(PERR::ForbiddenAnonymousFn.into_err(settings.pos))
             }
 
             // Interpolated string
@@ -1586,6 +2023,30 @@ impl Engine {
                             settings.pos,
                         )
                     }
+                    // Function call with only a closure block argument: id { |...| ... }
+                    #[cfg(not(feature = "no_function"))]
+                    Token::LeftBrace
+                        if settings.allow_block_arg
+                            && state.tokenizer_control.borrow().is_closure_block =>
+                    {
+                        #[cfg(not(feature = "no_closure"))]
+                        {
+                            // Once the identifier consumed we must enable next variables capturing
+                            state.allow_capture = true;
+                        }
+                        let name = state.get_interned_string(*s);
+                        self.parse_fn_call(
+                            input,
+                            state,
+                            lib,
+                            name,
+                            true,
+                            false,
+                            #[cfg(not(feature = "no_module"))]
+                            ns,
+                            settings.level_up(),
+                        )?
+                    }
                     // Namespace qualification
                     #[cfg(not(feature = "no_module"))]
                     Token::DoubleColon => {
@@ -1856,6 +2317,7 @@ impl Engine {
                         && !is_global
                         && !state.global_imports.iter().any(|m| m.as_str() == root)
                         && !self.global_sub_modules.contains_key(root)
+                        && !self.lazy_modules.contains_key(root)
                     {
                         return Err(
                             PERR::ModuleUndefined(root.to_string()).into_err(namespace.position())
@@ -1986,6 +2448,68 @@ impl Engine {
         }
     }
 
+    /// Parse a unary expression followed by any number of `as` type casts.
+    ///
+    /// A cast binds tighter than any binary operator, so `x as float + 1` is `(x as float) + 1`.
+    fn parse_cast(
+        &self,
+        input: &mut TokenStream,
+        state: &mut ParseState,
+        lib: &mut FnLib,
+        settings: ParseSettings,
+    ) -> ParseResult<Expr> {
+        let mut expr = self.parse_unary(input, state, lib, settings)?;
+
+        loop {
+            let (token, ..) = input.peek().expect(NEVER_ENDS);
+
+            if *token != Token::As || !(state.expr_filter)(token) {
+                return Ok(expr);
+            }
+
+            let pos = eat_token(input, Token::As);
+
+            let fn_name = match input.next().expect(NEVER_ENDS) {
+                (Token::Identifier(s), type_pos) => match s.as_str() {
+                    "int" => "to_int",
+                    #[cfg(not(feature = "no_float"))]
+                    "float" => "to_float",
+                    #[cfg(feature = "decimal")]
+                    "decimal" => "to_decimal",
+                    "string" => crate::packages::string_basic::FUNC_TO_STRING,
+                    _ => {
+                        return Err(LexError::ImproperSymbol(
+                            s.to_string(),
+                            format!("Cannot cast to unknown type '{s}'"),
+                        )
+                        .into_err(type_pos))
+                    }
+                },
+                (Token::LexError(err), type_pos) => return Err(err.into_err(type_pos)),
+                (.., type_pos) => {
+                    return Err(PERR::MissingToken(
+                        "type".into(),
+                        "after 'as' in a cast expression".into(),
+                    )
+                    .into_err(type_pos))
+                }
+            };
+
+            let mut args = StaticVec::new_const();
+            args.push(expr);
+            args.shrink_to_fit();
+
+            expr = FnCallExpr {
+                name: state.get_interned_string(fn_name),
+                hashes: FnCallHashes::from_native(calc_fn_hash(None, fn_name, 1)),
+                args,
+                pos,
+                ..Default::default()
+            }
+            .into_fn_call_expr(pos);
+        }
+    }
+
     /// Make an assignment statement.
     fn make_assignment_stmt(
         op: Option<Token>,
@@ -2021,10 +2545,14 @@ impl Engine {
             }
         }
 
-        let op_info = if let Some(ref op) = op {
-            OpAssignment::new_op_assignment_from_token(op, op_pos)
-        } else {
-            OpAssignment::new_assignment(op_pos)
+        let op_info = match op {
+            // var custom_op= ...
+            #[cfg(not(feature = "no_custom_syntax"))]
+            Some(Token::Custom(ref s)) => {
+                OpAssignment::new_custom_op_assignment(&s[..s.len() - 1], op_pos)
+            }
+            Some(ref op) => OpAssignment::new_op_assignment_from_token(op, op_pos),
+            None => OpAssignment::new_assignment(op_pos),
         };
 
         match lhs {
@@ -2113,6 +2641,12 @@ impl Engine {
                 .next()
                 .map(|(op, pos)| (Some(op), pos))
                 .expect(NEVER_ENDS),
+            // var custom_op= ...
+            #[cfg(not(feature = "no_custom_syntax"))]
+            (Token::Custom(s), ..) if self.is_custom_op_assignment(s) => input
+                .next()
+                .map(|(op, pos)| (Some(op), pos))
+                .expect(NEVER_ENDS),
             // Not op-assignment
             _ => return Ok(Stmt::Expr(lhs.into())),
         };
@@ -2289,6 +2823,9 @@ impl Engine {
             }
 
             let precedence = match current_op {
+                // Custom op-assignment operators end the expression
+                #[cfg(not(feature = "no_custom_syntax"))]
+                Token::Custom(c) if self.is_custom_op_assignment(c) => None,
                 #[cfg(not(feature = "no_custom_syntax"))]
                 Token::Custom(c) => self
                     .custom_keywords
@@ -2310,10 +2847,12 @@ impl Engine {
 
             let (op_token, pos) = input.next().expect(NEVER_ENDS);
 
-            let rhs = self.parse_unary(input, state, lib, settings)?;
+            let rhs = self.parse_cast(input, state, lib, settings)?;
 
             let (next_op, next_pos) = input.peek().expect(NEVER_ENDS);
             let next_precedence = match next_op {
+                #[cfg(not(feature = "no_custom_syntax"))]
+                Token::Custom(c) if self.is_custom_op_assignment(c) => None,
                 #[cfg(not(feature = "no_custom_syntax"))]
                 Token::Custom(c) => self
                     .custom_keywords
@@ -2660,7 +3199,7 @@ impl Engine {
 
         // Parse expression normally.
         let precedence = Precedence::new(1);
-        let lhs = self.parse_unary(input, state, lib, settings.level_up())?;
+        let lhs = self.parse_cast(input, state, lib, settings.level_up())?;
         self.parse_binary_op(input, state, lib, precedence, lhs, settings.level_up())
     }
 
@@ -2681,8 +3220,12 @@ impl Engine {
 
         // if guard { if_body }
         ensure_not_statement_expr(input, "a boolean")?;
+        let guard_settings = ParseSettings {
+            allow_block_arg: false,
+            ..settings.level_up()
+        };
         let guard = self
-            .parse_expr(input, state, lib, settings.level_up())?
+            .parse_expr(input, state, lib, guard_settings)?
             .ensure_bool_expr()?;
         ensure_not_assignment(input)?;
         let if_body = self.parse_block(input, state, lib, settings.level_up())?;
@@ -2720,20 +3263,25 @@ impl Engine {
         let mut settings = settings;
 
         // while|loops ...
-        let (guard, token_pos) = match input.next().expect(NEVER_ENDS) {
+        let (guard, token_pos, is_loop) = match input.next().expect(NEVER_ENDS) {
             (Token::While, pos) => {
                 ensure_not_statement_expr(input, "a boolean")?;
+                let guard_settings = ParseSettings {
+                    allow_block_arg: false,
+                    ..settings.level_up()
+                };
                 let expr = self
-                    .parse_expr(input, state, lib, settings.level_up())?
+                    .parse_expr(input, state, lib, guard_settings)?
                     .ensure_bool_expr()?;
                 ensure_not_assignment(input)?;
-                (expr, pos)
+                (expr, pos, false)
             }
-            (Token::Loop, pos) => (Expr::Unit(Position::NONE), pos),
+            (Token::Loop, pos) => (Expr::Unit(Position::NONE), pos, true),
             token => unreachable!("Token::While or Token::Loop expected but gets {:?}", token),
         };
         settings.pos = token_pos;
         settings.is_breakable = true;
+        settings.is_break_with_value = is_loop;
 
         let body = self.parse_block(input, state, lib, settings.level_up())?;
 
@@ -2757,6 +3305,7 @@ impl Engine {
 
         // do { body } [while|until] guard
         settings.is_breakable = true;
+        settings.is_break_with_value = false;
         let body = self.parse_block(input, state, lib, settings.level_up())?;
 
         let negated = match input.next().expect(NEVER_ENDS) {
@@ -2847,8 +3396,12 @@ impl Engine {
 
         // for name in expr { body }
         ensure_not_statement_expr(input, "a boolean")?;
+        let expr_settings = ParseSettings {
+            allow_block_arg: false,
+            ..settings.level_up()
+        };
         let expr = self
-            .parse_expr(input, state, lib, settings.level_up())?
+            .parse_expr(input, state, lib, expr_settings)?
             .ensure_iterable()?;
 
         let prev_stack_len = state.stack.len();
@@ -2869,6 +3422,7 @@ impl Engine {
         };
 
         settings.is_breakable = true;
+        settings.is_break_with_value = false;
         let body = self.parse_block(input, state, lib, settings.level_up())?;
 
         state.stack.rewind(prev_stack_len);
@@ -2922,7 +3476,8 @@ impl Engine {
                 &[],
                 &mut this_ptr,
                 level,
-            );
+            )
+            .with_position(pos);
 
             match filter(false, info, context) {
                 Ok(true) => (),
@@ -2998,7 +3553,11 @@ impl Engine {
         settings.pos = eat_token(input, Token::Import);
 
         // import expr ...
-        let expr = self.parse_expr(input, state, lib, settings.level_up())?;
+        let filter = state.expr_filter;
+        state.expr_filter = |t| t != &Token::As;
+        let expr = self.parse_expr(input, state, lib, settings.level_up());
+        state.expr_filter = filter;
+        let expr = expr?;
 
         // import expr;
         if !match_token(input, Token::As).0 {
@@ -3101,6 +3660,20 @@ impl Engine {
             }
         };
 
+        self.parse_block_rest(input, state, lib, settings)
+    }
+
+    /// Parse the rest of a statement block after the opening `{`, whose position is `settings.pos`.
+    fn parse_block_rest(
+        &self,
+        input: &mut TokenStream,
+        state: &mut ParseState,
+        lib: &mut FnLib,
+        settings: ParseSettings,
+    ) -> ParseResult<Stmt> {
+        let mut settings = settings;
+        settings.allow_block_arg = true;
+
         let mut statements = StaticVec::new_const();
 
         if !settings.allow_statements {
@@ -3168,6 +3741,9 @@ impl Engine {
                 _ if !need_semicolon => (),
                 // { ... stmt <error>
                 (Token::LexError(err), err_pos) => return Err(err.clone().into_err(*err_pos)),
+                // { ... stmt <newline> ???
+                _ if settings.options.contains(LangOptions::NEWLINE_STMT_END)
+                    && state.tokenizer_control.borrow().is_newline_before => {}
                 // { ... stmt ???
                 (.., pos) => {
                     // Semicolons are not optional between statements
@@ -3333,7 +3909,9 @@ impl Engine {
                             #[cfg(not(feature = "no_closure"))]
                             in_closure: false,
                             is_breakable: false,
+                            is_break_with_value: false,
                             allow_statements: true,
+                            allow_block_arg: true,
                             level: 0,
                             options,
                             pos,
@@ -3380,6 +3958,7 @@ impl Engine {
 
             Token::If => self.parse_if(input, state, lib, settings.level_up()),
             Token::Switch => self.parse_switch(input, state, lib, settings.level_up()),
+            Token::Match => self.parse_match(input, state, lib, settings.level_up()),
             Token::While | Token::Loop if self.allow_looping() => {
                 self.parse_while_loop(input, state, lib, settings.level_up())
             }
@@ -3392,11 +3971,29 @@ impl Engine {
 
             Token::Continue if self.allow_looping() && settings.is_breakable => {
                 let pos = eat_token(input, Token::Continue);
-                Ok(Stmt::BreakLoop(ASTFlags::NONE, pos))
+                Ok(Stmt::BreakLoop(None, ASTFlags::NONE, pos))
             }
             Token::Break if self.allow_looping() && settings.is_breakable => {
                 let pos = eat_token(input, Token::Break);
-                Ok(Stmt::BreakLoop(ASTFlags::BREAK, pos))
+
+                match input.peek().expect(NEVER_ENDS) {
+                    // `break` at <EOF> or at end of block
+                    (Token::EOF | Token::RightBrace, ..)
+                    // `break;`
+                    | (Token::SemiColon, ..)
+                    // `break` as a switch case
+                    | (Token::Comma, ..) => Ok(Stmt::BreakLoop(None, ASTFlags::BREAK, pos)),
+                    // `break` with expression inside `loop`
+                    _ if settings.is_break_with_value => {
+                        let expr = self.parse_expr(input, state, lib, settings.level_up())?;
+                        Ok(Stmt::BreakLoop(Some(expr.into()), ASTFlags::BREAK, pos))
+                    }
+                    (.., expr_pos) => Err(LexError::ImproperSymbol(
+                        Token::Break.syntax().to_string(),
+                        "'break' can only carry a value inside a 'loop'".to_string(),
+                    )
+                    .into_err(*expr_pos)),
+                }
             }
             Token::Continue | Token::Break if self.allow_looping() => {
                 Err(PERR::LoopBreak.into_err(token_pos))
@@ -3580,6 +4177,9 @@ impl Engine {
                         state.stack.push(s.clone(), ());
                         params.push((s, pos));
                     }
+                    (Token::Reserved(s), pos) if is_valid_identifier(s.chars()) => {
+                        return Err(PERR::Reserved(s.to_string()).into_err(pos))
+                    }
                     (Token::LexError(err), pos) => return Err(err.into_err(pos)),
                     (.., pos) => {
                         return Err(PERR::MissingToken(
@@ -3697,6 +4297,7 @@ impl Engine {
         state: &mut ParseState,
         lib: &mut FnLib,
         settings: ParseSettings,
+        is_block_arg: bool,
     ) -> ParseResult<(Expr, ScriptFnDef)> {
         #[cfg(not(feature = "unchecked"))]
         settings.ensure_level_within_max_limit(state.max_expr_depth)?;
@@ -3718,6 +4319,9 @@ impl Engine {
                         state.stack.push(s.clone(), ());
                         params_list.push(s);
                     }
+                    (Token::Reserved(s), pos) if is_valid_identifier(s.chars()) => {
+                        return Err(PERR::Reserved(s.to_string()).into_err(pos))
+                    }
                     (Token::LexError(err), pos) => return Err(err.into_err(pos)),
                     (.., pos) => {
                         return Err(PERR::MissingToken(
@@ -3745,7 +4349,12 @@ impl Engine {
 
         // Parse function body
         settings.is_breakable = false;
-        let body = self.parse_stmt(input, state, lib, settings.level_up())?;
+        let body = if is_block_arg {
+            // The body of a block argument is the rest of the block
+            self.parse_block_rest(input, state, lib, settings.level_up())?
+        } else {
+            self.parse_stmt(input, state, lib, settings.level_up())?
+        };
 
         // External variables may need to be processed in a consistent order,
         // so extract them into a list.
@@ -3801,6 +4410,7 @@ impl Engine {
         &self,
         input: &mut TokenStream,
         state: &mut ParseState,
+        expr_options: ExpressionOptions,
         _optimization_level: OptimizationLevel,
     ) -> ParseResult<AST> {
         let mut functions = StraightHashMap::default();
@@ -3818,12 +4428,67 @@ impl Engine {
             #[cfg(not(feature = "no_closure"))]
             in_closure: false,
             is_breakable: false,
+            is_break_with_value: false,
             allow_statements: false,
+            allow_block_arg: true,
             level: 0,
             options,
             pos: Position::NONE,
         };
-        let expr = self.parse_expr(input, state, &mut functions, settings)?;
+        let mut statements = StmtBlockContainer::new_const();
+
+        // Allowed statements before the final expression
+        loop {
+            let stmt = match input.peek().expect(NEVER_ENDS).0 {
+                Token::Let | Token::Const
+                    if expr_options.contains(ExpressionOptions::VARIABLES) =>
+                {
+                    self.parse_stmt(input, state, &mut functions, settings)?
+                }
+                Token::While | Token::Loop | Token::Do | Token::For
+                    if expr_options.contains(ExpressionOptions::LOOPS) && self.allow_looping() =>
+                {
+                    let mut settings = settings;
+                    settings.allow_statements = true;
+                    self.parse_stmt(input, state, &mut functions, settings)?
+                }
+                _ if expr_options.contains(ExpressionOptions::ASSIGNMENTS) => {
+                    match self.parse_expr_stmt(input, state, &mut functions, settings)? {
+                        stmt @ Stmt::Assignment(..) => stmt,
+                        // The final expression
+                        stmt => {
+                            statements.push(stmt);
+                            break;
+                        }
+                    }
+                }
+                // The final expression
+                _ => {
+                    let expr = self.parse_expr(input, state, &mut functions, settings)?;
+                    statements.push(Stmt::Expr(expr.into()));
+                    break;
+                }
+            };
+
+            let need_semicolon = !stmt.is_self_terminated();
+
+            statements.push(stmt);
+
+            match input.peek().expect(NEVER_ENDS) {
+                (Token::SemiColon, ..) => {
+                    eat_token(input, Token::SemiColon);
+                }
+                _ if !need_semicolon => (),
+                (Token::LexError(err), pos) => return Err(err.clone().into_err(*pos)),
+                (.., pos) => {
+                    return Err(PERR::MissingToken(
+                        Token::SemiColon.into(),
+                        "to terminate this statement".into(),
+                    )
+                    .into_err(*pos))
+                }
+            }
+        }
 
         assert!(functions.is_empty());
 
@@ -3835,9 +4500,6 @@ impl Engine {
             }
         }
 
-        let mut statements = StmtBlockContainer::new_const();
-        statements.push(Stmt::Expr(expr.into()));
-
         #[cfg(not(feature = "no_optimize"))]
         return Ok(crate::optimizer::optimize_into_ast(
             self,
@@ -3874,7 +4536,9 @@ impl Engine {
                 #[cfg(not(feature = "no_closure"))]
                 in_closure: false,
                 is_breakable: false,
+                is_break_with_value: false,
                 allow_statements: true,
+                allow_block_arg: true,
                 options: self.options,
                 level: 0,
                 pos: Position::NONE,
@@ -3903,6 +4567,9 @@ impl Engine {
                 _ if !need_semicolon => (),
                 // stmt <error>
                 (Token::LexError(err), pos) => return Err(err.clone().into_err(*pos)),
+                // stmt <newline> ???
+                _ if settings.options.contains(LangOptions::NEWLINE_STMT_END)
+                    && state.tokenizer_control.borrow().is_newline_before => {}
                 // stmt ???
                 (.., pos) => {
                     // Semicolons are not optional between statements
@@ -0,0 +1,150 @@
+//! Direct conversions between [`Dynamic`] and [`serde_json::Value`].
+
+use crate::types::dynamic::Union;
+use crate::{Dynamic, Position, RhaiResultOf, ERR};
+use serde::Deserialize;
+use serde_json::Value;
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+impl Dynamic {
+    /// _(serde)_ Convert a [`serde_json::Value`] into a [`Dynamic`] without going through a JSON
+    /// string. Exported under the `serde` feature only.
+    ///
+    /// JSON `null` becomes `()`, arrays become [`Array`][crate::Array]'s and objects become
+    /// [object maps][crate::Map].
+    ///
+    /// Numbers that fit into [`INT`][crate::INT] become integers, others become floating-point
+    /// numbers. Numbers that cannot be represented (e.g. under `no_float`) are kept as strings.
+    ///
+    /// Not available under `no_index` or `no_object`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Array, Dynamic, Map};
+    /// use serde_json::json;
+    ///
+    /// let value = Dynamic::from_json_value(&json!({ "a": 42, "b": [true, null] }));
+    ///
+    /// let map = value.cast::<Map>();
+    /// assert_eq!(map["a"].as_int().unwrap(), 42);
+    ///
+    /// let array = map["b"].clone().cast::<Array>();
+    /// assert!(array[0].as_bool().unwrap());
+    /// assert!(array[1].is::<()>());
+    /// ```
+    #[must_use]
+    pub fn from_json_value(value: &Value) -> Self {
+        match value {
+            Value::Null => Self::UNIT,
+            Value::Bool(b) => (*b).into(),
+            Value::Number(n) => Self::deserialize(n).unwrap_or_else(|_| n.to_string().into()),
+            Value::String(s) => s.as_str().into(),
+            Value::Array(a) => Self::from_array(a.iter().map(Self::from_json_value).collect()),
+            Value::Object(m) => Self::from_map(
+                m.iter()
+                    .map(|(k, v)| (k.as_str().into(), Self::from_json_value(v)))
+                    .collect(),
+            ),
+        }
+    }
+    /// _(serde)_ Convert the [`Dynamic`] into a [`serde_json::Value`] without going through a
+    /// JSON string. Exported under the `serde` feature only.
+    ///
+    /// `()` becomes JSON `null`, [`Array`][crate::Array]'s and [BLOB's][crate::Blob] become
+    /// arrays, [object maps][crate::Map] become objects and characters become strings.
+    ///
+    /// Not available under `no_index` or `no_object`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value (or any value nested inside it) cannot be represented in
+    /// JSON, such as a custom type, a function pointer, a timestamp or a floating-point number
+    /// that is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Dynamic, Map, INT};
+    /// use serde_json::json;
+    ///
+    /// let mut map = Map::new();
+    /// map.insert("a".into(), (42 as INT).into());
+    /// map.insert("b".into(), Dynamic::UNIT);
+    ///
+    /// let value = Dynamic::from_map(map).to_json_value()?;
+    ///
+    /// assert_eq!(value, json!({ "a": 42, "b": null }));
+    ///
+    /// #[derive(Clone)]
+    /// struct TestStruct;
+    ///
+    /// assert!(Dynamic::from(TestStruct).to_json_value().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_json_value(&self) -> RhaiResultOf<Value> {
+        match self.0 {
+            Union::Unit(..) => Ok(Value::Null),
+            Union::Bool(b, ..) => Ok(b.into()),
+            Union::Str(ref s, ..) => Ok(s.as_str().into()),
+            Union::Char(c, ..) => Ok(c.to_string().into()),
+            Union::Int(n, ..) => Ok(n.into()),
+
+            #[cfg(not(feature = "no_float"))]
+            Union::Float(x, ..) => {
+                #[cfg(not(feature = "f32_float"))]
+                let x = *x;
+                #[cfg(feature = "f32_float")]
+                let x = f64::from(*x);
+
+                serde_json::Number::from_f64(x)
+                    .map(Value::Number)
+                    .ok_or_else(|| self.json_conversion_error())
+            }
+
+            #[cfg(feature = "decimal")]
+            Union::Decimal(ref x, ..) => {
+                use rust_decimal::prelude::ToPrimitive;
+
+                x.to_f64()
+                    .and_then(serde_json::Number::from_f64)
+                    .map(Value::Number)
+                    .ok_or_else(|| self.json_conversion_error())
+            }
+
+            Union::Array(ref a, ..) => a
+                .iter()
+                .map(Self::to_json_value)
+                .collect::<RhaiResultOf<_>>()
+                .map(Value::Array),
+            Union::Blob(ref a, ..) => Ok(a.iter().copied().map(Value::from).collect()),
+            Union::Map(ref m, ..) => m
+                .iter()
+                .map(|(k, v)| v.to_json_value().map(|v| (k.to_string(), v)))
+                .collect::<RhaiResultOf<_>>()
+                .map(Value::Object),
+
+            #[cfg(not(feature = "no_closure"))]
+            Union::Shared(ref cell, ..) => crate::func::locked_read(cell).to_json_value(),
+
+            _ => Err(self.json_conversion_error()),
+        }
+    }
+    /// Make an error for a value that cannot be converted into JSON.
+    #[cold]
+    #[inline(never)]
+    fn json_conversion_error(&self) -> Box<ERR> {
+        ERR::ErrorRuntime(
+            format!(
+                "Value of type '{}' cannot be converted into JSON",
+                self.type_name()
+            )
+            .into(),
+            Position::NONE,
+        )
+        .into()
+    }
+}
@@ -3,6 +3,9 @@
 
 mod de;
 mod deserialize;
+#[cfg(not(feature = "no_index"))]
+#[cfg(not(feature = "no_object"))]
+mod json;
 mod metadata;
 mod ser;
 mod serialize;
@@ -5,7 +5,8 @@ use crate::engine::{
     KEYWORD_FN_PTR_CURRY, KEYWORD_IS_DEF_VAR, KEYWORD_PRINT, KEYWORD_THIS, KEYWORD_TYPE_OF,
 };
 use crate::func::native::OnParseTokenCallback;
-use crate::{Engine, Identifier, LexError, SmartString, StaticVec, INT, UNSIGNED_INT};
+use crate::types::dynamic::Union;
+use crate::{Dynamic, Engine, Identifier, LexError, SmartString, StaticVec, INT, UNSIGNED_INT};
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 use std::{
@@ -13,6 +14,7 @@ use std::{
     cell::RefCell,
     char, fmt,
     iter::{FusedIterator, Peekable},
+    mem,
     num::NonZeroUsize,
     ops::{Add, AddAssign},
     rc::Rc,
@@ -25,6 +27,12 @@ pub struct TokenizerControlBlock {
     /// Is the current tokenizer position within an interpolated text string?
     /// This flag allows switching the tokenizer back to _text_ parsing after an interpolation stream.
     pub is_within_text: bool,
+    /// Is the last `{` token immediately followed by the parameters list of a closure (`|` or `||`)?
+    /// This flag allows the parser to recognize a closure block argument after a function call.
+    pub is_closure_block: bool,
+    /// Is the last token preceded by a new line?
+    /// This flag allows the parser to end a statement at a new line.
+    pub is_newline_before: bool,
     /// Collection of global comments.
     #[cfg(feature = "metadata")]
     pub global_comments: Vec<SmartString>,
@@ -37,6 +45,8 @@ impl TokenizerControlBlock {
     pub const fn new() -> Self {
         Self {
             is_within_text: false,
+            is_closure_block: false,
+            is_newline_before: false,
             #[cfg(feature = "metadata")]
             global_comments: Vec::new(),
         }
@@ -385,6 +395,13 @@ pub enum Token {
     /// Requires the `decimal` feature.
     #[cfg(feature = "decimal")]
     DecimalConstant(Box<rust_decimal::Decimal>),
+    /// An [`i128`] constant, written with an `i128` suffix (e.g. `42i128`).
+    ///
+    /// Not available under `only_i32`, `only_i64` or on WASM targets.
+    #[cfg(not(feature = "only_i32"))]
+    #[cfg(not(feature = "only_i64"))]
+    #[cfg(not(target_family = "wasm"))]
+    Int128Constant(Box<i128>),
     /// An identifier.
     Identifier(Box<Identifier>),
     /// A character constant.
@@ -475,6 +492,8 @@ pub enum Token {
     Else,
     /// `switch`
     Switch,
+    /// `match`
+    Match,
     /// `do`
     Do,
     /// `while`
@@ -566,9 +585,6 @@ pub enum Token {
     #[cfg(not(feature = "no_module"))]
     Export,
     /// `as`
-    ///
-    /// Reserved under the `no_module` feature.
-    #[cfg(not(feature = "no_module"))]
     As,
     /// A lexer error.
     LexError(Box<LexError>),
@@ -635,6 +651,7 @@ impl Token {
             If => "if",
             Else => "else",
             Switch => "switch",
+            Match => "match",
             Do => "do",
             While => "while",
             Until => "until",
@@ -684,7 +701,6 @@ impl Token {
             Import => "import",
             #[cfg(not(feature = "no_module"))]
             Export => "export",
-            #[cfg(not(feature = "no_module"))]
             As => "as",
 
             _ => "ERROR: NOT A KEYWORD",
@@ -702,6 +718,10 @@ impl Token {
             FloatConstant(f) => f.to_string().into(),
             #[cfg(feature = "decimal")]
             DecimalConstant(d) => d.to_string().into(),
+            #[cfg(not(feature = "only_i32"))]
+            #[cfg(not(feature = "only_i64"))]
+            #[cfg(not(target_family = "wasm"))]
+            Int128Constant(i) => format!("{i}i128").into(),
             StringConstant(s) => format!("\"{s}\"").into(),
             InterpolatedString(..) => "string".into(),
             CharConstant(c) => c.to_string().into(),
@@ -836,6 +856,7 @@ impl Token {
             "if" => If,
             "else" => Else,
             "switch" => Switch,
+            "match" => Match,
             "do" => Do,
             "while" => While,
             "until" => Until,
@@ -893,11 +914,10 @@ impl Token {
             "import" => Import,
             #[cfg(not(feature = "no_module"))]
             "export" => Export,
-            #[cfg(not(feature = "no_module"))]
             "as" => As,
 
             #[cfg(feature = "no_module")]
-            "import" | "export" | "as" => Reserved(Box::new(syntax.into())),
+            "import" | "export" => Reserved(Box::new(syntax.into())),
 
             // List of reserved operators
             "===" | "!==" | "->" | "<-" | "?" | ":=" | ":;" | "~" | "!." | "::<" | "(*" | "*)"
@@ -907,9 +927,9 @@ impl Token {
 
             // List of reserved keywords
             "public" | "protected" | "super" | "new" | "use" | "module" | "package" | "var"
-            | "static" | "shared" | "with" | "is" | "goto" | "exit" | "match" | "case"
-            | "default" | "void" | "null" | "nil" | "spawn" | "thread" | "go" | "sync"
-            | "async" | "await" | "yield" => Reserved(Box::new(syntax.into())),
+            | "static" | "shared" | "with" | "is" | "goto" | "exit" | "case" | "default"
+            | "void" | "null" | "nil" | "spawn" | "thread" | "go" | "sync" | "async" | "await"
+            | "yield" => Reserved(Box::new(syntax.into())),
 
             KEYWORD_PRINT | KEYWORD_DEBUG | KEYWORD_TYPE_OF | KEYWORD_EVAL | KEYWORD_FN_PTR
             | KEYWORD_FN_PTR_CALL | KEYWORD_FN_PTR_CURRY | KEYWORD_THIS | KEYWORD_IS_DEF_VAR => {
@@ -1005,6 +1025,8 @@ impl Token {
         use Token::*;
 
         Precedence::new(match self {
+            DoubleQuestion => 20,
+
             Or | XOr | Pipe => 30,
 
             And | Ampersand => 60,
@@ -1015,8 +1037,6 @@ impl Token {
 
             LessThan | LessThanEqualsTo | GreaterThan | GreaterThanEqualsTo => 130,
 
-            DoubleQuestion => 135,
-
             ExclusiveRange | InclusiveRange => 140,
 
             Plus | Minus => 150,
@@ -1039,6 +1059,8 @@ impl Token {
         match self {
             // Exponentiation binds to the right
             PowerOf => true,
+            // Null-coalescing binds to the right, so the rest of a chain is skipped
+            DoubleQuestion => true,
 
             _ => false,
         }
@@ -1080,9 +1102,9 @@ impl Token {
             Fn | Private => true,
 
             #[cfg(not(feature = "no_module"))]
-            Import | Export | As => true,
+            Import | Export => true,
 
-            True | False | Let | Const | If | Else | Do | While | Until | Loop | For | In
+            True | False | As | Let | Const | If | Else | Do | While | Until | Loop | For | In
             | Continue | Break | Return | Throw | Try | Catch => true,
 
             _ => false,
@@ -1140,6 +1162,15 @@ pub struct TokenizeState {
     pub include_comments: bool,
     /// Is the current tokenizer position within the text stream of an interpolated string?
     pub is_within_text_terminated_by: Option<char>,
+    /// Is a custom number parser installed?
+    ///
+    /// If so, a comma between two digits is accepted as part of a number, and the text of each
+    /// number is kept in [`number_text`][TokenizeState::number_text].
+    /// A number containing commas that the custom number parser rejects is split back into
+    /// numbers separated by commas.
+    pub custom_number_parser: bool,
+    /// Text of the last number parsed, if kept.
+    pub number_text: Option<SmartString>,
 }
 
 /// _(internals)_ Trait that encapsulates a peekable character input stream.
@@ -1553,6 +1584,10 @@ fn get_next_token_inner(
                 let mut result = SmartString::new_const();
                 let mut radix_base: Option<u32> = None;
                 let mut valid: fn(char) -> bool = is_numeric_digit;
+                #[cfg(not(feature = "only_i32"))]
+                #[cfg(not(feature = "only_i64"))]
+                #[cfg(not(target_family = "wasm"))]
+                let mut is_int128 = false;
                 result.push(c);
 
                 while let Some(next_char) = stream.peek_next() {
@@ -1623,6 +1658,55 @@ fn get_next_token_inner(
                                 }
                             }
                         }
+                        // comma between digits - only with a custom number parser
+                        ',' if state.custom_number_parser && radix_base.is_none() => {
+                            stream.get_next().expect("`,`");
+
+                            if !stream.peek_next().map_or(false, is_numeric_digit) {
+                                stream.unget(next_char);
+                                break;
+                            }
+                            result.push(next_char);
+                            pos.advance();
+                        }
+                        // i128 suffix
+                        #[cfg(not(feature = "only_i32"))]
+                        #[cfg(not(feature = "only_i64"))]
+                        #[cfg(not(target_family = "wasm"))]
+                        'i' => {
+                            stream.get_next().expect("`i`");
+
+                            // Not followed by a digit - not a suffix
+                            if !stream.peek_next().map_or(false, is_numeric_digit) {
+                                stream.unget(next_char);
+                                break;
+                            }
+                            pos.advance();
+
+                            let mut suffix = SmartString::new_const();
+
+                            while let Some(ch) = stream.peek_next() {
+                                if !is_id_continue(ch) {
+                                    break;
+                                }
+                                suffix.push(ch);
+                                eat_next(stream, pos);
+                            }
+
+                            if suffix != "128" {
+                                result.push('i');
+                                result.push_str(&suffix);
+                                return Some((
+                                    Token::LexError(
+                                        LERR::MalformedNumber(result.to_string()).into(),
+                                    ),
+                                    start_pos,
+                                ));
+                            }
+
+                            is_int128 = true;
+                            break;
+                        }
                         // 0x????, 0o????, 0b???? at beginning
                         ch @ ('x' | 'o' | 'b' | 'X' | 'O' | 'B')
                             if c == '0' && result.len() <= 1 =>
@@ -1654,6 +1738,34 @@ fn get_next_token_inner(
                     negated_pos
                 });
 
+                // Parse i128 number
+                #[cfg(not(feature = "only_i32"))]
+                #[cfg(not(feature = "only_i64"))]
+                #[cfg(not(target_family = "wasm"))]
+                if is_int128 {
+                    let num = if let Some(radix) = radix_base {
+                        u128::from_str_radix(&result[2..], radix).map(|v| v as i128)
+                    } else {
+                        i128::from_str(&result)
+                    };
+
+                    return Some((
+                        num.map_or_else(
+                            |_| {
+                                Token::LexError(
+                                    LERR::MalformedNumber(format!("{result}i128")).into(),
+                                )
+                            },
+                            |v| Token::Int128Constant(v.into()),
+                        ),
+                        num_pos,
+                    ));
+                }
+
+                if state.custom_number_parser {
+                    state.number_text = Some(result.clone());
+                }
+
                 // Parse number
                 return Some((
                     if let Some(radix) = radix_base {
@@ -1670,33 +1782,7 @@ fn get_next_token_inner(
                                 Token::IntegerConstant,
                             )
                     } else {
-                        let num = INT::from_str(&result).map(Token::IntegerConstant);
-
-                        // If integer parsing is unnecessary, try float instead
-                        #[cfg(not(feature = "no_float"))]
-                        let num = num.or_else(|_| {
-                            crate::ast::FloatWrapper::from_str(&result).map(Token::FloatConstant)
-                        });
-
-                        // Then try decimal
-                        #[cfg(feature = "decimal")]
-                        let num = num.or_else(|_| {
-                            rust_decimal::Decimal::from_str(&result)
-                                .map(Box::new)
-                                .map(Token::DecimalConstant)
-                        });
-
-                        // Then try decimal in scientific notation
-                        #[cfg(feature = "decimal")]
-                        let num = num.or_else(|_| {
-                            rust_decimal::Decimal::from_scientific(&result)
-                                .map(Box::new)
-                                .map(Token::DecimalConstant)
-                        });
-
-                        num.unwrap_or_else(|_| {
-                            Token::LexError(LERR::MalformedNumber(result.to_string()).into())
-                        })
+                        parse_number_text(&result)
                     },
                     num_pos,
                 ));
@@ -2359,6 +2445,184 @@ pub struct TokenIterator<'a> {
     pub stream: MultiInputsStream<'a>,
     /// A processor function that maps a token to another.
     pub token_mapper: Option<&'a OnParseTokenCallback>,
+    /// Token read ahead while checking for a conditional compilation directive.
+    pending: Option<(Token, Position)>,
+    /// Is the last token returned by [`next_raw`][TokenIterator::next_raw] preceded by a new line?
+    newline_before: bool,
+    /// Is the token read ahead preceded by a new line?
+    pending_newline_before: bool,
+    /// Does the last token returned by [`next_raw`][TokenIterator::next_raw] start with an `=`
+    /// immediately following the previous token (i.e. without whitespace or comments in between)?
+    equals_adjacent: bool,
+    /// Does the token read ahead start with an `=` immediately following the previous token?
+    pending_equals_adjacent: bool,
+    /// Stack of currently open `#if` blocks.
+    ///
+    /// Each entry is (is the current branch active?, is the enclosing block active?, is `#else` seen?).
+    directives: StaticVec<(bool, bool, bool)>,
+    /// Tokens left over from splitting a number literal at its commas, in reverse order.
+    split_tokens: StaticVec<(Token, Position)>,
+}
+
+impl TokenIterator<'_> {
+    /// Scan the next token from the input stream, running the custom number parser, if any.
+    fn scan(&mut self) -> Option<(Token, Position)> {
+        if let Some(r) = self.split_tokens.pop() {
+            self.newline_before = false;
+            self.equals_adjacent = false;
+            return Some(r);
+        }
+
+        // Positions are not available under `no_position`, so check the next character instead
+        self.equals_adjacent = self.stream.peek_next() == Some('=');
+
+        let line = self.pos.line();
+        let (token, pos) = get_next_token(&mut self.stream, &mut self.state, &mut self.pos)?;
+        self.newline_before = pos.line() > line;
+
+        let token = match self.state.number_text.take() {
+            Some(text) => match self.parse_number(&text) {
+                Some(token) => token,
+                // Not a number - split at the commas
+                None if text.contains(',') => return self.split_number(&text, pos),
+                None => token,
+            },
+            None => token,
+        };
+
+        Some((token, pos))
+    }
+    /// Parse the text of a number literal with the custom number parser, if any.
+    fn parse_number(&self, text: &str) -> Option<Token> {
+        self.engine
+            .number_parser
+            .as_ref()
+            .and_then(|f| f(text))
+            .map(|value| number_to_token(value, text))
+    }
+    /// Split the text of a number literal rejected by the custom number parser into numbers
+    /// separated by commas, returning the first token.
+    fn split_number(&mut self, text: &str, pos: Position) -> Option<(Token, Position)> {
+        let mut tokens = StaticVec::<(Token, Position)>::new_const();
+        let mut offset = 0;
+
+        for (i, part) in text.split(',').enumerate() {
+            if i > 0 {
+                tokens.push((Token::Comma, advance_position(pos, offset)));
+                offset += 1;
+            }
+
+            let token = self
+                .parse_number(part)
+                .unwrap_or_else(|| parse_number_text(part));
+            tokens.push((token, advance_position(pos, offset)));
+            offset += part.chars().count();
+        }
+
+        tokens.reverse();
+        self.split_tokens = tokens;
+        self.split_tokens.pop()
+    }
+    /// Read ahead the next token, if not already done.
+    fn read_ahead(&mut self) {
+        if self.pending.is_none() {
+            let newline_before = self.newline_before;
+            let equals_adjacent = self.equals_adjacent;
+            self.pending = self.scan();
+            self.pending_newline_before = mem::replace(&mut self.newline_before, newline_before);
+            self.pending_equals_adjacent = mem::replace(&mut self.equals_adjacent, equals_adjacent);
+        }
+    }
+    /// Get the next raw token.
+    fn next_raw(&mut self) -> Option<(Token, Position)> {
+        match self.pending.take() {
+            Some(r) => {
+                self.newline_before = self.pending_newline_before;
+                self.equals_adjacent = self.pending_equals_adjacent;
+                Some(r)
+            }
+            None => self.scan(),
+        }
+    }
+    /// Get the next token, processing conditional compilation directives
+    /// (`#if` _flag_, `#else` and `#endif`) and skipping tokens within inactive blocks.
+    fn next_active(&mut self) -> Option<(Token, Position)> {
+        loop {
+            let (token, pos) = match self.next_raw() {
+                Some(r) => r,
+                None if self.directives.is_empty() => return None,
+                None => {
+                    self.directives.clear();
+                    let err =
+                        LERR::ImproperSymbol("#if".to_string(), "Missing '#endif'".to_string());
+                    return Some((Token::LexError(err.into()), self.pos));
+                }
+            };
+
+            let is_active = self.directives.last().map_or(true, |&(active, ..)| active);
+            let newline_before = self.newline_before;
+            let equals_adjacent = self.equals_adjacent;
+
+            match token {
+                Token::Reserved(ref s) if s.as_str() == "#" => match self.next_raw() {
+                    // #if flag
+                    Some((Token::If, if_pos)) => match self.next_raw() {
+                        Some((Token::Identifier(flag), ..)) => {
+                            let active = is_active && self.engine.script_flag(&flag);
+                            self.directives.push((active, is_active, false));
+                        }
+                        next => {
+                            let pos = next.map_or(if_pos, |(.., pos)| pos);
+                            let err = LERR::ImproperSymbol(
+                                "#if".to_string(),
+                                "Expecting a flag name after '#if'".to_string(),
+                            );
+                            return Some((Token::LexError(err.into()), pos));
+                        }
+                    },
+                    // #else
+                    Some((Token::Else, else_pos)) => match self.directives.last_mut() {
+                        Some((active, parent_active, seen_else)) if !*seen_else => {
+                            *active = *parent_active && !*active;
+                            *seen_else = true;
+                        }
+                        _ => {
+                            let err = LERR::ImproperSymbol(
+                                "#else".to_string(),
+                                "'#else' without a matching '#if'".to_string(),
+                            );
+                            return Some((Token::LexError(err.into()), else_pos));
+                        }
+                    },
+                    // #endif
+                    Some((Token::Identifier(ref s), endif_pos)) if s.as_str() == "endif" => {
+                        if self.directives.pop().is_none() {
+                            let err = LERR::ImproperSymbol(
+                                "#endif".to_string(),
+                                "'#endif' without a matching '#if'".to_string(),
+                            );
+                            return Some((Token::LexError(err.into()), endif_pos));
+                        }
+                    }
+                    // Not a directive
+                    next => {
+                        self.pending = next;
+                        self.pending_newline_before =
+                            mem::replace(&mut self.newline_before, newline_before);
+                        self.pending_equals_adjacent =
+                            mem::replace(&mut self.equals_adjacent, equals_adjacent);
+
+                        if is_active {
+                            return Some((token, pos));
+                        }
+                    }
+                },
+                _ if is_active => return Some((token, pos)),
+                // Skip tokens within inactive blocks
+                _ => (),
+            }
+        }
+    }
 }
 
 impl<'a> Iterator for TokenIterator<'a> {
@@ -2376,7 +2640,7 @@ impl<'a> Iterator for TokenIterator<'a> {
             }
         }
 
-        let (token, pos) = match get_next_token(&mut self.stream, &mut self.state, &mut self.pos) {
+        let (token, pos) = match self.next_active() {
             // {EOF}
             None => return None,
             // {EOF} after unterminated string.
@@ -2441,6 +2705,10 @@ impl<'a> Iterator for TokenIterator<'a> {
             Some((Token::Identifier(s), pos)) if !self.engine.custom_keywords.is_empty() && self.engine.custom_keywords.contains_key(&*s) => {
                 (Token::Custom(s), pos)
             }
+            // Reserved identifier
+            Some((Token::Identifier(s), pos)) if !self.engine.reserved_keywords.is_empty() && self.engine.reserved_keywords.contains(&*s) => {
+                (Token::Reserved(s), pos)
+            }
             // Custom keyword/symbol - must be disabled
             #[cfg(not(feature = "no_custom_syntax"))]
             Some((token, pos)) if !self.engine.custom_keywords.is_empty() && self.engine.custom_keywords.contains_key(token.literal_syntax()) => {
@@ -2460,6 +2728,40 @@ impl<'a> Iterator for TokenIterator<'a> {
             Some(r) => r,
         };
 
+        // Custom operator immediately followed by `=` - custom op-assignment operator
+        #[cfg(not(feature = "no_custom_syntax"))]
+        let token = match token {
+            Token::Custom(mut s)
+                if self
+                    .engine
+                    .custom_keywords
+                    .get(&*s)
+                    .map_or(false, Option::is_some) =>
+            {
+                self.read_ahead();
+
+                if self.pending_equals_adjacent && matches!(self.pending, Some((Token::Equals, ..)))
+                {
+                    self.pending = None;
+                    s.push('=');
+                }
+                Token::Custom(s)
+            }
+            token => token,
+        };
+
+        // Look ahead for a closure block argument: `{ |...| ... }`
+        #[cfg(not(feature = "no_function"))]
+        {
+            let is_closure_block = token == Token::LeftBrace && {
+                self.read_ahead();
+                matches!(self.pending, Some((Token::Pipe | Token::Or, ..)))
+            };
+            self.state.tokenizer_control.borrow_mut().is_closure_block = is_closure_block;
+        }
+
+        self.state.tokenizer_control.borrow_mut().is_newline_before = self.newline_before;
+
         // Run the mapper, if any
         let token = match self.token_mapper {
             Some(map_func) => map_func(token, pos, &self.state),
@@ -2472,6 +2774,53 @@ impl<'a> Iterator for TokenIterator<'a> {
 
 impl FusedIterator for TokenIterator<'_> {}
 
+/// Get the [`Position`] a number of characters after another on the same line.
+fn advance_position(mut pos: Position, offset: usize) -> Position {
+    for _ in 0..offset {
+        pos.advance();
+    }
+    pos
+}
+
+/// Parse the text of a decimal number literal into a number token.
+fn parse_number_text(text: &str) -> Token {
+    let num = INT::from_str(text).map(Token::IntegerConstant);
+
+    // If integer parsing is unnecessary, try float instead
+    #[cfg(not(feature = "no_float"))]
+    let num = num.or_else(|_| crate::ast::FloatWrapper::from_str(text).map(Token::FloatConstant));
+
+    // Then try decimal
+    #[cfg(feature = "decimal")]
+    let num = num.or_else(|_| {
+        rust_decimal::Decimal::from_str(text)
+            .map(Box::new)
+            .map(Token::DecimalConstant)
+    });
+
+    // Then try decimal in scientific notation
+    #[cfg(feature = "decimal")]
+    let num = num.or_else(|_| {
+        rust_decimal::Decimal::from_scientific(text)
+            .map(Box::new)
+            .map(Token::DecimalConstant)
+    });
+
+    num.unwrap_or_else(|_| Token::LexError(LERR::MalformedNumber(text.to_string()).into()))
+}
+
+/// Convert a value returned by a custom number parser into a [`Token`].
+fn number_to_token(value: Dynamic, text: &str) -> Token {
+    match value.0 {
+        Union::Int(n, ..) => Token::IntegerConstant(n),
+        #[cfg(not(feature = "no_float"))]
+        Union::Float(f, ..) => Token::FloatConstant(f),
+        #[cfg(feature = "decimal")]
+        Union::Decimal(d, ..) => Token::DecimalConstant(d),
+        _ => Token::LexError(LERR::MalformedNumber(text.to_string()).into()),
+    }
+}
+
 impl Engine {
     /// _(internals)_ Tokenize an input text stream.
     /// Exported under the `internals` feature only.
@@ -2520,6 +2869,8 @@ impl Engine {
                     comment_level: 0,
                     include_comments: false,
                     is_within_text_terminated_by: None,
+                    custom_number_parser: self.number_parser.is_some(),
+                    number_text: None,
                 },
                 pos: Position::new(1, 0),
                 stream: MultiInputsStream {
@@ -2531,6 +2882,13 @@ impl Engine {
                     index: 0,
                 },
                 token_mapper,
+                pending: None,
+                newline_before: false,
+                pending_newline_before: false,
+                equals_adjacent: false,
+                pending_equals_adjacent: false,
+                directives: StaticVec::new_const(),
+                split_tokens: StaticVec::new_const(),
             },
             buffer2,
         )
@@ -1,7 +1,11 @@
 //! Collection of custom types.
 
 use crate::Identifier;
-use std::{any::type_name, collections::BTreeMap, fmt};
+use std::{
+    any::{type_name, Any, TypeId},
+    collections::BTreeMap,
+    fmt,
+};
 
 /// _(internals)_ Information for a custom type.
 /// Exported under the `internals` feature only.
@@ -9,6 +13,8 @@ use std::{any::type_name, collections::BTreeMap, fmt};
 pub struct CustomTypeInfo {
     /// Friendly display name of the custom type.
     pub display_name: Identifier,
+    /// [`TypeId`] of the custom type, if known.
+    pub type_id: Option<TypeId>,
 }
 
 /// _(internals)_ A collection of custom types.
@@ -38,6 +44,7 @@ impl CustomTypesCollection {
             type_name,
             CustomTypeInfo {
                 display_name: name.into(),
+                type_id: None,
             },
         );
     }
@@ -48,6 +55,18 @@ impl CustomTypesCollection {
             type_name::<T>(),
             CustomTypeInfo {
                 display_name: name.into(),
+                type_id: None,
+            },
+        );
+    }
+    /// Register a custom type, recording its [`TypeId`].
+    #[inline(always)]
+    pub fn add_type_with_id<T: Any>(&mut self, name: &str) {
+        self.add_raw(
+            type_name::<T>(),
+            CustomTypeInfo {
+                display_name: name.into(),
+                type_id: Some(TypeId::of::<T>()),
             },
         );
     }
@@ -61,4 +80,11 @@ impl CustomTypesCollection {
     pub fn get(&self, key: &str) -> Option<&CustomTypeInfo> {
         self.0.get(key)
     }
+    /// Find a custom type by its [`TypeId`].
+    ///
+    /// Custom types registered without a [`TypeId`] (e.g. via [`add`][Self::add] or [`add_type`][Self::add_type]) are not found.
+    #[inline]
+    pub fn get_by_type_id(&self, type_id: TypeId) -> Option<&CustomTypeInfo> {
+        self.0.values().find(|t| t.type_id == Some(type_id))
+    }
 }
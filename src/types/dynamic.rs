@@ -375,6 +375,77 @@ impl Dynamic {
             Union::Shared(ref cell, ..) => (*cell.read().unwrap()).type_name(),
         }
     }
+    /// Calculate a hash of the value held by this [`Dynamic`], e.g. for use as a key in a cache
+    /// of results kept by the host.
+    ///
+    /// `()`, booleans, characters, strings, numbers and [BLOB's][crate::Blob] can be hashed, as
+    /// well as [arrays][crate::Array] and [object maps][crate::Map] containing only such values.
+    /// Shared values are hashed as the values they hold.
+    ///
+    /// Returns [`None`] for any other value, such as a custom type, a function pointer or a
+    /// timestamp, or an array or object map containing one.
+    ///
+    /// The hash is stable within the same run of the process, but may differ between runs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::{Dynamic, INT};
+    ///
+    /// let x = Dynamic::from(42 as INT);
+    ///
+    /// assert_eq!(x.calc_hash(), Dynamic::from(42 as INT).calc_hash());
+    /// assert_ne!(x.calc_hash(), Dynamic::from("42").calc_hash());
+    ///
+    /// #[derive(Clone)]
+    /// struct TestStruct;
+    ///
+    /// assert!(Dynamic::from(TestStruct).calc_hash().is_none());
+    /// ```
+    #[must_use]
+    pub fn calc_hash(&self) -> Option<u64> {
+        let hasher = &mut crate::func::hashing::get_hasher();
+
+        if self.hash_value(hasher) {
+            Some(hasher.finish())
+        } else {
+            None
+        }
+    }
+    /// Hash the value held by this [`Dynamic`], looking through shared values.
+    ///
+    /// Returns `false` if the value cannot be hashed.
+    fn hash_value<H: Hasher>(&self, state: &mut H) -> bool {
+        match self.0 {
+            #[cfg(not(feature = "no_closure"))]
+            Union::Shared(ref cell, ..) => return crate::func::locked_read(cell).hash_value(state),
+
+            #[cfg(not(feature = "no_index"))]
+            Union::Array(ref a, ..) => {
+                mem::discriminant(&self.0).hash(state);
+                a.len().hash(state);
+                return a.iter().all(|v| v.hash_value(state));
+            }
+            #[cfg(not(feature = "no_object"))]
+            Union::Map(ref m, ..) => {
+                mem::discriminant(&self.0).hash(state);
+                m.len().hash(state);
+                return m.iter().all(|(k, v)| {
+                    k.hash(state);
+                    v.hash_value(state)
+                });
+            }
+
+            Union::FnPtr(..) | Union::Variant(..) => return false,
+            #[cfg(not(feature = "no_std"))]
+            Union::TimeStamp(..) => return false,
+
+            _ => (),
+        }
+
+        self.hash(state);
+        true
+    }
 }
 
 impl Hash for Dynamic {
@@ -1064,6 +1135,9 @@ impl Dynamic {
         reify!(value, |v: ImmutableString| return v.into());
         reify!(value, |v: String| return v.into());
         reify!(value, |v: &str| return v.into());
+        reify!(value, |v: std::borrow::Cow<'static, str>| {
+            return ImmutableString::from(v).into();
+        });
         reify!(value, |v: ()| return v.into());
 
         #[cfg(not(feature = "no_index"))]
@@ -1627,6 +1701,101 @@ impl Dynamic {
             _ => Err(self.type_name()),
         }
     }
+    /// Convert the [`Dynamic`] into the system integer type [`INT`], truncating a floating-point
+    /// number towards zero.
+    ///
+    /// Unlike [`as_int`][Dynamic::as_int], a floating-point number is also accepted, in the same
+    /// way as the `to_int` function in scripts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ErrorMismatchDataType`][crate::EvalAltResult::ErrorMismatchDataType] if the value
+    /// is not a number.
+    ///
+    /// Returns [`ErrorArithmetic`][crate::EvalAltResult::ErrorArithmetic] if the floating-point
+    /// number is `NaN` or out of the range of [`INT`]. Under `unchecked`, such numbers are
+    /// saturated instead (with `NaN` becoming zero).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::Dynamic;
+    ///
+    /// assert_eq!(Dynamic::from_int(42).to_int().unwrap(), 42);
+    /// # #[cfg(not(feature = "no_float"))]
+    /// assert_eq!(Dynamic::from_float(-3.9).to_int().unwrap(), -3);
+    /// assert!(Dynamic::from("hello").to_int().is_err());
+    /// ```
+    #[inline]
+    pub fn to_int(&self) -> crate::RhaiResultOf<INT> {
+        if let Ok(n) = self.as_int() {
+            return Ok(n);
+        }
+
+        #[cfg(not(feature = "no_float"))]
+        if let Ok(x) = self.as_float() {
+            #[allow(clippy::cast_precision_loss)]
+            let out_of_range =
+                x.is_nan() || x > (INT::MAX as crate::FLOAT) || x < (INT::MIN as crate::FLOAT);
+
+            if cfg!(not(feature = "unchecked")) && out_of_range {
+                return Err(crate::ERR::ErrorArithmetic(
+                    format!("Integer overflow: to_int({x})"),
+                    crate::Position::NONE,
+                )
+                .into());
+            }
+
+            #[allow(clippy::cast_possible_truncation)]
+            return Ok(x.trunc() as INT);
+        }
+
+        Err(crate::ERR::ErrorMismatchDataType(
+            type_name::<INT>().into(),
+            self.type_name().into(),
+            crate::Position::NONE,
+        )
+        .into())
+    }
+    /// Convert the [`Dynamic`] into the system floating-point type [`FLOAT`][crate::FLOAT],
+    /// converting an integer if necessary.
+    ///
+    /// Unlike [`as_float`][Dynamic::as_float], an integer is also accepted, in the same way as the
+    /// `to_float` function in scripts. Very large integers may lose precision.
+    ///
+    /// Not available under `no_float`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ErrorMismatchDataType`][crate::EvalAltResult::ErrorMismatchDataType] if the value
+    /// is not a number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::Dynamic;
+    ///
+    /// assert_eq!(Dynamic::from_int(42).to_float().unwrap(), 42.0);
+    /// assert_eq!(Dynamic::from_float(1.5).to_float().unwrap(), 1.5);
+    /// assert!(Dynamic::from(true).to_float().is_err());
+    /// ```
+    #[cfg(not(feature = "no_float"))]
+    #[inline]
+    pub fn to_float(&self) -> crate::RhaiResultOf<crate::FLOAT> {
+        if let Ok(x) = self.as_float() {
+            return Ok(x);
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        self.as_int().map(|n| n as crate::FLOAT).map_err(|typ| {
+            crate::ERR::ErrorMismatchDataType(
+                type_name::<crate::FLOAT>().into(),
+                typ.into(),
+                crate::Position::NONE,
+            )
+            .into()
+        })
+    }
     /// _(decimal)_ Cast the [`Dynamic`] as a [`Decimal`][rust_decimal::Decimal].
     /// Returns the name of the actual type if the cast fails.
     ///
@@ -95,8 +95,12 @@ pub enum EvalAltResult {
     ErrorTooManyOperations(Position),
     /// [Modules][crate::Module] over maximum limit.
     ErrorTooManyModules(Position),
+    /// Variables in a [`Scope`][crate::Scope] over maximum limit.
+    ErrorTooManyVariables(Position),
     /// Call stack over maximum limit.
     ErrorStackOverflow(Position),
+    /// Nested closure calls over maximum limit.
+    ErrorClosureStackOverflow(Position),
     /// Data value over maximum size limit. Wrapped value is the type name.
     ErrorDataTooLarge(String, Position),
     /// The script is prematurely terminated. Wrapped value is the termination token.
@@ -116,6 +120,9 @@ pub enum EvalAltResult {
     /// The wrapped value, if true, means breaking clean out of the loop (i.e. a `break` statement).
     /// The wrapped value, if false, means breaking the current context (i.e. a `continue` statement).
     LoopBreak(bool, Position),
+    /// Not an error: Breaking out of a `loop` expression with a value.
+    /// Wrapped value is the value of the `break` statement.
+    LoopBreakWithValue(Dynamic, Position),
     /// Not an error: Value returned from a script via the `return` keyword.
     /// Wrapped value is the result value.
     Return(Dynamic, Position),
@@ -169,7 +176,9 @@ impl fmt::Display for EvalAltResult {
             Self::ErrorFor(..) => f.write_str("For loop expects an iterable type")?,
             Self::ErrorTooManyOperations(..) => f.write_str("Too many operations")?,
             Self::ErrorTooManyModules(..) => f.write_str("Too many modules imported")?,
+            Self::ErrorTooManyVariables(..) => f.write_str("Too many variables defined")?,
             Self::ErrorStackOverflow(..) => f.write_str("Stack overflow")?,
+            Self::ErrorClosureStackOverflow(..) => f.write_str("Stack overflow in closures")?,
             Self::ErrorTerminated(..) => f.write_str("Script terminated")?,
 
             Self::ErrorRuntime(d, ..) if d.is::<()>() => f.write_str("Runtime error")?,
@@ -198,6 +207,7 @@ impl fmt::Display for EvalAltResult {
 
             Self::LoopBreak(true, ..) => f.write_str("'break' not inside a loop")?,
             Self::LoopBreak(false, ..) => f.write_str("'continue' not inside a loop")?,
+            Self::LoopBreakWithValue(..) => f.write_str("'break' not inside a loop")?,
 
             Self::Return(..) => f.write_str("NOT AN ERROR - function returns value")?,
 
@@ -260,13 +270,14 @@ impl<T: AsRef<str>> From<T> for Box<EvalAltResult> {
 impl EvalAltResult {
     /// Is this a pseudo error?  A pseudo error is one that does not occur naturally.
     ///
-    /// [`LoopBreak`][EvalAltResult::LoopBreak] and [`Return`][EvalAltResult::Return] are pseudo errors.
+    /// [`LoopBreak`][EvalAltResult::LoopBreak], [`LoopBreakWithValue`][EvalAltResult::LoopBreakWithValue]
+    /// and [`Return`][EvalAltResult::Return] are pseudo errors.
     #[cold]
     #[inline(never)]
     #[must_use]
     pub const fn is_pseudo_error(&self) -> bool {
         match self {
-            Self::LoopBreak(..) | Self::Return(..) => true,
+            Self::LoopBreak(..) | Self::LoopBreakWithValue(..) | Self::Return(..) => true,
             _ => false,
         }
     }
@@ -310,11 +321,13 @@ impl EvalAltResult {
 
             Self::ErrorTooManyOperations(..)
             | Self::ErrorTooManyModules(..)
+            | Self::ErrorTooManyVariables(..)
             | Self::ErrorStackOverflow(..)
+            | Self::ErrorClosureStackOverflow(..)
             | Self::ErrorDataTooLarge(..)
             | Self::ErrorTerminated(..) => false,
 
-            Self::LoopBreak(..) | Self::Return(..) => false,
+            Self::LoopBreak(..) | Self::LoopBreakWithValue(..) | Self::Return(..) => false,
         }
     }
     /// Is this error a system exception?
@@ -329,7 +342,9 @@ impl EvalAltResult {
             Self::ErrorCustomSyntax(..)
             | Self::ErrorTooManyOperations(..)
             | Self::ErrorTooManyModules(..)
+            | Self::ErrorTooManyVariables(..)
             | Self::ErrorStackOverflow(..)
+            | Self::ErrorClosureStackOverflow(..)
             | Self::ErrorDataTooLarge(..) => true,
 
             Self::ErrorTerminated(..) => true,
@@ -352,7 +367,7 @@ impl EvalAltResult {
         );
 
         match self {
-            Self::LoopBreak(..) | Self::Return(..) => (),
+            Self::LoopBreak(..) | Self::LoopBreakWithValue(..) | Self::Return(..) => (),
 
             Self::ErrorSystem(..)
             | Self::ErrorParsing(..)
@@ -361,7 +376,9 @@ impl EvalAltResult {
             | Self::ErrorArithmetic(..)
             | Self::ErrorTooManyOperations(..)
             | Self::ErrorTooManyModules(..)
+            | Self::ErrorTooManyVariables(..)
             | Self::ErrorStackOverflow(..)
+            | Self::ErrorClosureStackOverflow(..)
             | Self::ErrorRuntime(..) => (),
 
             Self::ErrorFunctionNotFound(f, ..) => {
@@ -433,6 +450,30 @@ impl EvalAltResult {
             _ => self,
         }
     }
+    /// Get the chain of function calls that this error propagated through, as a list of function
+    /// names and the [positions][Position] of the calls.
+    ///
+    /// The innermost call (i.e. the one in which the error occurred) comes first.
+    /// The position of the error itself is available via
+    /// [`unwrap_inner`][EvalAltResult::unwrap_inner].
+    ///
+    /// Turn on [`Engine::set_error_backtrace`][crate::Engine::set_error_backtrace] to keep every
+    /// call in the chain. Otherwise, nested script function calls are collapsed into one entry.
+    #[cold]
+    #[inline(never)]
+    #[must_use]
+    pub fn backtrace(&self) -> Vec<(String, Position)> {
+        let mut frames = Vec::new();
+        let mut err = self;
+
+        while let Self::ErrorInFunctionCall(name, .., inner, pos) = err {
+            frames.push((name.clone(), *pos));
+            err = inner;
+        }
+
+        frames.reverse();
+        frames
+    }
     /// Get the [position][Position] of this error.
     #[cold]
     #[inline(never)]
@@ -465,12 +506,15 @@ impl EvalAltResult {
             | Self::ErrorArithmetic(.., pos)
             | Self::ErrorTooManyOperations(pos)
             | Self::ErrorTooManyModules(pos)
+            | Self::ErrorTooManyVariables(pos)
             | Self::ErrorStackOverflow(pos)
+            | Self::ErrorClosureStackOverflow(pos)
             | Self::ErrorDataTooLarge(.., pos)
             | Self::ErrorTerminated(.., pos)
             | Self::ErrorCustomSyntax(.., pos)
             | Self::ErrorRuntime(.., pos)
             | Self::LoopBreak(.., pos)
+            | Self::LoopBreakWithValue(.., pos)
             | Self::Return(.., pos) => *pos,
         }
     }
@@ -524,12 +568,15 @@ impl EvalAltResult {
             | Self::ErrorArithmetic(.., pos)
             | Self::ErrorTooManyOperations(pos)
             | Self::ErrorTooManyModules(pos)
+            | Self::ErrorTooManyVariables(pos)
             | Self::ErrorStackOverflow(pos)
+            | Self::ErrorClosureStackOverflow(pos)
             | Self::ErrorDataTooLarge(.., pos)
             | Self::ErrorTerminated(.., pos)
             | Self::ErrorCustomSyntax(.., pos)
             | Self::ErrorRuntime(.., pos)
             | Self::LoopBreak(.., pos)
+            | Self::LoopBreakWithValue(.., pos)
             | Self::Return(.., pos) => *pos = new_position,
         }
         self
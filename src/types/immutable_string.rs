@@ -105,6 +105,15 @@ impl From<Box<str>> for ImmutableString {
         Self(value.into())
     }
 }
+impl From<std::borrow::Cow<'_, str>> for ImmutableString {
+    #[inline(always)]
+    fn from(value: std::borrow::Cow<'_, str>) -> Self {
+        match value {
+            std::borrow::Cow::Borrowed(s) => s.into(),
+            std::borrow::Cow::Owned(s) => s.into(),
+        }
+    }
+}
 impl From<&String> for ImmutableString {
     #[inline(always)]
     fn from(value: &String) -> Self {
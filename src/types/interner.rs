@@ -8,6 +8,7 @@ use std::collections::hash_map::Entry;
 #[cfg(feature = "no_std")]
 use std::prelude::v1::*;
 use std::{
+    cmp::Reverse,
     fmt,
     hash::{Hash, Hasher},
     marker::PhantomData,
@@ -24,6 +25,7 @@ pub const MAX_STRING_LEN: usize = 24;
 /// Exported under the `internals` feature only.
 ///
 /// Normal identifiers, property getters and setters are interned separately.
+#[derive(Clone)]
 pub struct StringsInterner<'a> {
     /// Maximum number of strings interned.
     pub capacity: usize,
@@ -107,38 +109,59 @@ impl StringsInterner<'_> {
             }
         };
 
-        // If the interner is over capacity, remove the longest entry that has the lowest count
-        if self.cache.len() > self.capacity {
-            // Leave some buffer to grow when shrinking the cache.
-            // We leave at least two entries, one for the empty string, and one for the string
-            // that has just been inserted.
-            let max = if self.capacity < 5 {
-                2
-            } else {
-                self.capacity - 3
-            };
-
-            while self.cache.len() > max {
-                let (_, _, n) = self
-                    .cache
-                    .iter()
-                    .fold((0, usize::MAX, 0), |(x, c, n), (&k, v)| {
-                        if k != hash
-                            && (v.strong_count() < c || (v.strong_count() == c && v.len() > x))
-                        {
-                            (v.len(), v.strong_count(), k)
-                        } else {
-                            (x, c, n)
-                        }
-                    });
-
-                self.cache.remove(&n);
-            }
-        }
+        self.shrink_to_capacity(Some(hash));
 
         result
     }
 
+    /// If the interner is over capacity, remove the longest entries that have the lowest counts.
+    ///
+    /// The entry with the hash `keep`, if any, is never removed.
+    fn shrink_to_capacity(&mut self, keep: Option<u64>) {
+        if self.cache.len() <= self.capacity {
+            return;
+        }
+
+        // Leave some buffer to grow when shrinking the cache.
+        // We leave at least two entries, one for the empty string, and one for the string
+        // that has just been inserted.
+        let max = if self.capacity < 5 {
+            2
+        } else {
+            self.capacity - 3
+        };
+
+        // Sort the entries once by lowest count first, then longest first.
+        let mut entries: Vec<_> = self
+            .cache
+            .iter()
+            .filter(|(&k, ..)| Some(k) != keep)
+            .map(|(&k, v)| (v.strong_count(), Reverse(v.len()), k))
+            .collect();
+        entries.sort_unstable();
+
+        let excess = self.cache.len() - max;
+
+        for (.., k) in entries.into_iter().take(excess) {
+            self.cache.remove(&k);
+        }
+    }
+
+    /// Merge the strings interned by another [`StringsInterner`] into this one.
+    ///
+    /// Unlike `+=`, the capacity of this [`StringsInterner`] is respected and the "one-hit wonders"
+    /// filter of the other [`StringsInterner`] is kept.
+    #[inline]
+    pub fn merge(&mut self, other: StringsInterner) {
+        self.filter += other.filter;
+
+        for (k, v) in other.cache {
+            self.cache.entry(k).or_insert(v);
+        }
+
+        self.shrink_to_capacity(None);
+    }
+
     /// Number of strings interned.
     #[inline(always)]
     #[must_use]
@@ -163,16 +186,16 @@ impl StringsInterner<'_> {
     }
 }
 
-impl AddAssign<Self> for StringsInterner<'_> {
+impl<'b> AddAssign<StringsInterner<'b>> for StringsInterner<'_> {
     #[inline(always)]
-    fn add_assign(&mut self, rhs: Self) {
+    fn add_assign(&mut self, rhs: StringsInterner<'b>) {
         self.cache.extend(rhs.cache.into_iter());
     }
 }
 
-impl AddAssign<&Self> for StringsInterner<'_> {
+impl<'b> AddAssign<&StringsInterner<'b>> for StringsInterner<'_> {
     #[inline(always)]
-    fn add_assign(&mut self, rhs: &Self) {
+    fn add_assign(&mut self, rhs: &StringsInterner<'b>) {
         self.cache
             .extend(rhs.cache.iter().map(|(&k, v)| (k, v.clone())));
     }
@@ -0,0 +1,126 @@
+//! The `LazySequence` type.
+
+use crate::func::{locked_write, LazySequenceSource, Locked, SendSync, Shared};
+use crate::{Dynamic, FnPtr, StaticVec};
+use std::fmt;
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+/// A lazy sequence of values driven by a Rust iterator.
+///
+/// Values are only produced when the sequence is iterated by a `for` loop, so a sequence can
+/// stream from a source without first collecting it into an [`Array`][crate::Array].
+///
+/// The source iterator is shared by all clones of a [`LazySequence`], including those returned
+/// by [`take`][LazySequence::take] and [`map`][LazySequence::map].
+/// Therefore a sequence can only be consumed once: values yielded to one `for` loop are not seen
+/// by another.
+#[derive(Clone)]
+pub struct LazySequence {
+    /// The source iterator.
+    source: Shared<Locked<Box<LazySequenceSource>>>,
+    /// Maximum number of values to yield, if any.
+    limit: Option<usize>,
+    /// Functions to map each value with, applied in order.
+    mappers: StaticVec<FnPtr>,
+}
+
+impl fmt::Debug for LazySequence {
+    #[cold]
+    #[inline(never)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("LazySequence");
+        if let Some(limit) = self.limit {
+            d.field("limit", &limit);
+        }
+        if !self.mappers.is_empty() {
+            d.field("mappers", &self.mappers);
+        }
+        d.finish_non_exhaustive()
+    }
+}
+
+impl LazySequence {
+    /// Create a new [`LazySequence`] from a Rust iterator.
+    #[inline]
+    #[must_use]
+    pub fn new<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Dynamic>,
+        I::IntoIter: SendSync + 'static,
+    {
+        let source: Box<LazySequenceSource> = Box::new(iter.into_iter());
+
+        Self {
+            source: Shared::new(Locked::new(source)),
+            limit: None,
+            mappers: StaticVec::new_const(),
+        }
+    }
+    /// Get the maximum number of values to yield, if any.
+    #[inline(always)]
+    #[must_use]
+    pub const fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+    /// Get the functions mapping each value, in the order they are applied.
+    #[inline(always)]
+    #[must_use]
+    pub fn mappers(&self) -> &[FnPtr] {
+        &self.mappers
+    }
+    /// Create a sequence yielding at most `n` values of this sequence.
+    #[inline]
+    #[must_use]
+    pub fn take(&self, n: usize) -> Self {
+        let mut seq = self.clone();
+        seq.limit = Some(self.limit.map_or(n, |limit| limit.min(n)));
+        seq
+    }
+    /// Create a sequence mapping each value of this sequence with a function.
+    ///
+    /// The function is only called when the sequence is iterated.
+    #[inline]
+    #[must_use]
+    pub fn map(&self, mapper: FnPtr) -> Self {
+        let mut seq = self.clone();
+        seq.mappers.push(mapper);
+        seq
+    }
+    /// Get an iterator over the source values of this sequence, up to its limit.
+    ///
+    /// The mapping functions are _not_ applied.
+    #[inline]
+    #[must_use]
+    pub fn source_iter(&self) -> LazySequenceIter {
+        LazySequenceIter {
+            source: self.source.clone(),
+            remaining: self.limit,
+        }
+    }
+}
+
+/// An iterator over the source values of a [`LazySequence`].
+pub struct LazySequenceIter {
+    /// The source iterator.
+    source: Shared<Locked<Box<LazySequenceSource>>>,
+    /// Number of values left to yield, if limited.
+    remaining: Option<usize>,
+}
+
+impl Iterator for LazySequenceIter {
+    type Item = Dynamic;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.remaining {
+            Some(0) => None,
+            Some(ref mut n) => {
+                let value = locked_write(&self.source).next();
+                *n = if value.is_some() { *n - 1 } else { 0 };
+                value
+            }
+            None => locked_write(&self.source).next(),
+        }
+    }
+}
@@ -7,9 +7,13 @@ pub mod error;
 pub mod fn_ptr;
 pub mod immutable_string;
 pub mod interner;
+pub mod lazy_sequence;
 pub mod parse_error;
 pub mod scope;
+pub mod source_map;
+pub mod typed_map;
 pub mod variant;
+pub mod walk;
 
 pub use bloom_filter::BloomFilterU64;
 pub use custom_types::{CustomTypeInfo, CustomTypesCollection};
@@ -20,6 +24,11 @@ pub use error::EvalAltResult;
 pub use fn_ptr::FnPtr;
 pub use immutable_string::ImmutableString;
 pub use interner::StringsInterner;
+pub use lazy_sequence::LazySequence;
 pub use parse_error::{LexError, ParseError, ParseErrorType};
-pub use scope::Scope;
+pub use scope::{Scope, ScopeBuilder};
+pub use source_map::SourceMap;
+#[cfg(not(feature = "no_object"))]
+pub use typed_map::TypedMap;
 pub use variant::Variant;
+pub use walk::PathSegment;
@@ -115,12 +115,17 @@ pub enum ParseErrorType {
     WrongSwitchDefaultCase,
     /// The case condition of a `switch` statement is not appropriate.
     WrongSwitchCaseCondition,
+    /// A `switch` statement has no default case (in strict `switch` mode).
+    MissingSwitchDefaultCase,
     /// Missing a property name for custom types and maps.
     PropertyExpected,
     /// Missing a variable name after the `let`, `const`, `for` or `catch` keywords.
     VariableExpected,
     /// Forbidden variable name.  Wrapped value is the variable name.
     ForbiddenVariable(String),
+    /// An anonymous function (i.e. closure) is used when anonymous functions are disabled via
+    /// [`Engine::set_allow_anonymous_fn`][crate::Engine::set_allow_anonymous_fn].
+    ForbiddenAnonymousFn,
     /// An identifier is a reserved symbol.
     Reserved(String),
     /// An expression is of the wrong type.
@@ -244,9 +249,11 @@ impl fmt::Display for ParseErrorType {
             Self::WrongSwitchIntegerCase => f.write_str("Integer switch case cannot follow a range case"),
             Self::WrongSwitchDefaultCase => f.write_str("Default switch case must be the last"),
             Self::WrongSwitchCaseCondition => f.write_str("This switch case cannot have a condition"),
+            Self::MissingSwitchDefaultCase => f.write_str("Switch must have a default case"),
             Self::PropertyExpected => f.write_str("Expecting name of a property"),
             Self::VariableExpected => f.write_str("Expecting name of a variable"),
             Self::ForbiddenVariable(s) => write!(f, "Forbidden variable name: {s}"),
+            Self::ForbiddenAnonymousFn => f.write_str("Anonymous functions are not allowed"),
             Self::WrongFnDefinition => f.write_str("Function definitions must be at global level and cannot be inside a block or another function"),
             Self::FnMissingName => f.write_str("Expecting function name in function declaration"),
             Self::WrongDocComment => f.write_str("Doc-comment must be followed immediately by a function definition"),
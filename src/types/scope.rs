@@ -199,6 +199,28 @@ impl Scope<'_> {
             dummy: PhantomData,
         }
     }
+    /// Create a [`ScopeBuilder`] to fluently build a new [`Scope`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rhai::Scope;
+    ///
+    /// let my_scope = Scope::builder()
+    ///                     .with_constant("x", 42_i64)
+    ///                     .with_var("y", "hello".to_string())
+    ///                     .build();
+    ///
+    /// assert_eq!(my_scope.len(), 2);
+    /// assert!(my_scope.is_constant("x").expect("x should exist"));
+    /// assert_eq!(my_scope.get_value::<String>("y").expect("y should exist"), "hello");
+    /// ```
+    #[inline(always)]
+    pub const fn builder<'a>() -> ScopeBuilder<'a> {
+        ScopeBuilder {
+            scope: Scope::new(),
+        }
+    }
     /// Empty the [`Scope`].
     ///
     /// # Example
@@ -339,6 +361,59 @@ impl Scope<'_> {
     ) -> &mut Self {
         self.push_entry(name, AccessMode::ReadOnly, value)
     }
+    /// Add (push) a new entry to the [`Scope`] that is an alias of an existing variable.
+    ///
+    /// Reads and writes to `alias` go to the storage of `target`, so a value written through one
+    /// name is seen through the other. The alias is constant if `target` is constant.
+    ///
+    /// To share storage, the value of `target` is turned into a shared value.
+    ///
+    /// Returns `false` and does nothing if there is no variable named `target`.
+    ///
+    /// Not available under `no_closure`.
+    ///
+    /// # Shadowing
+    ///
+    /// Only the last variable named `target` (and not other shadowed versions) is aliased.
+    ///
+    /// Defining a new variable named `alias` (e.g. via `let alias = ...` in a script) shadows the
+    /// alias and breaks the link. Likewise, a new variable shadowing `target` is not seen through
+    /// `alias`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+    /// use rhai::{Engine, Scope};
+    ///
+    /// let engine = Engine::new();
+    /// let mut my_scope = Scope::new();
+    ///
+    /// my_scope.push("x", 40_i64);
+    /// assert!(my_scope.push_alias("old_x", "x"));
+    /// assert!(!my_scope.push_alias("old_y", "y"));
+    ///
+    /// engine.run_with_scope(&mut my_scope, "old_x += 2;")?;
+    /// assert_eq!(my_scope.get_value::<i64>("x").expect("x should exist"), 42);
+    ///
+    /// my_scope.set_value("x", 0_i64);
+    /// assert_eq!(my_scope.get_value::<i64>("old_x").expect("old_x should exist"), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(feature = "no_closure"))]
+    #[inline]
+    pub fn push_alias(&mut self, alias: impl Into<Identifier>, target: &str) -> bool {
+        if let Some((index, access)) = self.get_index(target) {
+            let value = self.get_mut_by_index(index);
+            *value = std::mem::take(value).into_shared();
+            let value = value.clone();
+            self.push_entry(alias, access, value);
+            true
+        } else {
+            false
+        }
+    }
     /// Add (push) a new entry with a [`Dynamic`] value to the [`Scope`].
     #[inline]
     pub(crate) fn push_entry(
@@ -508,8 +583,7 @@ impl Scope<'_> {
                 self.push(name, value);
             }
             Some((index, AccessMode::ReadWrite)) => {
-                let value_ref = self.values.get_mut(index).unwrap();
-                *value_ref = Dynamic::from(value);
+                self.set_value_by_index(index, Dynamic::from(value));
             }
         }
         self
@@ -548,8 +622,7 @@ impl Scope<'_> {
             }
             Some((.., AccessMode::ReadOnly)) => panic!("variable {} is constant", name.as_ref()),
             Some((index, AccessMode::ReadWrite)) => {
-                let value_ref = self.values.get_mut(index).unwrap();
-                *value_ref = Dynamic::from(value);
+                self.set_value_by_index(index, Dynamic::from(value));
             }
         }
         self
@@ -655,6 +728,26 @@ impl Scope<'_> {
     pub(crate) fn get_mut_by_index(&mut self, index: usize) -> &mut Dynamic {
         self.values.get_mut(index).unwrap()
     }
+    /// Update the value of an entry in the [`Scope`] based on the index.
+    ///
+    /// A shared value (e.g. one aliased via [`push_alias`][Scope::push_alias]) is updated in place,
+    /// so the new value is seen by all entries sharing it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[inline]
+    fn set_value_by_index(&mut self, index: usize, value: Dynamic) {
+        let value_ref = self.get_mut_by_index(index);
+
+        #[cfg(not(feature = "no_closure"))]
+        if value_ref.is_shared() {
+            *value_ref.write_lock::<Dynamic>().unwrap() = value;
+            return;
+        }
+
+        *value_ref = value;
+    }
     /// Add an alias to an entry in the [`Scope`].
     ///
     /// # Panics
@@ -833,3 +926,58 @@ impl<K: Into<Identifier>> FromIterator<(K, bool, Dynamic)> for Scope<'_> {
         scope
     }
 }
+
+/// Builder to fluently build a [`Scope`] with variables and constants.
+///
+/// Create one via [`Scope::builder`].
+#[derive(Debug, Clone, Default)]
+#[must_use = "the scope is only returned when `build` is called"]
+pub struct ScopeBuilder<'a> {
+    /// The [`Scope`] being built.
+    scope: Scope<'a>,
+}
+
+impl<'a> ScopeBuilder<'a> {
+    /// Add a new variable to the [`Scope`] being built.
+    ///
+    /// This is the same as [`Scope::push`].
+    #[inline(always)]
+    pub fn with_var(mut self, name: impl Into<Identifier>, value: impl Variant + Clone) -> Self {
+        self.scope.push(name, value);
+        self
+    }
+    /// Add a new [`Dynamic`] entry to the [`Scope`] being built.
+    ///
+    /// This is the same as [`Scope::push_dynamic`].
+    #[inline(always)]
+    pub fn with_dynamic(mut self, name: impl Into<Identifier>, value: Dynamic) -> Self {
+        self.scope.push_dynamic(name, value);
+        self
+    }
+    /// Add a new constant to the [`Scope`] being built.
+    ///
+    /// This is the same as [`Scope::push_constant`].
+    #[inline(always)]
+    pub fn with_constant(
+        mut self,
+        name: impl Into<Identifier>,
+        value: impl Variant + Clone,
+    ) -> Self {
+        self.scope.push_constant(name, value);
+        self
+    }
+    /// Add a new constant with a [`Dynamic`] value to the [`Scope`] being built.
+    ///
+    /// This is the same as [`Scope::push_constant_dynamic`].
+    #[inline(always)]
+    pub fn with_constant_dynamic(mut self, name: impl Into<Identifier>, value: Dynamic) -> Self {
+        self.scope.push_constant_dynamic(name, value);
+        self
+    }
+    /// Build the [`Scope`].
+    #[inline(always)]
+    #[must_use]
+    pub fn build(self) -> Scope<'a> {
+        self.scope
+    }
+}
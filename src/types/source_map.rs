@@ -0,0 +1,72 @@
+//! The `SourceMap` type.
+
+use crate::{Identifier, Position, StaticVec};
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+/// Map of positions in a script assembled from multiple named sources back to those sources.
+///
+/// Each source starts on a new line of the assembled script, so a [`Position`] in the assembled
+/// script belongs to exactly one source.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(not(feature = "no_position"))]
+/// # {
+/// use rhai::{Position, SourceMap};
+///
+/// let map = SourceMap::new(&[("header", "let x = 1;\nlet y = 2;"), ("body", "x + y")]);
+///
+/// assert_eq!(map.resolve(Position::new(3, 5)), Some(("body", Position::new(1, 5))));
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct SourceMap {
+    /// Name and starting line (1-based) of each source, in order.
+    segments: StaticVec<(Identifier, usize)>,
+}
+
+impl SourceMap {
+    /// Create a new [`SourceMap`] from a list of `(name, code)` sources, in the order that they
+    /// are assembled.
+    #[must_use]
+    pub fn new(sources: &[(&str, &str)]) -> Self {
+        let mut line = 1;
+
+        let segments = sources
+            .iter()
+            .map(|&(name, code)| {
+                let start = line;
+                line += code.matches('\n').count() + 1;
+                (name.into(), start)
+            })
+            .collect();
+
+        Self { segments }
+    }
+    /// Is this [`SourceMap`] empty?
+    #[inline(always)]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+    /// Map a [`Position`] in the assembled script to the name of its source and the [`Position`]
+    /// within that source.
+    ///
+    /// Returns [`None`] if the position is [`NONE`][Position::NONE] or does not belong to any source.
+    #[must_use]
+    pub fn resolve(&self, pos: Position) -> Option<(&str, Position)> {
+        let line = pos.line()?;
+
+        self.segments
+            .iter()
+            .rev()
+            .find(|&&(.., start)| start <= line)
+            .map(|(name, start)| {
+                let line = (line - start + 1) as u16;
+                let column = pos.position().unwrap_or(0) as u16;
+                (name.as_str(), Position::new(line, column))
+            })
+    }
+}
@@ -0,0 +1,215 @@
+//! The `TypedMap` type.
+#![cfg(not(feature = "no_object"))]
+
+use crate::func::hashing::get_hasher;
+use crate::types::dynamic::Union;
+use crate::{Dynamic, Engine, Position, RhaiResultOf, StaticVec, ERR};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+/// A map keyed by values other than strings.
+///
+/// Keys can be `()`, booleans, characters, strings, integers, or custom types registered via
+/// [`Engine::register_type_as_map_key`]. Using any other type as key raises an error.
+///
+/// Unlike an object [`Map`][crate::Map], entries are not kept in any particular order.
+///
+/// Not available under `no_object`.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), Box<rhai::EvalAltResult>> {
+/// use rhai::{Engine, TypedMap, INT};
+///
+/// let engine = Engine::new();
+///
+/// let mut map = TypedMap::new();
+/// map.insert(&engine, (42 as INT).into(), "hello".into())?;
+///
+/// assert_eq!(map.get(&engine, &(42 as INT).into())?.unwrap().to_string(), "hello");
+/// assert!(map.get(&engine, &"42".into())?.is_none());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct TypedMap {
+    /// Entries grouped by the hash of their keys.
+    buckets: BTreeMap<u64, StaticVec<(Dynamic, Dynamic)>>,
+    /// Number of entries.
+    len: usize,
+}
+
+impl fmt::Debug for TypedMap {
+    #[cold]
+    #[inline(never)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl TypedMap {
+    /// Create a new empty [`TypedMap`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            buckets: BTreeMap::new(),
+            len: 0,
+        }
+    }
+    /// Get the number of entries in the [`TypedMap`].
+    #[inline(always)]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// Is the [`TypedMap`] empty?
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Get the value of a key, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type of the key cannot be used as a map key.
+    pub fn get(&self, engine: &Engine, key: &Dynamic) -> RhaiResultOf<Option<&Dynamic>> {
+        let key = &key.flatten_clone();
+        let hash = hash_key(engine, key)?;
+
+        Ok(self.buckets.get(&hash).and_then(|bucket| {
+            bucket
+                .iter()
+                .find(|(k, ..)| keys_equal(engine, k, key))
+                .map(|(.., v)| v)
+        }))
+    }
+    /// Does the [`TypedMap`] contain a key?
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type of the key cannot be used as a map key.
+    #[inline(always)]
+    pub fn contains_key(&self, engine: &Engine, key: &Dynamic) -> RhaiResultOf<bool> {
+        self.get(engine, key).map(|v| v.is_some())
+    }
+    /// Insert a value under a key, returning the previous value of the key, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type of the key cannot be used as a map key.
+    pub fn insert(
+        &mut self,
+        engine: &Engine,
+        key: Dynamic,
+        value: Dynamic,
+    ) -> RhaiResultOf<Option<Dynamic>> {
+        let key = key.flatten();
+        let hash = hash_key(engine, &key)?;
+        let bucket = self.buckets.entry(hash).or_default();
+
+        if let Some((.., v)) = bucket
+            .iter_mut()
+            .find(|(k, ..)| keys_equal(engine, k, &key))
+        {
+            return Ok(Some(std::mem::replace(v, value)));
+        }
+
+        bucket.push((key, value));
+        self.len += 1;
+        Ok(None)
+    }
+    /// Remove a key, returning its value, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type of the key cannot be used as a map key.
+    pub fn remove(&mut self, engine: &Engine, key: &Dynamic) -> RhaiResultOf<Option<Dynamic>> {
+        let key = &key.flatten_clone();
+        let hash = hash_key(engine, key)?;
+
+        let bucket = match self.buckets.get_mut(&hash) {
+            Some(bucket) => bucket,
+            None => return Ok(None),
+        };
+        let index = match bucket.iter().position(|(k, ..)| keys_equal(engine, k, key)) {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+
+        let (.., value) = bucket.remove(index);
+        if bucket.is_empty() {
+            self.buckets.remove(&hash);
+        }
+        self.len -= 1;
+        Ok(Some(value))
+    }
+    /// Remove all entries.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.buckets.clear();
+        self.len = 0;
+    }
+    /// Get an iterator over the keys and values of the [`TypedMap`].
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&Dynamic, &Dynamic)> {
+        self.buckets.values().flatten().map(|(k, v)| (k, v))
+    }
+}
+
+/// Calculate the hash of a map key.
+fn hash_key(engine: &Engine, key: &Dynamic) -> RhaiResultOf<u64> {
+    let hasher = &mut get_hasher();
+
+    match key.0 {
+        Union::Unit(..) | Union::Bool(..) | Union::Char(..) | Union::Str(..) | Union::Int(..) => {
+            key.hash(hasher)
+        }
+        Union::Variant(..) => match engine.map_key_types.get(&key.type_id()) {
+            Some((.., hash_fn)) => {
+                key.type_id().hash(hasher);
+                hash_fn(key).hash(hasher);
+            }
+            None => return Err(invalid_key_error(engine, key)),
+        },
+        _ => return Err(invalid_key_error(engine, key)),
+    }
+
+    Ok(hasher.finish())
+}
+
+/// Are two map keys equal?
+fn keys_equal(engine: &Engine, a: &Dynamic, b: &Dynamic) -> bool {
+    match (&a.0, &b.0) {
+        (Union::Unit(..), Union::Unit(..)) => true,
+        (Union::Bool(x, ..), Union::Bool(y, ..)) => x == y,
+        (Union::Char(x, ..), Union::Char(y, ..)) => x == y,
+        (Union::Str(x, ..), Union::Str(y, ..)) => x == y,
+        (Union::Int(x, ..), Union::Int(y, ..)) => x == y,
+        (Union::Variant(..), Union::Variant(..)) if a.type_id() == b.type_id() => engine
+            .map_key_types
+            .get(&a.type_id())
+            .map_or(false, |(eq_fn, ..)| eq_fn(a, b)),
+        _ => false,
+    }
+}
+
+/// Make an error for a value that cannot be used as a map key.
+#[cold]
+#[inline(never)]
+fn invalid_key_error(engine: &Engine, key: &Dynamic) -> Box<ERR> {
+    ERR::ErrorRuntime(
+        format!(
+            "Value of type '{}' cannot be used as a map key",
+            engine.map_type_name(key.type_name())
+        )
+        .into(),
+        Position::NONE,
+    )
+    .into()
+}
@@ -0,0 +1,99 @@
+//! Recursive traversal of [`Dynamic`] values.
+
+use crate::types::dynamic::Union;
+use crate::Dynamic;
+#[cfg(feature = "no_std")]
+use std::prelude::v1::*;
+
+/// A segment in the path to a value nested inside a [`Dynamic`], as passed to the visitor of
+/// [`Dynamic::walk`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum PathSegment {
+    /// Index of an element in an [`Array`][crate::Array].
+    ///
+    /// Not available under `no_index`.
+    #[cfg(not(feature = "no_index"))]
+    Index(usize),
+    /// Name of a property in an [object map][crate::Map].
+    ///
+    /// Not available under `no_object`.
+    #[cfg(not(feature = "no_object"))]
+    Key(crate::Identifier),
+}
+
+impl Dynamic {
+    /// Visit this value and every value nested inside it, together with the path leading to it.
+    ///
+    /// Values are visited in depth-first order, each one before the values nested inside it.
+    /// The path of this value itself is empty.
+    ///
+    /// Elements of [arrays][crate::Array] and properties of [object maps][crate::Map] are
+    /// visited recursively. All other values, including BLOB's and custom types, are leaves.
+    /// Shared values are visited via their contents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(not(feature = "no_index"))]
+    /// # #[cfg(not(feature = "no_object"))]
+    /// # {
+    /// use rhai::{Dynamic, Engine, PathSegment, INT};
+    ///
+    /// let engine = Engine::new();
+    ///
+    /// let value = engine.eval::<Dynamic>("#{ a: [1, 2], b: #{ c: 3 } }").unwrap();
+    ///
+    /// let mut sum = 0;
+    /// let mut leaves = Vec::new();
+    ///
+    /// value.walk(|path, v| {
+    ///     if let Ok(n) = v.as_int() {
+    ///         sum += n;
+    ///         leaves.push(path.to_vec());
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(sum, 6);
+    /// assert_eq!(leaves[1], [PathSegment::Key("a".into()), PathSegment::Index(1)]);
+    /// assert_eq!(leaves[2], [PathSegment::Key("b".into()), PathSegment::Key("c".into())]);
+    /// # }
+    /// ```
+    pub fn walk(&self, mut visitor: impl FnMut(&[PathSegment], &Dynamic)) {
+        walk_value(self, &mut Vec::new(), &mut visitor);
+    }
+}
+
+/// Visit a value and every value nested inside it.
+fn walk_value(
+    value: &Dynamic,
+    path: &mut Vec<PathSegment>,
+    visitor: &mut impl FnMut(&[PathSegment], &Dynamic),
+) {
+    #[cfg(not(feature = "no_closure"))]
+    if let Union::Shared(ref cell, ..) = value.0 {
+        return walk_value(&crate::func::locked_read(cell), path, visitor);
+    }
+
+    visitor(path, value);
+
+    match value.0 {
+        #[cfg(not(feature = "no_index"))]
+        Union::Array(ref arr, ..) => {
+            for (index, item) in arr.iter().enumerate() {
+                path.push(PathSegment::Index(index));
+                walk_value(item, path, visitor);
+                path.pop();
+            }
+        }
+        #[cfg(not(feature = "no_object"))]
+        Union::Map(ref map, ..) => {
+            for (key, item) in map.iter() {
+                path.push(PathSegment::Key(key.clone()));
+                walk_value(item, path, visitor);
+                path.pop();
+            }
+        }
+        _ => (),
+    }
+}
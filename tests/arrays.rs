@@ -64,6 +64,30 @@ fn test_arrays() -> Result<(), Box<EvalAltResult>> {
             .into_typed_array::<INT>()?,
         vec![] as Vec<INT>
     );
+    assert_eq!(
+        engine
+            .eval::<Dynamic>("let y = [1, 2, 3, 4, 5]; splice(y, 1, 2, [7, 8, 9])")?
+            .into_typed_array::<INT>()?,
+        vec![2, 3]
+    );
+    assert_eq!(
+        engine
+            .eval::<Dynamic>("let y = [1, 2, 3, 4, 5]; splice(y, -2, 99, [7]); y")?
+            .into_typed_array::<INT>()?,
+        vec![1, 2, 3, 7]
+    );
+    assert_eq!(
+        engine
+            .eval::<Dynamic>("let y = [1, 2, 3]; splice(y, 99, 1, [4])")?
+            .into_typed_array::<INT>()?,
+        vec![] as Vec<INT>
+    );
+    assert_eq!(
+        engine
+            .eval::<Dynamic>("let y = [1, 2, 3]; splice(y, -99, 1, [4]); y")?
+            .into_typed_array::<INT>()?,
+        vec![4, 2, 3]
+    );
 
     #[cfg(not(feature = "no_object"))]
     {
@@ -422,6 +446,106 @@ fn test_arrays_map_reduce() -> Result<(), Box<EvalAltResult>> {
         "
     )?);
 
+    assert!(engine.eval::<bool>(
+        "
+            let x = [1, 2, 3];
+            x.any(|v| v > 2)
+        "
+    )?);
+
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let x = [1, 2, 3, 4, 5];
+                x.count(|v| v > 2)
+            "
+        )?,
+        3
+    );
+
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let x = [1, 2, 3, 4, 5];
+                x.count(|v, i| v * i > 5)
+            "
+        )?,
+        3
+    );
+
+    assert_eq!(engine.eval::<INT>("[].count(|v| true)")?, 0);
+
+    #[cfg(not(feature = "no_closure"))]
+    {
+        assert_eq!(
+            engine.eval::<INT>(
+                "
+                    let n = 0;
+                    let x = [1, 2, 3, 4, 5];
+                    x.some(|v| { n += 1; v > 1 });
+                    n
+                "
+            )?,
+            2
+        );
+
+        assert_eq!(
+            engine.eval::<INT>(
+                "
+                    let n = 0;
+                    let x = [1, 2, 3, 4, 5];
+                    x.all(|v| { n += 1; v < 3 });
+                    n
+                "
+            )?,
+            3
+        );
+    }
+
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let x = [1, 2, 3, 4];
+                x.position(|v| v > 2)
+            "
+        )?,
+        2
+    );
+
+    engine.eval::<()>(
+        "
+            let x = [1, 2, 3, 4];
+            x.position(|v| v > 8)
+        ",
+    )?;
+
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let x = [1, 2, 3, 4];
+                x.find_map(|v| if v > 2 { v * v })
+            "
+        )?,
+        9
+    );
+
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let x = [(), (), 42];
+                x.find_map(|v, i| if i > 0 { v })
+            "
+        )?,
+        42
+    );
+
+    engine.eval::<()>(
+        "
+            let x = [1, 2, 3, 4];
+            x.find_map(|v| if v > 8 { v })
+        ",
+    )?;
+
     Ok(())
 }
 
@@ -435,3 +559,369 @@ fn test_arrays_elvis() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_arrays_concat_interleave() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine
+            .eval::<Array>("concat([1, 2], [3], [], [4, 5])")?
+            .into_iter()
+            .map(|v| v.as_int().unwrap())
+            .collect::<Vec<_>>(),
+        [1, 2, 3, 4, 5]
+    );
+    assert_eq!(
+        engine.eval::<INT>("let x = [1, 2]; let y = x.concat([3], [4]); x.len() + y.len()")?,
+        6
+    );
+    assert_eq!(
+        engine
+            .eval::<Array>("[1, 2, 3, 4].interleave([10, 20])")?
+            .into_iter()
+            .map(|v| v.as_int().unwrap())
+            .collect::<Vec<_>>(),
+        [1, 10, 2, 20]
+    );
+    assert_eq!(
+        engine
+            .eval::<Array>("[1, 2].interleave_longest([10, 20, 30, 40])")?
+            .into_iter()
+            .map(|v| v.as_int().unwrap())
+            .collect::<Vec<_>>(),
+        [1, 10, 2, 20, 30, 40]
+    );
+    assert_eq!(engine.eval::<INT>("([1, 2] + [3]).len()")?, 3);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_arrays_transpose() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let matrix = engine
+        .eval::<Array>("let m = [[1, 2, 3], [4, 5, 6]]; m.transpose()")?
+        .into_iter()
+        .map(|row| {
+            row.cast::<Array>()
+                .into_iter()
+                .map(|v| v.as_int().unwrap())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(matrix, [[1, 4], [2, 5], [3, 6]]);
+
+    assert_eq!(
+        engine.eval::<INT>("let m = [[1, 2], [3, 4]]; m.transpose().transpose()[1][0]")?,
+        3
+    );
+    assert!(engine.eval::<Array>("[].transpose()")?.is_empty());
+    assert!(engine.eval::<Array>("[[1, 2], [3]].transpose()").is_err());
+    assert!(engine.eval::<Array>("[[1, 2], 3].transpose()").is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_arrays_fill_resize() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<INT>("let x = [1, 2, 3]; x.fill(42); x.len() * 100 + x[1]")?,
+        342
+    );
+    assert_eq!(
+        engine.eval::<INT>("let x = [1, 2, 3]; x.resize(5, 42); x.len() * 100 + x[4]")?,
+        542
+    );
+    assert_eq!(
+        engine.eval::<INT>("let x = [1, 2, 3]; x.resize(2, 42); x.len() * 100 + x[1]")?,
+        202
+    );
+    assert!(engine
+        .eval::<Array>("let x = [1, 2, 3]; x.resize(-1, 0); x")?
+        .is_empty());
+
+    // Each new element is a separate copy
+    assert_eq!(
+        engine.eval::<INT>("let x = []; x.resize(3, [0]); x[0][0] = 42; x[1][0] + x[2][0]")?,
+        0
+    );
+
+    #[cfg(not(feature = "no_function"))]
+    {
+        assert_eq!(
+            engine.eval::<INT>("let x = [1, 2, 3]; x.resize_with(6, |i| i * i); x[5] + x[2]")?,
+            28
+        );
+        assert_eq!(
+            engine.eval::<INT>("let x = [1, 2, 3]; x.resize_with(1, |i| i); x.len()")?,
+            1
+        );
+        assert!(engine
+            .run("let x = []; x.resize_with(3, |i| i / 0)")
+            .is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_arrays_drain() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine
+            .eval::<Array>("let x = [1, 2, 3, 4, 5]; let y = x.drain(1..3); x + y")?
+            .into_iter()
+            .map(|v| v.as_int().unwrap())
+            .collect::<Vec<_>>(),
+        [1, 4, 5, 2, 3]
+    );
+
+    // Out-of-bounds ranges are clamped
+    assert_eq!(
+        engine.eval::<INT>("let x = [1, 2, 3]; let y = x.drain(1..=10); x.len() * 10 + y.len()")?,
+        12
+    );
+    assert!(engine
+        .eval::<Array>("let x = [1, 2, 3]; x.drain(5..10)")?
+        .is_empty());
+
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(
+        engine
+            .eval::<Array>(
+                "let x = [1, 2, 3, 4, 5, 6]; let y = x.drain_filter(|v| v % 2 == 0); y + x"
+            )?
+            .into_iter()
+            .map(|v| v.as_int().unwrap())
+            .collect::<Vec<_>>(),
+        [2, 4, 6, 1, 3, 5]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_arrays_insert_all_remove_range() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine
+            .eval::<Array>("let x = [1, 2, 3]; x.insert_all(1, [4, 5]); x.insert_all(-1, [6]); x")?
+            .into_iter()
+            .map(|v| v.as_int().unwrap())
+            .collect::<Vec<_>>(),
+        [1, 4, 5, 2, 6, 3]
+    );
+    assert_eq!(
+        engine
+            .eval::<Array>("let x = [1]; x.insert_all(99, [2, 3]); x.insert_all(-99, [0]); x")?
+            .into_iter()
+            .map(|v| v.as_int().unwrap())
+            .collect::<Vec<_>>(),
+        [0, 1, 2, 3]
+    );
+
+    assert_eq!(
+        engine
+            .eval::<Array>("let x = [1, 2, 3, 4, 5]; let y = x.remove_range(1..3); x + y")?
+            .into_iter()
+            .map(|v| v.as_int().unwrap())
+            .collect::<Vec<_>>(),
+        [1, 4, 5, 2, 3]
+    );
+    assert_eq!(
+        engine.eval::<INT>(
+            "let x = [1, 2, 3]; let y = x.remove_range(0..=2); x.len() * 10 + y.len()"
+        )?,
+        3
+    );
+    assert!(engine
+        .eval::<Array>("let x = [1, 2, 3]; x.remove_range(3..3)")?
+        .is_empty());
+    assert!(matches!(
+        *engine
+            .run("let x = [1, 2, 3]; x.remove_range(1..=3)")
+            .expect_err("should error"),
+        EvalAltResult::ErrorArrayBounds(3, 3, ..)
+    ));
+    assert!(matches!(
+        *engine
+            .run("let x = [1, 2, 3]; x.remove_range(4..5)")
+            .expect_err("should error"),
+        EvalAltResult::ErrorArrayBounds(3, 4, ..)
+    ));
+    assert!(matches!(
+        *engine
+            .run("let x = [1, 2, 3]; x.remove_range(-2..3)")
+            .expect_err("should error"),
+        EvalAltResult::ErrorArrayBounds(3, -2, ..)
+    ));
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_function"))]
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_arrays_scan() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let eval = |script: &str| -> Result<Vec<INT>, Box<EvalAltResult>> {
+        Ok(engine
+            .eval::<Array>(script)?
+            .into_iter()
+            .map(|v| v.as_int().unwrap())
+            .collect())
+    };
+
+    assert_eq!(
+        eval("[1, 2, 3, 4, 5].scan(|r, v| v + (r ?? 0))")?,
+        [1, 3, 6, 10, 15]
+    );
+    assert_eq!(
+        eval("[1, 2, 3, 4, 5].scan(|r, v| v + r, 100)")?,
+        [101, 103, 106, 110, 115]
+    );
+    assert_eq!(
+        eval("[1, 2, 3, 4, 5].scan(|r, v, i| v + i + r, 0)")?,
+        [1, 4, 9, 16, 25]
+    );
+    assert_eq!(
+        eval(
+            r#"
+                fn process(r, x) { x * r }
+                [1, 2, 3, 4].scan("process", 1)
+            "#
+        )?,
+        [1, 2, 6, 24]
+    );
+    assert!(eval("[].scan(|r, v| v + r, 0)")?.is_empty());
+
+    // The original array is unchanged
+    assert_eq!(
+        eval("let x = [1, 2, 3]; let y = x.scan(|r, v| v + r, 0); x + y")?,
+        [1, 2, 3, 1, 3, 6]
+    );
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_function"))]
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_arrays_take_skip() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let eval = |script: &str| -> Result<Vec<INT>, Box<EvalAltResult>> {
+        Ok(engine
+            .eval::<Array>(script)?
+            .into_iter()
+            .map(|v| v.as_int().unwrap())
+            .collect())
+    };
+
+    assert_eq!(eval("[1, 2, 3, 4, 5].take(2)")?, [1, 2]);
+    assert_eq!(eval("[1, 2, 3, 4, 5].take(10)")?, [1, 2, 3, 4, 5]);
+    assert!(eval("[1, 2, 3, 4, 5].take(0)")?.is_empty());
+    assert!(eval("[1, 2, 3, 4, 5].take(-1)")?.is_empty());
+
+    assert_eq!(eval("[1, 2, 3, 4, 5].skip(2)")?, [3, 4, 5]);
+    assert!(eval("[1, 2, 3, 4, 5].skip(10)")?.is_empty());
+    assert_eq!(eval("[1, 2, 3, 4, 5].skip(-1)")?, [1, 2, 3, 4, 5]);
+
+    assert_eq!(eval("[1, 2, 3, 4, 1, 2].take_while(|v| v < 3)")?, [1, 2]);
+    assert_eq!(
+        eval("[1, 2, 3, 4, 1, 2].take_while(|v, i| v > i)")?,
+        [1, 2, 3, 4]
+    );
+    assert_eq!(
+        eval("[1, 2, 3, 4, 1, 2].skip_while(|v| v < 3)")?,
+        [3, 4, 1, 2]
+    );
+    assert_eq!(eval("[1, 2, 3, 4, 1, 2].skip_while(|v, i| v > i)")?, [1, 2]);
+    assert_eq!(
+        eval(
+            r#"
+                fn small(x) { x < 3 }
+                let x = [1, 2, 3, 4, 1, 2];
+                x.take_while("small") + x.skip_while("small")
+            "#
+        )?,
+        [1, 2, 3, 4, 1, 2]
+    );
+    assert!(eval("[].take_while(|v| true)")?.is_empty());
+    assert!(eval("[1, 2].skip_while(|v| true)")?.is_empty());
+
+    // The original array is unchanged, even for constants
+    assert_eq!(
+        eval(
+            "
+                const x = [1, 2, 3];
+                let y = x.take(1);
+                let z = x.skip_while(|v| v < 3);
+                x + y + z
+            "
+        )?,
+        [1, 2, 3, 1, 3]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_arrays_aggregates() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>("min([3, 1, 4, 1, 5])")?, 1);
+    assert_eq!(engine.eval::<INT>("max([3, 1, 4, 1, 5])")?, 5);
+    assert_eq!(engine.eval::<INT>("sum([3, 1, 4, 1, 5])")?, 14);
+    assert_eq!(engine.eval::<INT>("sum([])")?, 0);
+    assert_eq!(engine.eval::<()>("min([])")?, ());
+    assert_eq!(engine.eval::<()>("max([])")?, ());
+
+    #[cfg(not(feature = "no_float"))]
+    {
+        use rhai::FLOAT;
+
+        assert_eq!(engine.eval::<FLOAT>("min([2.5, -1.0, 3.0])")?, -1.0);
+        assert_eq!(engine.eval::<FLOAT>("max([2.5, -1.0, 3.0])")?, 3.0);
+        assert_eq!(engine.eval::<FLOAT>("sum([2.5, -1.0, 3.0])")?, 4.5);
+        assert_eq!(engine.eval::<FLOAT>("avg([1, 2, 3, 4])")?, 2.5);
+        assert_eq!(engine.eval::<FLOAT>("avg([1.0, 2.0])")?, 1.5);
+        assert_eq!(engine.eval::<()>("avg([])")?, ());
+
+        assert!(matches!(
+            *engine
+                .eval::<INT>("sum([1, 2.0])")
+                .expect_err("should error"),
+            EvalAltResult::ErrorMismatchDataType(..)
+        ));
+    }
+
+    assert!(matches!(
+        *engine
+            .eval::<INT>(r#"max([1, "x"])"#)
+            .expect_err("should error"),
+        EvalAltResult::ErrorMismatchDataType(..)
+    ));
+
+    #[cfg(not(feature = "unchecked"))]
+    assert!(matches!(
+        *engine
+            .eval::<INT>(&format!("sum([{}, 1])", INT::MAX))
+            .expect_err("should error"),
+        EvalAltResult::ErrorArithmetic(..)
+    ));
+
+    Ok(())
+}
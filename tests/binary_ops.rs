@@ -142,6 +142,72 @@ fn test_binary_ops_null_coalesce() -> Result<(), Box<EvalAltResult>> {
 
     assert_eq!(engine.eval::<INT>("let x = 42; x ?? 123")?, 42);
     assert_eq!(engine.eval::<INT>("let x = (); x ?? 123")?, 123);
+    assert_eq!(
+        engine.eval::<INT>("let x = (); let y = (); x ?? y ?? 42")?,
+        42
+    );
+    assert_eq!(engine.eval::<INT>("let x = (); x ?? 40 + 2")?, 42);
+    assert!(engine.eval::<bool>("let x = (); x ?? 0 < 1")?);
+    assert_eq!(engine.eval::<INT>("let x = 42; x ?? 0 == 0")?, 42);
+    assert_eq!(engine.eval::<INT>("let x = 42; x ?? 0 < 1")?, 42);
+    assert!(engine.eval::<bool>("() ?? 1 + 1 == 2")?);
+    assert_eq!(engine.eval::<INT>("(() ?? 1) + 1")?, 2);
+    assert_eq!(
+        engine.eval::<INT>("let x = 42; let n = 0; x ?? { n += 1; 0 } ?? { n += 1; 0 }; n")?,
+        0
+    );
+
+    #[cfg(not(feature = "no_object"))]
+    {
+        assert_eq!(engine.eval::<INT>("let x = #{}; x.a ?? 42")?, 42);
+        assert_eq!(engine.eval::<INT>("let x = (); x?.a ?? 42")?, 42);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_binary_ops_custom_type() -> Result<(), Box<EvalAltResult>> {
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+    struct Money(INT);
+
+    let mut engine = Engine::new();
+
+    engine.register_fn("money", Money);
+
+    engine
+        .register_type_ops::<Money>()
+        .add(|a, b| Money(a.0 + b.0))
+        .sub(|a, b| Money(a.0 - b.0))
+        .neg(|a| Money(-a.0))
+        .eq(|a, b| a == b)
+        .cmp(|a, b| a.cmp(b));
+
+    assert_eq!(engine.eval::<Money>("money(40) + money(2)")?, Money(42));
+    assert_eq!(engine.eval::<Money>("money(40) - money(2)")?, Money(38));
+    assert_eq!(engine.eval::<Money>("-money(42)")?, Money(-42));
+    assert_eq!(
+        engine.eval::<Money>("let x = money(40); x += money(2); x")?,
+        Money(42)
+    );
+
+    assert!(engine.eval::<bool>("money(1) == money(1)")?);
+    assert!(!engine.eval::<bool>("money(1) != money(1)")?);
+    assert!(engine.eval::<bool>("money(1) != money(2)")?);
+
+    for (op, expected) in [
+        ("<", [true, false, false]),
+        ("<=", [true, true, false]),
+        (">", [false, false, true]),
+        (">=", [false, true, true]),
+    ] {
+        for (x, expected) in [1, 2, 3].iter().zip(expected) {
+            let script = format!("money({}) {} money(2)", x, op);
+            assert_eq!(engine.eval::<bool>(&script)?, expected, "{}", script);
+        }
+    }
+
+    assert!(engine.eval::<bool>("money(1) * money(2)").is_err());
 
     Ok(())
 }
@@ -272,3 +272,126 @@ fn test_blobs_write_string() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+fn test_blobs_slice() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<Blob>("let x = blob(); x += 1; x += 2; x += 3; x += 4; x[1..3]")?,
+        [2, 3]
+    );
+    assert_eq!(
+        engine.eval::<Blob>("let x = blob(); x += 1; x += 2; x += 3; x += 4; x[1..=3]")?,
+        [2, 3, 4]
+    );
+    assert_eq!(
+        engine.eval::<Blob>("let x = blob(4, 1); x[4..4]")?,
+        Blob::new()
+    );
+    assert_eq!(engine.eval::<INT>("let x = blob(4, 1); x[1..3][0]")?, 1);
+
+    // Slices are copies
+    assert_eq!(
+        engine.eval::<Blob>("let x = blob(3, 1); let y = x[0..2]; y += 9; x")?,
+        [1, 1, 1]
+    );
+
+    assert!(matches!(
+        *engine
+            .eval::<Blob>("let x = blob(4, 1); x[2..5]")
+            .expect_err("should error"),
+        EvalAltResult::ErrorArrayBounds(4, 5, ..)
+    ));
+    assert!(matches!(
+        *engine
+            .eval::<Blob>("let x = blob(4, 1); x[1..=4]")
+            .expect_err("should error"),
+        EvalAltResult::ErrorArrayBounds(4, 4, ..)
+    ));
+
+    // Assigning to a slice replaces that part of the BLOB
+    assert_eq!(
+        engine.eval::<Blob>("let x = blob(4, 1); x[1..3] = blob(2, 9); x")?,
+        [1, 9, 9, 1]
+    );
+    assert_eq!(
+        engine.eval::<Blob>("let x = blob(4, 1); x[1..3] = blob(3, 9); x")?,
+        [1, 9, 9, 9, 1]
+    );
+    assert_eq!(
+        engine.eval::<Blob>("let x = blob(4, 1); x[1..=2] = blob(); x")?,
+        [1, 1]
+    );
+    assert_eq!(
+        engine.eval::<Blob>("let x = blob(4, 1); x[0..1] += 7; x")?,
+        [1, 7, 1, 1, 1]
+    );
+    assert_eq!(
+        engine.eval::<Blob>("let x = blob(4, 1); x[1..3][0] = 5; x")?,
+        [1, 5, 1, 1]
+    );
+    assert!(matches!(
+        *engine
+            .run("let x = blob(4, 1); x[1..3] = 42;")
+            .expect_err("should error"),
+        EvalAltResult::ErrorMismatchDataType(..)
+    ));
+
+    #[cfg(not(feature = "no_object"))]
+    {
+        assert_eq!(
+            engine.eval::<Blob>("let x = blob(4, 1); x[1..3].push(5); x")?,
+            [1, 1, 1, 5, 1]
+        );
+        assert_eq!(
+            engine.eval::<Blob>("let x = blob(2, 1); x.extend(blob(1, 2)); x")?,
+            [1, 1, 2]
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "encoding")]
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_blobs_encoding() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<String>("let x = blob(); x += 1; x += 2; x += 3; x += 4; x.to_base64()")?,
+        "AQIDBA=="
+    );
+    assert_eq!(
+        engine.eval::<String>("blob(3, 0xff).to_base64_url()")?,
+        "____"
+    );
+    assert_eq!(engine.eval::<String>("blob(3, 0xff).to_base64()")?, "////");
+    assert_eq!(
+        engine.eval::<Blob>(r#"from_base64("AQIDBA==")"#)?,
+        [1, 2, 3, 4]
+    );
+    assert_eq!(
+        engine.eval::<Blob>(r#"from_base64_url("____")"#)?,
+        [255, 255, 255]
+    );
+    assert!(engine.eval::<Blob>(r#"from_base64("AQ!D")"#).is_err());
+
+    assert_eq!(engine.eval::<String>("blob(3, 0xab).to_hex()")?, "ababab");
+    assert_eq!(
+        engine.eval::<Blob>(r#"from_hex("01aBff")"#)?,
+        [1, 0xab, 0xff]
+    );
+    assert_eq!(engine.eval::<Blob>(r#"from_hex("")"#)?, []);
+
+    let err = engine
+        .eval::<Blob>("\n\n   from_hex(\"123\")")
+        .expect_err("should error");
+    assert!(matches!(*err, EvalAltResult::ErrorRuntime(..)));
+    #[cfg(not(feature = "no_position"))]
+    assert_eq!(err.position().line(), Some(3));
+    assert!(engine.eval::<Blob>(r#"from_hex("0g")"#).is_err());
+
+    Ok(())
+}
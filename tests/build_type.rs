@@ -145,3 +145,57 @@ fn build_type() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+fn build_type_derive() -> Result<(), Box<EvalAltResult>> {
+    #[derive(Debug, Clone, PartialEq, Eq, CustomType)]
+    pub struct Point {
+        pub x: INT,
+        pub y: INT,
+        #[rhai(readonly)]
+        pub id: INT,
+        #[rhai(skip)]
+        pub cache: INT,
+        hidden: INT,
+        pub r#type: String,
+    }
+
+    let mut engine = Engine::new();
+    engine
+        .build_type::<Point>()
+        .register_fn("new_point", || Point {
+            x: 1,
+            y: 2,
+            id: 42,
+            cache: 0,
+            hidden: 0,
+            r#type: "origin".into(),
+        });
+
+    assert_eq!(engine.eval::<String>("type_of(new_point())")?, "Point");
+    assert_eq!(
+        engine.eval::<INT>("let p = new_point(); p.x += 10; p.x + p.y + p.id")?,
+        55
+    );
+    assert_eq!(
+        engine.eval::<String>(r#"let p = new_point(); p.type = "moved"; p.type"#)?,
+        "moved"
+    );
+    assert_eq!(
+        engine.eval::<Point>("let p = new_point(); p.y = 5; p")?,
+        Point {
+            x: 1,
+            y: 5,
+            id: 42,
+            cache: 0,
+            hidden: 0,
+            r#type: "origin".into(),
+        }
+    );
+
+    assert!(engine.eval::<()>("let p = new_point(); p.id = 0;").is_err());
+    assert!(engine.eval::<INT>("new_point().cache").is_err());
+    assert!(engine.eval::<INT>("new_point().hidden").is_err());
+
+    Ok(())
+}
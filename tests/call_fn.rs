@@ -139,6 +139,48 @@ fn test_call_fn_args() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[test]
+fn test_call_fn_prepared() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+    let mut scope = Scope::new();
+
+    scope.push("scale", 2 as INT);
+
+    let ast = engine.compile("fn step(x, dt) { let y = x + dt * scale; y }")?;
+
+    let mut call = engine.prepare_call(&ast, "step", 2)?;
+    assert_eq!(call.fn_name(), "step");
+    assert_eq!(call.num_params(), 2);
+
+    let mut args = [Dynamic::UNIT, Dynamic::UNIT];
+    let mut x = 0 as INT;
+
+    for _ in 0..10 {
+        args[0] = x.into();
+        args[1] = (1 as INT).into();
+        x = call.call::<INT>(&mut scope, &mut args)?;
+    }
+
+    assert_eq!(x, 20);
+    assert_eq!(scope.len(), 1);
+
+    assert!(matches!(
+        *call
+            .call_raw(&mut scope, &mut [Dynamic::UNIT])
+            .expect_err("should error"),
+        EvalAltResult::ErrorFunctionNotFound(..)
+    ));
+
+    assert!(matches!(
+        *engine
+            .prepare_call(&ast, "step", 1)
+            .expect_err("should error"),
+        EvalAltResult::ErrorFunctionNotFound(..)
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn test_call_fn_private() -> Result<(), Box<EvalAltResult>> {
     let engine = Engine::new();
@@ -157,6 +199,38 @@ fn test_call_fn_private() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[cfg(not(feature = "no_module"))]
+#[test]
+fn test_call_fn_private_in_module() -> Result<(), Box<EvalAltResult>> {
+    let script = "
+        private fn double(x) { x * 2 }
+        fn calc(x) { double(x) + 1 }
+    ";
+
+    let mut engine = Engine::new();
+
+    let module_ast = engine.compile(script)?;
+    let module = rhai::Module::eval_ast_as_new(Scope::new(), &module_ast, &engine)?;
+    let mut resolver = rhai::module_resolvers::StaticModuleResolver::new();
+    resolver.insert("helpers", module);
+    engine.set_module_resolver(resolver);
+
+    assert_eq!(
+        engine.eval::<INT>(r#"import "helpers" as h; h::calc(20)"#)?,
+        41
+    );
+    assert!(engine
+        .eval::<INT>(r#"import "helpers" as h; h::double(21)"#)
+        .is_err());
+
+    // The private helper can be tested directly via the module script's AST
+    let mut scope = Scope::new();
+    let r = engine.call_fn::<INT>(&mut scope, &module_ast, "double", (21 as INT,))?;
+    assert_eq!(r, 42);
+
+    Ok(())
+}
+
 #[test]
 #[cfg(not(feature = "no_object"))]
 fn test_fn_ptr_raw() -> Result<(), Box<EvalAltResult>> {
@@ -432,3 +432,69 @@ fn test_closures_callback() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[cfg(not(feature = "no_index"))]
+#[cfg(not(feature = "no_object"))]
+#[test]
+fn test_closures_block_arg() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<INT>("let y = [1, 2, 3].map { |x| x * 2 }; y[0] + y[1] + y[2]")?,
+        12
+    );
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let y = [1, 2, 3].map { |x|
+                    let z = x + 1;
+                    z * z
+                };
+                y[0] + y[1] + y[2]
+            "
+        )?,
+        29
+    );
+    assert_eq!(
+        engine.eval::<INT>("[1, 2, 3].filter { |x| x > 1 }.len()")?,
+        2
+    );
+    assert_eq!(
+        engine.eval::<INT>("let z = 10; [1].map { |x| x + z }[0]")?,
+        11
+    );
+    assert_eq!(
+        engine.eval::<INT>("fn apply(x, f) { f.call(x) } apply(20) { |x| x + 1 }")?,
+        21
+    );
+    assert_eq!(
+        engine.eval::<INT>("fn run(f) { f.call() } run { || 42 } + run() { || 1 }")?,
+        43
+    );
+
+    // A block not starting with a closure is not a block argument
+    assert_eq!(
+        engine.eval::<INT>("let a = [1]; if a.len() > 0 { 42 } else { 0 }")?,
+        42
+    );
+    assert_eq!(
+        engine.eval::<INT>("fn f() { true } if f() { 1 } else { 2 }")?,
+        1
+    );
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                fn f() { true }
+                let g = if f() { |x| x + 1 } else { |x| x - 1 };
+                g.call(1)
+            "
+        )?,
+        2
+    );
+    assert_eq!(
+        engine.eval::<INT>("if ([1, 2].filter { |x| x > 1 }).len() == 1 { 42 } else { 0 }")?,
+        42
+    );
+
+    Ok(())
+}
@@ -316,6 +316,45 @@ fn test_custom_syntax_raw() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[test]
+fn test_custom_syntax_raw_block_value() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.register_custom_syntax_with_state_raw(
+        "twice",
+        |stream, _, _| match stream.len() {
+            1 => Ok(Some("$block$".into())),
+            _ => Ok(None),
+        },
+        false,
+        |context, inputs, _| {
+            assert!(inputs[0].is_block());
+
+            let x = context.eval_expression_tree(&inputs[0])?.as_int().unwrap();
+            let y = context.eval_expression_tree(&inputs[0])?.as_int().unwrap();
+            Ok((x + y).into())
+        },
+    );
+
+    // The value of the last statement in the block is returned, and changes to existing
+    // variables are kept
+    assert_eq!(
+        engine
+            .eval::<INT>("let x = 1; let y = twice { let z = x; x += 1; z * 10 }; x * 100 + y")?,
+        330
+    );
+
+    // Variables defined inside the block do not leak out
+    assert!(matches!(
+        *engine
+            .run("twice { let z = 1; z }; z")
+            .expect_err("should error"),
+        EvalAltResult::ErrorVariableNotFound(..)
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn test_custom_syntax_raw2() -> Result<(), Box<EvalAltResult>> {
     let mut engine = Engine::new();
@@ -350,3 +389,94 @@ fn test_custom_syntax_raw2() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+fn test_custom_syntax_push_var() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.register_custom_syntax(
+        ["using", "$ident$", "=", "$expr$"],
+        true,
+        |context, inputs| {
+            let name = inputs[0].get_string_value().unwrap().to_string();
+            let value = context.eval_expression_tree(&inputs[1])?;
+            context.push_var(name, value);
+            Ok(Dynamic::UNIT)
+        },
+    )?;
+    engine.register_custom_syntax(
+        ["fixed", "$ident$", "=", "$expr$"],
+        true,
+        |context, inputs| {
+            let name = inputs[0].get_string_value().unwrap().to_string();
+            let value = context.eval_expression_tree(&inputs[1])?;
+            context.push_constant(name, value);
+            Ok(Dynamic::UNIT)
+        },
+    )?;
+
+    assert_eq!(engine.eval::<INT>("using x = 40; x + 2")?, 42);
+    assert_eq!(
+        engine.eval::<INT>("let x = 1; using y = x + 1; let z = 3; x + y + z")?,
+        6
+    );
+    assert_eq!(
+        engine.eval::<INT>("let y = 0; { using x = 42; y = x; } y")?,
+        42
+    );
+    assert!(matches!(
+        *engine.run("{ using x = 42; } x").expect_err("should error"),
+        EvalAltResult::ErrorVariableNotFound(..)
+    ));
+    assert_eq!(engine.eval::<INT>("fixed x = 42; x")?, 42);
+    assert!(matches!(
+        *engine
+            .run("fixed x = 42; x = 0;")
+            .expect_err("should error"),
+        EvalAltResult::ErrorAssignmentToConstant(..)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_custom_syntax_eval_with_scope() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.register_custom_syntax(
+        ["bind", "$ident$", "=", "$expr$", "$block$"],
+        false,
+        |context, inputs| {
+            let name = inputs[0].get_string_value().unwrap().to_string();
+            let value = context.eval_expression_tree(&inputs[1])?;
+            let mut scope = Scope::new();
+            scope.push_dynamic(name, value);
+            context.eval_expression_tree_with_scope(&inputs[2], &mut scope)
+        },
+    )?;
+
+    assert_eq!(engine.eval::<INT>("bind x = 40 { x + 2 }")?, 42);
+    assert_eq!(engine.eval::<INT>("let y = 2; bind x = 40 { x + y }")?, 42);
+
+    // The binding shadows an existing variable only within the expression
+    assert_eq!(
+        engine.eval::<INT>("let x = 1; let y = bind x = 40 { x + 1 }; x + y")?,
+        42
+    );
+    assert!(matches!(
+        *engine.run("bind x = 1 { x }; x").expect_err("should error"),
+        EvalAltResult::ErrorVariableNotFound(..)
+    ));
+
+    // Changes are written back to the overlay scope
+    engine.register_custom_syntax(["bump", "$block$"], false, |context, inputs| {
+        let mut scope = Scope::new();
+        scope.push("n", 41 as INT);
+        context.eval_expression_tree_with_scope(&inputs[0], &mut scope)?;
+        Ok(scope.get_value::<INT>("n").unwrap().into())
+    })?;
+
+    assert_eq!(engine.eval::<INT>("bump { n += 1 }")?, 42);
+
+    Ok(())
+}
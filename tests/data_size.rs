@@ -55,6 +55,12 @@ fn test_max_string_size() -> Result<(), Box<EvalAltResult>> {
         EvalAltResult::ErrorDataTooLarge(..)
     ));
 
+    assert!(matches!(
+        *engine.run(r#""-" * 11"#).expect_err("should error"),
+        EvalAltResult::ErrorDataTooLarge(..)
+    ));
+    assert_eq!(engine.eval::<String>(r#""-" * 10"#)?, "----------");
+
     engine.set_max_string_size(0);
 
     assert_eq!(
@@ -352,3 +358,44 @@ fn test_max_map_size() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(not(feature = "no_index"))]
+#[cfg(not(feature = "no_object"))]
+fn test_max_data_size_growth() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+    engine.set_max_array_size(10);
+    #[cfg(not(feature = "no_object"))]
+    engine.set_max_map_size(10);
+
+    for script in [
+        "let x = []; loop { x.push(42); }",
+        "let x = []; loop { x.insert(0, 42); }",
+        "let x = []; loop { x += 42; }",
+        "let x = []; loop { x.splice(0, 0, [42]); }",
+        "let x = [[]]; loop { x[0].push(42); }",
+        "let x = [[], 1, 2, 3, 4, 5, 6, 7, 8]; x[0] += [1, 2];",
+    ] {
+        let err = engine.run(script).expect_err("should error");
+
+        match *err {
+            EvalAltResult::ErrorDataTooLarge(ref typ, ..) => assert_eq!(typ, "Size of array (11)"),
+            _ => panic!("{script}: expected ErrorDataTooLarge but gets {err}"),
+        }
+    }
+
+    #[cfg(not(feature = "no_object"))]
+    for script in [
+        r#"let x = #{}; let n = 0; loop { x["k" + n] = n; n += 1; }"#,
+        "let x = #{a: #{}}; let n = 0; loop { x.a[`${n}`] = n; n += 1; }",
+    ] {
+        match *engine.run(script).expect_err("should error") {
+            EvalAltResult::ErrorDataTooLarge(ref typ, ..) => {
+                assert_eq!(typ, "Size of object map (11)")
+            }
+            ref err => panic!("{script}: expected ErrorDataTooLarge but gets {err}"),
+        }
+    }
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use rhai::{Engine, EvalAltResult, LexError, ParseErrorType, Scope, INT};
+use rhai::{Engine, EvalAltResult, LexError, ParseErrorType, Scope, SourceMap, INT};
 
 #[test]
 fn test_eval() -> Result<(), Box<EvalAltResult>> {
@@ -176,3 +176,128 @@ fn test_eval_disabled() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[cfg(not(feature = "no_position"))]
+#[test]
+fn test_eval_compile_sources() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let sources = [
+        ("header", "let x = 40;\n// comment without newline"),
+        ("body", "let y = x + 2;\ny"),
+    ];
+    let ast = engine.compile_sources(&sources)?;
+    assert_eq!(engine.eval_ast::<INT>(&ast)?, 42);
+
+    let sources = [("header", "let x = 40;"), ("body", "x + 2;\nfoo(x)")];
+    let ast = engine.compile_sources(&sources)?;
+    let err = engine.eval_ast::<INT>(&ast).expect_err("should error");
+    let (name, pos) = ast.source_map().unwrap().resolve(err.position()).unwrap();
+    assert_eq!((name, pos.line()), ("body", Some(2)));
+
+    let sources = [("header", "let x = 40;"), ("body", "x +")];
+    let err = engine.compile_sources(&sources).expect_err("should error");
+    let (name, ..) = SourceMap::new(&sources).resolve(err.position()).unwrap();
+    assert_eq!(name, "body");
+
+    Ok(())
+}
+
+#[test]
+fn test_eval_with_stats() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let (result, stats) = engine.eval_with_stats::<INT>("let x = 40; let y = 2; x + y");
+
+    assert_eq!(result?, 42);
+    assert_eq!(stats.peak_scope_size, 2);
+    assert_eq!(stats.max_call_depth, 0);
+    #[cfg(not(feature = "unchecked"))]
+    assert!(stats.operations > 0);
+
+    #[cfg(not(feature = "no_function"))]
+    {
+        let (result, stats) = engine.eval_with_stats::<INT>(
+            "
+                fn sum(n) { if n <= 0 { 0 } else { n + sum(n - 1) } }
+                sum(5)
+            ",
+        );
+
+        assert_eq!(result?, 15);
+        assert_eq!(stats.max_call_depth, 6);
+        assert!(stats.fn_calls >= 6);
+    }
+
+    let (result, stats) = engine.eval_with_stats::<()>("let x = 1; let y = 2; let z = 3;");
+    assert!(result.is_ok());
+    assert_eq!(stats.peak_scope_size, 3);
+
+    let (result, stats) = engine.eval_with_stats::<INT>("foo(42)");
+    assert!(result.is_err());
+    assert_eq!(stats.fn_calls, 0);
+
+    let (result, ..) = engine.eval_with_stats::<INT>("let x = ;");
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_eval_run_returning() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(engine.run_returning::<INT>("let x = 40; x + 2")?, Some(42));
+    assert_eq!(engine.run_returning::<INT>("let x = 40; x + 2;")?, Some(42));
+    assert_eq!(engine.run_returning::<INT>("let x = 40;")?, None);
+    assert_eq!(engine.run_returning::<INT>("let x = 40; x += 2;")?, None);
+    assert_eq!(engine.run_returning::<INT>("return 42;")?, Some(42));
+    assert_eq!(
+        engine.run_returning::<INT>("let x = 40; if x > 0 { x + 2 } else { 0 }")?,
+        Some(42)
+    );
+    assert_eq!(
+        engine.run_returning::<INT>("let x = 40; if x < 0 { x + 2 }")?,
+        None
+    );
+    assert_eq!(
+        engine.run_returning::<INT>("let x = 2; switch x { 1 => 0, 2 => 42, _ => 1 }")?,
+        Some(42)
+    );
+    assert_eq!(
+        engine.run_returning::<INT>("let x = 2; switch x { 1 => 0 }")?,
+        None
+    );
+    assert_eq!(
+        engine.run_returning::<INT>("{ let x = 40; x + 2 }")?,
+        Some(42)
+    );
+    assert_eq!(engine.run_returning::<INT>("{ let x = 40; }")?, None);
+    assert!(engine
+        .run_returning::<INT>("if true { \"hello\" }")
+        .is_err());
+    assert_eq!(
+        engine.run_returning::<String>(r#"to_string(42)"#)?,
+        Some("42".into())
+    );
+    assert_eq!(engine.run_returning::<INT>("")?, None);
+    assert_eq!(engine.run_returning::<INT>("let x = 40; print(x);")?, None);
+    #[cfg(not(feature = "no_index"))]
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.run_returning::<INT>("let a = []; a.push(1);")?, None);
+
+    assert!(matches!(
+        *engine
+            .run_returning::<INT>(r#""hello""#)
+            .expect_err("should error"),
+        EvalAltResult::ErrorMismatchOutputType(..)
+    ));
+    assert!(matches!(
+        *engine
+            .run_returning::<INT>("throw 42;")
+            .expect_err("should error"),
+        EvalAltResult::ErrorRuntime(..)
+    ));
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use rhai::{Engine, EvalAltResult, Scope, INT};
+use rhai::{Engine, EvalAltResult, ExpressionOptions, Scope, INT};
 
 #[test]
 fn test_expressions() -> Result<(), Box<EvalAltResult>> {
@@ -65,6 +65,60 @@ fn test_expressions() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[test]
+fn test_expressions_with_options() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+    let scope = Scope::new();
+
+    let ast = engine.compile_expression_with_options(
+        &scope,
+        "let x = 40; const y = 2; x + y",
+        ExpressionOptions::VARIABLES,
+    )?;
+    assert_eq!(engine.eval_ast::<INT>(&ast)?, 42);
+
+    assert!(engine
+        .compile_expression_with_options(
+            &scope,
+            "let x = 40; x += 2; x",
+            ExpressionOptions::VARIABLES
+        )
+        .is_err());
+    assert!(engine
+        .compile_expression_with_options(&scope, "let x = 40", ExpressionOptions::VARIABLES)
+        .is_err());
+    assert!(engine
+        .compile_expression_with_options(&scope, "let x = 40 x", ExpressionOptions::VARIABLES)
+        .is_err());
+
+    let ast = engine.compile_expression_with_options(
+        &scope,
+        "let x = 40; x += 2; x",
+        ExpressionOptions::VARIABLES | ExpressionOptions::ASSIGNMENTS,
+    )?;
+    assert_eq!(engine.eval_ast::<INT>(&ast)?, 42);
+
+    let script = "let x = 0; for i in 0..7 { x += i; } while x < 42 { x += 1 } x";
+
+    assert!(engine
+        .compile_expression_with_options(
+            &scope,
+            script,
+            ExpressionOptions::VARIABLES | ExpressionOptions::ASSIGNMENTS
+        )
+        .is_err());
+
+    let ast = engine.compile_expression_with_options(&scope, script, ExpressionOptions::all())?;
+    assert_eq!(engine.eval_ast::<INT>(&ast)?, 42);
+
+    // No statements allowed
+    assert!(engine
+        .compile_expression_with_options(&scope, "let x = 42; x", ExpressionOptions::empty())
+        .is_err());
+
+    Ok(())
+}
+
 /// This example taken from https://github.com/rhaiscript/rhai/issues/115
 #[test]
 #[cfg(not(feature = "no_object"))]
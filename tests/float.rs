@@ -1,5 +1,5 @@
 #![cfg(not(feature = "no_float"))]
-use rhai::{Engine, EvalAltResult, FLOAT};
+use rhai::{Dynamic, Engine, EvalAltResult, FLOAT};
 
 const EPSILON: FLOAT = 0.000_000_000_1;
 
@@ -92,3 +92,81 @@ fn test_float_func() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+fn test_float_coerce() -> Result<(), Box<EvalAltResult>> {
+    assert_eq!(Dynamic::from_int(42).to_float()?, 42.0);
+    assert_eq!(Dynamic::from_float(1.5).to_float()?, 1.5);
+    assert_eq!(Dynamic::from_float(-3.9).to_int()?, -3);
+    assert_eq!(Dynamic::from_int(42).to_int()?, 42);
+
+    assert!(Dynamic::from_int(42).as_float().is_err());
+    assert!(Dynamic::from_float(1.5).as_int().is_err());
+
+    assert!(matches!(
+        *Dynamic::from("hello").to_float().expect_err("should error"),
+        EvalAltResult::ErrorMismatchDataType(..)
+    ));
+    assert!(matches!(
+        *Dynamic::from(true).to_int().expect_err("should error"),
+        EvalAltResult::ErrorMismatchDataType(..)
+    ));
+
+    #[cfg(not(feature = "unchecked"))]
+    {
+        assert!(matches!(
+            *Dynamic::from_float(FLOAT::NAN)
+                .to_int()
+                .expect_err("should error"),
+            EvalAltResult::ErrorArithmetic(..)
+        ));
+        assert!(matches!(
+            *Dynamic::from_float(FLOAT::MAX)
+                .to_int()
+                .expect_err("should error"),
+            EvalAltResult::ErrorArithmetic(..)
+        ));
+    }
+    #[cfg(feature = "unchecked")]
+    assert_eq!(Dynamic::from_float(FLOAT::MAX).to_int()?, rhai::INT::MAX);
+
+    Ok(())
+}
+
+#[test]
+fn test_float_strict_numeric_coercion() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    assert!((engine.eval::<FLOAT>("1 + 2.5")? - 3.5).abs() < EPSILON);
+
+    engine.set_strict_numeric_coercion(true);
+
+    assert!(matches!(
+        *engine.eval::<FLOAT>("1 + 2.5").expect_err("should error"),
+        EvalAltResult::ErrorArithmetic(..)
+    ));
+    assert!(matches!(
+        *engine
+            .eval::<FLOAT>("let x = 2.5; x * 2")
+            .expect_err("should error"),
+        EvalAltResult::ErrorArithmetic(..)
+    ));
+    assert!(matches!(
+        *engine
+            .eval::<FLOAT>("let x = 2.5; x += 1; x")
+            .expect_err("should error"),
+        EvalAltResult::ErrorArithmetic(..)
+    ));
+    assert!((engine.eval::<FLOAT>("to_float(1) + 2.5")? - 3.5).abs() < EPSILON);
+    assert!((engine.eval::<FLOAT>("let x = 2.5; x *= 2.0; x")? - 5.0).abs() < EPSILON);
+    assert!(engine.eval::<bool>("1 < 2.5")?);
+
+    engine.set_fast_operators(false);
+
+    assert!(matches!(
+        *engine.eval::<FLOAT>("1 + 2.5").expect_err("should error"),
+        EvalAltResult::ErrorArithmetic(..)
+    ));
+
+    Ok(())
+}
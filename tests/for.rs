@@ -1,4 +1,4 @@
-use rhai::{Engine, EvalAltResult, Module, INT};
+use rhai::{Dynamic, Engine, EvalAltResult, Module, INT};
 
 #[cfg(not(feature = "no_float"))]
 use rhai::FLOAT;
@@ -304,6 +304,37 @@ fn test_for_string() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[test]
+fn test_for_char_range() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<String>(r#"let s = ""; for c in 'a'..='e' { s += c; } s"#)?,
+        "abcde"
+    );
+    assert_eq!(
+        engine.eval::<String>(r#"let s = ""; for c in 'a'..'e' { s += c; } s"#)?,
+        "abcd"
+    );
+
+    // Descending ranges are empty
+    assert_eq!(
+        engine.eval::<String>(r#"let s = ""; for c in 'e'..='a' { s += c; } s"#)?,
+        ""
+    );
+
+    // Surrogate code points are skipped
+    assert_eq!(
+        engine.eval::<INT>(r"let n = 0; for c in '\uD7FF'..='\uE000' { n += 1; } n")?,
+        2
+    );
+
+    assert_eq!(engine.eval::<String>("type_of('a'..'z')")?, "range");
+    assert_eq!(engine.eval::<String>("type_of('a'..='z')")?, "range=");
+
+    Ok(())
+}
+
 #[cfg(not(feature = "no_object"))]
 #[cfg(not(feature = "no_index"))]
 #[test]
@@ -330,6 +361,40 @@ fn test_for_object() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[cfg(not(feature = "no_object"))]
+#[test]
+fn test_for_object_native() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let sum = 0;
+                for value in #{a: 1, b: 2, c: 3} {
+                    sum += value;
+                }
+                sum
+            "
+        )?,
+        6
+    );
+
+    assert_eq!(
+        engine.eval::<String>(
+            r#"
+                let s = "";
+                for (value, key) in #{c: 3, a: 1, b: 2} {
+                    s += `${key}=${value};`;
+                }
+                s
+            "#
+        )?,
+        "a=1;b=2;c=3;"
+    );
+
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 struct MyIterableType(String);
 
@@ -400,3 +465,59 @@ fn test_for_capture() -> Result<(), Box<EvalAltResult>> {
     );
     Ok(())
 }
+
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_for_lazy_sequence() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    // An endless sequence
+    engine.register_lazy_fn("numbers", |start: INT| (start..).map(Dynamic::from));
+
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let sum = 0;
+                for (x, i) in numbers(1).take(5) { sum += x * i; }
+                sum
+            "
+        )?,
+        40
+    );
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let sum = 0;
+                for x in numbers(1) { if x > 10 { break; } sum += x; }
+                sum
+            "
+        )?,
+        55
+    );
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let seq = numbers(1);
+                for x in seq.take(3) { }
+                let rest = 0;
+                for x in seq.take(1) { rest = x; }
+                rest
+            "
+        )?,
+        4
+    );
+
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let sum = 0;
+                for x in numbers(1).take(10).map(|x| x * x).take(3) { sum += x; }
+                sum
+            "
+        )?,
+        14
+    );
+
+    Ok(())
+}
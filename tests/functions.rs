@@ -223,3 +223,117 @@ fn test_functions_bang() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+fn test_functions_ast_try_combine() -> Result<(), Box<EvalAltResult>> {
+    use rhai::ParseErrorType;
+
+    let engine = Engine::new();
+
+    let prelude = engine.compile("fn double(x) { x * 2 } fn triple(x) { x * 3 }")?;
+
+    let mut ast = prelude.clone();
+    let err = ast
+        .try_combine(engine.compile("fn triple(x) { 0 } triple(1)")?)
+        .expect_err("should error");
+    assert_eq!(
+        *err.err_type(),
+        ParseErrorType::FnDuplicatedDefinition("triple".into(), 1)
+    );
+    assert_eq!(ast.iter_functions().count(), 2);
+
+    // Same name but different number of parameters is fine
+    ast.try_combine(engine.compile("fn triple() { 3 } double(triple()) + triple(1)")?)?;
+    assert_eq!(engine.eval_ast::<INT>(&ast)?, 9);
+
+    // Later definitions win with `combine`
+    let mut ast = prelude.clone();
+    ast.combine(engine.compile("fn double(x) { x } double(21)")?);
+    assert_eq!(engine.eval_ast::<INT>(&ast)?, 21);
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_position"))]
+#[test]
+fn test_functions_error_backtrace() -> Result<(), Box<EvalAltResult>> {
+    use rhai::Position;
+
+    let script = "
+        fn inner(x) { throw x; }
+        fn middle(x) { inner(x) }
+        fn outer(x) { middle(x) }
+        outer(42)
+    ";
+
+    let mut engine = Engine::new();
+
+    // Nested calls are collapsed by default
+    let err = engine.run(script).expect_err("should error");
+    assert_eq!(
+        err.backtrace(),
+        vec![("inner < middle < outer".to_string(), Position::new(5, 9))]
+    );
+    assert!(matches!(
+        *err.unwrap_inner(),
+        EvalAltResult::ErrorRuntime(ref x, pos) if x.as_int().unwrap() == 42 && pos == Position::new(2, 23)
+    ));
+
+    engine.set_error_backtrace(true);
+
+    let err = engine.run(script).expect_err("should error");
+    assert_eq!(
+        err.backtrace(),
+        vec![
+            ("inner".to_string(), Position::new(3, 24)),
+            ("middle".to_string(), Position::new(4, 23)),
+            ("outer".to_string(), Position::new(5, 9)),
+        ]
+    );
+    assert!(matches!(
+        *err.unwrap_inner(),
+        EvalAltResult::ErrorRuntime(ref x, pos) if x.as_int().unwrap() == 42 && pos == Position::new(2, 23)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_functions_metadata() -> Result<(), Box<EvalAltResult>> {
+    use rhai::FnAccess;
+
+    let engine = Engine::new();
+
+    let ast = engine.compile(
+        "
+            /// Called on click
+            fn on_click(x, y) { helper() + x + y }
+
+            private fn helper() { 42 }
+
+            on_click(1, 2)
+        ",
+    )?;
+
+    assert_eq!(engine.eval_ast::<INT>(&ast)?, 45);
+
+    let mut functions: Vec<_> = ast.iter_functions().collect();
+    functions.sort();
+
+    assert_eq!(functions.len(), 2);
+    assert_eq!(functions[0].name, "helper");
+    assert!(functions[0].params.is_empty());
+    assert_eq!(functions[0].access, FnAccess::Private);
+    assert_eq!(functions[0].to_string(), "private helper()");
+    assert_eq!(functions[1].name, "on_click");
+    assert_eq!(functions[1].params, vec!["x", "y"]);
+    assert_eq!(functions[1].access, FnAccess::Public);
+
+    #[cfg(feature = "metadata")]
+    {
+        assert!(functions[0].comments.is_empty());
+        assert_eq!(functions[1].comments, vec!["/// Called on click"]);
+    }
+
+    Ok(())
+}
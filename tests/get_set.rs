@@ -1,6 +1,6 @@
 #![cfg(not(feature = "no_object"))]
 
-use rhai::{Engine, EvalAltResult, NativeCallContext, Scope, INT};
+use rhai::{Engine, EvalAltResult, NativeCallContext, Scope, Shared, INT};
 
 #[test]
 fn test_get_set() -> Result<(), Box<EvalAltResult>> {
@@ -395,6 +395,132 @@ fn test_get_set_indexer() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[cfg(not(feature = "no_index"))]
+#[test]
+fn test_get_set_indexer_grow() -> Result<(), Box<EvalAltResult>> {
+    #[derive(Debug, Clone, Default)]
+    struct MyMap(std::collections::BTreeMap<String, INT>);
+
+    let mut engine = Engine::new();
+
+    engine
+        .register_type_with_name::<MyMap>("MyMap")
+        .register_fn("new_map", MyMap::default)
+        .register_indexer_get_set(
+            |map: &mut MyMap, index: &str| -> Result<INT, Box<EvalAltResult>> {
+                map.0.get(index).cloned().ok_or_else(|| {
+                    EvalAltResult::ErrorIndexNotFound(index.into(), rhai::Position::NONE).into()
+                })
+            },
+            |map: &mut MyMap, index: &str, value: INT| {
+                map.0.insert(index.to_string(), value);
+            },
+        );
+
+    let map = engine.eval::<MyMap>(
+        r#"
+            let my_map = new_map();
+            my_map["a"] = 1;
+            my_map["b"] = 2;
+            my_map["a"] = 40;
+            my_map["a"] += 2;
+            my_map["c"] += 3;
+            my_map
+        "#,
+    )?;
+
+    assert_eq!(map.0.len(), 3);
+    assert_eq!(map.0["a"], 42);
+    assert_eq!(map.0["b"], 2);
+    assert_eq!(map.0["c"], 3);
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_index"))]
+#[test]
+fn test_get_set_indexer_range() -> Result<(), Box<EvalAltResult>> {
+    #[derive(Debug, Clone)]
+    struct MyList(Vec<INT>);
+
+    let mut engine = Engine::new();
+
+    engine
+        .register_type_with_name::<MyList>("MyList")
+        .register_fn("new_list", || MyList((1..=5).collect()))
+        .register_indexer_get(|list: &mut MyList, index: INT| list.0[index as usize])
+        .register_indexer_range_get(|list: &mut MyList, start: INT, end: INT| {
+            list.0[start as usize..end as usize]
+                .iter()
+                .map(|&v| v.into())
+                .collect::<rhai::Array>()
+        });
+
+    assert_eq!(engine.eval::<INT>("let x = new_list(); x[1]")?, 2);
+    assert_eq!(
+        engine
+            .eval::<rhai::Array>("let x = new_list(); x[1..3]")?
+            .len(),
+        2
+    );
+    assert_eq!(engine.eval::<INT>("let x = new_list(); x[1..=3][2]")?, 4);
+
+    Ok(())
+}
+
+#[test]
+fn test_get_set_shared_view() -> Result<(), Box<EvalAltResult>> {
+    #[derive(Clone)]
+    struct TestStruct {
+        items: Shared<Vec<INT>>,
+    }
+
+    #[derive(Clone)]
+    struct ItemsView(Shared<Vec<INT>>);
+
+    impl IntoIterator for ItemsView {
+        type Item = INT;
+        type IntoIter = ItemsIter;
+
+        fn into_iter(self) -> Self::IntoIter {
+            ItemsIter(self.0, 0)
+        }
+    }
+
+    struct ItemsIter(Shared<Vec<INT>>, usize);
+
+    impl Iterator for ItemsIter {
+        type Item = INT;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let item = self.0.get(self.1).copied();
+            self.1 += 1;
+            item
+        }
+    }
+
+    let obj = TestStruct {
+        items: Shared::new((1..=100).collect()),
+    };
+
+    let mut engine = Engine::new();
+
+    engine
+        .register_type::<TestStruct>()
+        .register_get("items", |x: &mut TestStruct| ItemsView(x.items.clone()))
+        .register_iterator::<ItemsView>();
+
+    let mut scope = Scope::new();
+    scope.push("obj", obj);
+
+    assert_eq!(
+        engine.eval_with_scope::<INT>(&mut scope, "let s = 0; for x in obj.items { s += x } s")?,
+        5050
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_get_set_elvis() -> Result<(), Box<EvalAltResult>> {
     let engine = Engine::new();
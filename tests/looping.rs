@@ -44,3 +44,65 @@ fn test_loop() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+fn test_loop_expression() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let i = 0;
+
+                let x = loop {
+                    i += 1;
+                    if i > 5 { break i * 10; }
+                };
+
+                x
+            "
+        )?,
+        60
+    );
+
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let i = 0;
+
+                loop {
+                    i += 1;
+                    switch i {
+                        3 => break 42,
+                        _ => continue,
+                    }
+                }
+            "
+        )?,
+        42
+    );
+
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                loop {
+                    let x = loop { break 1; };
+                    for y in 0..10 { if y > 5 { break; } }
+                    break x + loop { break 41 };
+                }
+            "
+        )?,
+        42
+    );
+
+    engine.eval::<()>("let x = loop { break; }; x")?;
+
+    assert!(engine.compile("while true { break 42; }").is_err());
+    assert!(engine.compile("for x in 0..10 { break 42; }").is_err());
+    assert!(engine.compile("do { break 42; } while true").is_err());
+    assert!(engine
+        .compile("loop { for x in 0..10 { break 42; } }")
+        .is_err());
+
+    Ok(())
+}
@@ -201,6 +201,48 @@ fn test_map_return() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[test]
+fn test_map_get_or_insert() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<INT>(
+            r#"let m = #{a: 1}; m.get_or_insert("a", 42) + m.get_or_insert("b", 2) + m.b"#
+        )?,
+        5
+    );
+
+    #[cfg(not(feature = "no_function"))]
+    {
+        #[cfg(not(feature = "no_index"))]
+        assert_eq!(
+            engine.eval::<INT>(
+                r#"
+                    let counts = #{};
+                    for w in ["a", "b", "a", "a"] {
+                        counts.get_or_insert_with(w, || 0);
+                        counts[w] += 1;
+                    }
+                    counts.a * 10 + counts.b
+                "#
+            )?,
+            31
+        );
+        assert_eq!(
+            engine.eval::<INT>(
+                r#"
+                    let m = #{x: 1};
+                    m.get_or_insert_with("x", || throw "should not be called")
+                        + m.get_or_insert_with("hello", |p| p.len())
+                "#
+            )?,
+            6
+        );
+    }
+
+    Ok(())
+}
+
 #[test]
 #[cfg(not(feature = "no_index"))]
 fn test_map_for() -> Result<(), Box<EvalAltResult>> {
@@ -227,6 +269,47 @@ fn test_map_for() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(feature = "no_index"))]
+#[cfg(not(feature = "no_function"))]
+fn test_map_entries() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<String>(
+            r#"
+                let map = #{c: 3, a: 1, b: 2};
+                let s = "";
+
+                for e in map.entries() {
+                    s += `${e[0]}=${e[1]};`;
+                }
+
+                s
+            "#
+        )?,
+        "a=1;b=2;c=3;"
+    );
+    assert_eq!(
+        engine.eval::<INT>(
+            "#{a: 1, b: 2, c: 3}.values().filter(|v| v > 1).reduce(|sum, v| sum + v, 0)"
+        )?,
+        5
+    );
+    assert_eq!(
+        engine.eval::<String>(
+            r#"
+                let map = #{a: 1, b: 2, c: 3};
+                map.entries().filter(|e| e[1] != 2).map(|e| e[0]).reduce(|s, k| s + k, "")
+            "#
+        )?,
+        "ac"
+    );
+    assert_eq!(engine.eval::<INT>("#{}.entries().len()")?, 0);
+
+    Ok(())
+}
+
 #[test]
 /// Because a Rhai object map literal is almost the same as JSON,
 /// it is possible to convert from JSON into a Rhai object map.
@@ -305,6 +388,69 @@ fn test_map_json() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(feature = "no_index"))]
+fn test_map_json_pretty() -> Result<(), Box<EvalAltResult>> {
+    #[derive(Debug, Clone)]
+    struct Point;
+
+    let mut engine = Engine::new();
+
+    engine
+        .register_type_with_name::<Point>("Point")
+        .register_fn("new_point", || Point)
+        .register_fn("to_string", |_: &mut Point| "point".to_string());
+
+    assert_eq!(
+        engine.eval::<String>(r#"#{z: (), b: [1, "x\ty"], a: #{}}.to_json_pretty(2)"#)?,
+        "{\n  \"a\": {},\n  \"b\": [\n    1,\n    \"x\\ty\"\n  ],\n  \"z\": null\n}"
+    );
+    assert_eq!(
+        engine.eval::<String>(r#"#{b: [true, 'c'], a: 1}.to_json_pretty(0)"#)?,
+        r#"{"a":1,"b":[true,"c"]}"#
+    );
+    assert_eq!(
+        engine.eval::<String>("#{p: new_point()}.to_json_pretty(0, false)")?,
+        r#"{"p":"point"}"#
+    );
+    assert!(engine
+        .eval::<String>("#{p: new_point()}.to_json_pretty(0, true)")
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_map_flatten_keys() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let map = engine.eval::<Map>("#{a: #{b: 1, c: #{d: true}, e: #{}}, f: 42}.flatten_keys()")?;
+
+    assert_eq!(
+        map.keys().map(|k| k.as_str()).collect::<Vec<_>>(),
+        ["a.b", "a.c.d", "a.e", "f"]
+    );
+    assert_eq!(map["a.b"].as_int().unwrap(), 1);
+    assert!(map["a.c.d"].as_bool().unwrap());
+    assert!(map["a.e"].is::<Map>());
+
+    assert_eq!(
+        engine.eval::<INT>(
+            r#"let m = #{"a.b": 1, "a.c.d": 2, f: 3}.unflatten_keys(); m.a.b + m.a.c.d + m.f"#
+        )?,
+        6
+    );
+    assert_eq!(
+        engine.eval::<INT>(r#"let m = #{a: 1, "a.b": 2}.unflatten_keys(); m.a.b"#)?,
+        2
+    );
+    assert!(engine.eval::<bool>(
+        "let m = #{a: #{b: #{c: 1}}, d: 2}; m.flatten_keys().unflatten_keys() == m"
+    )?);
+
+    Ok(())
+}
+
 #[test]
 #[cfg(not(feature = "no_function"))]
 fn test_map_oop() -> Result<(), Box<EvalAltResult>> {
@@ -326,3 +472,71 @@ fn test_map_oop() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(not(feature = "no_index"))]
+fn test_map_typed() -> Result<(), Box<EvalAltResult>> {
+    #[derive(Debug, Clone)]
+    struct Point {
+        x: INT,
+        y: INT,
+    }
+
+    let mut engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<INT>(
+            r#"
+                let m = typed_map();
+                m[1] = 10;
+                m["1"] = 20;
+                m['1'] = 30;
+                m[true] = 40;
+                m[1] += 1;
+                m[1] + m["1"] + m['1'] + m[true] + m.len()
+            "#
+        )?,
+        105
+    );
+    assert_eq!(
+        engine.eval::<INT>("let m = typed_map(); m[1] = 42; m.remove(1) + m.len()")?,
+        42
+    );
+    assert!(engine.eval::<()>("let m = typed_map(); m[42]").is_ok());
+    assert!(engine.run("let m = typed_map(); m[[1, 2]] = 42;").is_err());
+
+    engine
+        .register_type_with_name::<Point>("Point")
+        .register_fn("point", |x: INT, y: INT| Point { x, y });
+
+    assert!(engine
+        .run("let m = typed_map(); m[point(1, 2)] = 42;")
+        .is_err());
+
+    engine.register_type_as_map_key::<Point>(
+        |a, b| a.x == b.x && a.y == b.y,
+        // Deliberately weak hash to exercise collisions
+        |p| (p.x + p.y) as u64,
+    );
+
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let m = typed_map();
+                m[point(1, 2)] = 1;
+                m[point(2, 1)] = 2;
+                m[point(1, 2)] += 10;
+                m[point(1, 2)] * 10 + m[point(2, 1)] + m.len() * 100
+            "
+        )?,
+        312
+    );
+    assert!(
+        engine.eval::<bool>("let m = typed_map(); m[point(1, 2)] = 1; m.contains(point(1, 2))")?
+    );
+    assert!(
+        !engine.eval::<bool>("let m = typed_map(); m[point(1, 2)] = 1; m.contains(point(2, 1))")?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use rhai::{Engine, EvalAltResult, ParseErrorType, INT};
+
+#[test]
+fn test_match() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<INT>("match 2 { 1 => 10, 2 => 20, _ => 0 }")?,
+        20
+    );
+    assert_eq!(engine.eval::<INT>("match 42 { 1 => 10, n => n + 1 }")?, 43);
+    assert_eq!(
+        engine.eval::<INT>("match 42 { n if n < 0 => -1, 0..10 => 1, 10..=100 => 2, _ => 3 }")?,
+        2
+    );
+    assert_eq!(
+        engine.eval::<String>(r#"match "hello" { "world" => "no", s => s + "!" }"#)?,
+        "hello!"
+    );
+    assert_eq!(engine.run("match 3 { 1 => 10, 2 => 20 }")?, ());
+
+    // Bindings do not leak out of the arm
+    assert_eq!(
+        engine.eval::<INT>("let n = 1; let y = match 42 { n => n * 2 }; n + y")?,
+        85
+    );
+
+    // Statements as arms
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let x = 0;
+                match 5 {
+                    n if n > 3 => { x = n; x += 1; }
+                    _ => x = -1
+                }
+                x
+            "
+        )?,
+        6
+    );
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_index"))]
+#[cfg(not(feature = "no_function"))]
+#[test]
+fn test_match_array() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let script = "
+        fn describe(v) {
+            match v {
+                [] => 0,
+                [x] => x,
+                [1, y] => 100 + y,
+                [[a, b], ..] => a * b,
+                [x, y] if x > y => x - y,
+                [x, y] => x + y,
+                [x, ..] => -x,
+                _ => -1
+            }
+        }
+        [describe([]), describe([42]), describe([1, 2]), describe([5, 3]), describe([3, 5]),
+         describe([[6, 7], 0]), describe([9, 8, 7]), describe(42)]
+    ";
+
+    assert_eq!(
+        engine
+            .eval::<rhai::Array>(script)?
+            .into_iter()
+            .map(|v| v.as_int().unwrap())
+            .collect::<Vec<_>>(),
+        [0, 42, 102, 2, 8, 42, -9, -1]
+    );
+
+    assert_eq!(
+        *engine
+            .compile("match [1, 2] { [x, x] => x }")
+            .expect_err("should error")
+            .err_type(),
+        ParseErrorType::DuplicatedVariable("x".to_string())
+    );
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_object"))]
+#[test]
+fn test_match_map() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<INT>(
+            r#"
+                let shape = #{ kind: "rect", w: 6, h: 7 };
+
+                match shape {
+                    #{ kind: "circle", r } => 3 * r * r,
+                    #{ kind: "rect", w, h } if w > 0 => w * h,
+                    _ => 0
+                }
+            "#
+        )?,
+        42
+    );
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let point = #{ x: 1, y: 2, z: 3 };
+
+                match point {
+                    #{ x, y, w } => 0,
+                    #{ x: px, y } if px > 0 => px + y,
+                    _ => -1
+                }
+            "
+        )?,
+        3
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_match_strict() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    assert_eq!(engine.run("match 42 { 1 => 2 }")?, ());
+
+    engine.set_strict_match(true);
+
+    assert!(engine.run("match 42 { 1 => 2 }").is_err());
+    assert_eq!(engine.eval::<INT>("match 42 { 1 => 2, _ => 3 }")?, 3);
+
+    Ok(())
+}
@@ -116,6 +116,25 @@ fn test_math_parse() -> Result<(), Box<EvalAltResult>> {
     assert_eq!(engine.eval::<INT>(r#"parse_int("42")"#)?, 42);
     assert_eq!(engine.eval::<INT>(r#"parse_int("42", 16)"#)?, 0x42);
     assert_eq!(engine.eval::<INT>(r#"parse_int("abcdef", 16)"#)?, 0xabcdef);
+    assert_eq!(engine.eval::<INT>(r#"parse_int("0xff", 16)"#)?, 0xff);
+    assert_eq!(engine.eval::<INT>(r#"parse_int("-0b1010", 2)"#)?, -10);
+    assert_eq!(engine.eval::<INT>(r#"parse_int("+0o17", 8)"#)?, 0o17);
+    assert_eq!(engine.eval::<INT>(r#"parse_int("0b11", 16)"#)?, 0xb11);
+    assert!(engine.eval::<INT>(r#"parse_int("0xff", 10)"#).is_err());
+    assert!(engine.eval::<INT>(r#"parse_int("--1", 10)"#).is_err());
+    assert!(engine.eval::<INT>(r#"parse_int("1", 37)"#).is_err());
+    assert!(engine
+        .eval::<INT>(r#"parse_int("ffffffffffffffffffffffff", 16)"#)
+        .is_err());
+
+    assert_eq!(engine.eval::<String>("to_radix_string(255, 16)")?, "ff");
+    assert_eq!(engine.eval::<String>("to_radix_string(-10, 2)")?, "-1010");
+    assert_eq!(engine.eval::<String>("to_radix_string(0, 36)")?, "0");
+    assert_eq!(
+        engine.eval::<INT>("parse_int(to_radix_string(-123456, 36), 36)")?,
+        -123456
+    );
+    assert!(engine.eval::<String>("to_radix_string(42, 1)").is_err());
 
     Ok(())
 }
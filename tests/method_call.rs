@@ -39,6 +39,34 @@ fn test_method_call() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[test]
+fn test_method_call_mut_first_param() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine
+        .register_type::<TestStruct>()
+        .register_fn("new_ts", TestStruct::new)
+        .register_fn("update", TestStruct::update)
+        .register_fn("value", |x: TestStruct| x.x)
+        .register_fn("value", |x: &mut TestStruct| x.x * 10);
+
+    // The later registration replaces the earlier one with the same signature
+    assert_eq!(engine.eval::<INT>("new_ts().value()")?, 10);
+    assert_eq!(engine.eval::<INT>("let x = new_ts(); value(x)")?, 10);
+
+    // Changes are made to the variable itself
+    assert_eq!(
+        engine.eval::<INT>("let x = new_ts(); x.update(1); x.value()")?,
+        20
+    );
+    assert_eq!(
+        engine.eval::<INT>("let x = new_ts(); update(x, 1); x.value()")?,
+        20
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_method_call_style() -> Result<(), Box<EvalAltResult>> {
     let engine = Engine::new();
@@ -89,6 +89,106 @@ fn test_module_sub_module() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[test]
+fn test_module_builder() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine
+        .module_builder("calc::basic")
+        .with_fn("inc", |x: INT| x + 1)
+        .with_fn("add", |x: INT, y: INT| x + y)
+        .with_constant("ANSWER", 41 as INT)
+        .build()
+        .module_builder("greet")
+        .with_fn("hello", |name: &str| format!("hello, {name}!"))
+        .build();
+
+    assert_eq!(
+        engine.eval::<INT>("calc::basic::inc(calc::basic::ANSWER)")?,
+        42
+    );
+    assert_eq!(engine.eval::<INT>("calc::basic::add(40, 2)")?, 42);
+    assert_eq!(
+        engine.eval::<String>(r#"greet::hello("world")"#)?,
+        "hello, world!"
+    );
+    assert!(engine.eval::<INT>("inc(41)").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_module_lazy() -> Result<(), Box<EvalAltResult>> {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let mut engine = Engine::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    let count2 = count.clone();
+
+    engine.register_lazy_module("calc", move || {
+        count2.fetch_add(1, Ordering::SeqCst);
+        let mut module = Module::new();
+        module.set_var("ANSWER", 41 as INT);
+        module.set_native_fn("inc", |x: INT| Ok(x + 1));
+        module
+    });
+
+    assert_eq!(engine.eval::<INT>("40 + 2")?, 42);
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+
+    assert_eq!(engine.eval::<INT>("calc::inc(calc::ANSWER)")?, 42);
+    assert_eq!(engine.eval::<INT>("calc::inc(1)")?, 2);
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    engine.set_strict_variables(true);
+    assert_eq!(engine.eval::<INT>("calc::ANSWER")?, 41);
+    assert!(engine.compile("unknown::ANSWER").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_module_eval_with_modules() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    let mut module = Module::new();
+    module.set_var("ANSWER", 1 as INT);
+    engine.register_static_module("calc", module.into());
+
+    let mut module = Module::new();
+    module.set_var("ANSWER", 41 as INT);
+    module.set_native_fn("inc", |x: INT| Ok(x + 1));
+    let module = Shared::new(module);
+
+    let mut resolver = StaticModuleResolver::new();
+    let mut imported = Module::new();
+    imported.set_var("ANSWER", 99 as INT);
+    resolver.insert("other", imported);
+    engine.set_module_resolver(resolver);
+
+    let ast = engine.compile("calc::inc(calc::ANSWER)")?;
+
+    assert_eq!(
+        engine.eval_with_modules::<INT>(&mut Scope::new(), &ast, &[("calc", module.clone())])?,
+        42
+    );
+    assert!(engine.eval_ast::<INT>(&ast).is_err());
+    assert_eq!(engine.eval::<INT>("calc::ANSWER")?, 1);
+
+    let ast =
+        engine.compile(r#"let x = calc::ANSWER; import "other" as calc; x + calc::ANSWER"#)?;
+
+    assert_eq!(
+        engine.eval_with_modules::<INT>(&mut Scope::new(), &ast, &[("calc", module)])?,
+        140
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_module_resolver() -> Result<(), Box<EvalAltResult>> {
     let mut resolver = StaticModuleResolver::new();
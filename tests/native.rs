@@ -1,4 +1,4 @@
-use rhai::{Dynamic, Engine, EvalAltResult, ImmutableString, NativeCallContext, INT};
+use rhai::{Dynamic, Engine, EvalAltResult, ImmutableString, NativeCallContext, Scope, INT};
 use std::any::TypeId;
 
 #[cfg(not(feature = "no_module"))]
@@ -97,3 +97,386 @@ fn test_native_overload() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+fn test_native_override_operators() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.register_fn("+", |x: INT, y: INT| x.saturating_add(y));
+
+    let mut scope = Scope::new();
+    scope.push_constant("MAX", INT::MAX);
+
+    #[cfg(not(feature = "unchecked"))]
+    assert!(engine
+        .eval_with_scope::<INT>(&mut scope, "MAX + 1")
+        .is_err());
+    assert_eq!(engine.eval::<INT>("let x = 40; x += 2; x")?, 42);
+
+    engine.set_fast_operators(false);
+
+    assert_eq!(
+        engine.eval_with_scope::<INT>(&mut scope, "MAX + 1")?,
+        INT::MAX
+    );
+    assert_eq!(
+        engine.eval_with_scope::<INT>(&mut scope, "let x = MAX; x += 1; x")?,
+        INT::MAX
+    );
+    assert_eq!(engine.eval::<INT>("let x = 40; x += 2; x")?, 42);
+
+    // Built-in op-assignments without a binary operator still work
+    #[cfg(not(feature = "no_index"))]
+    {
+        assert_eq!(engine.eval::<INT>("let a = [1]; a += 2; len(a)")?, 2);
+        assert_eq!(engine.eval::<INT>("let b = blob(); b += 1; len(b)")?, 1);
+        assert_eq!(engine.eval::<INT>("let b = blob(); b += 'x'; len(b)")?, 1);
+        assert_eq!(
+            engine.eval::<INT>(r#"let b = blob(); b += "hello"; len(b)"#)?,
+            5
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_native_on_fn_call() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.register_fn("add", |x: INT, y: INT| x + y);
+
+    engine.on_fn_call(|name, args| match name {
+        "http_get" => Some(format!("mocked: {}", args[0]).into()),
+        "add" if args[0].as_int() == Ok(0) => Some(Dynamic::from(-1 as INT)),
+        _ => None,
+    });
+
+    assert_eq!(
+        engine.eval::<String>(r#"http_get("example.com")"#)?,
+        "mocked: example.com"
+    );
+    assert_eq!(engine.eval::<INT>("add(1, 2)")?, 3);
+    assert_eq!(engine.eval::<INT>("add(0, 2)")?, -1);
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<INT>("let x = 0; x.add(2)")?, -1);
+
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(
+        engine.eval::<String>(
+            "
+                fn http_get(url) { 42 }
+                http_get(1)
+            "
+        )?,
+        "mocked: 1"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_native_on_missing_fn() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.register_fn("add", |x: INT, y: INT| x + y);
+
+    engine.on_missing_fn(|name, args| match name {
+        "add" => Some(Ok(Dynamic::from(-1 as INT))),
+        "cmd_inc" => {
+            *args[0] = (args[0].as_int().unwrap() + 1).into();
+            Some(Ok(Dynamic::UNIT))
+        }
+        "cmd_fail" => Some(Err("failed".into())),
+        _ if name.starts_with("cmd_") => Some(Ok((args.len() as INT).into())),
+        _ => None,
+    });
+
+    assert_eq!(engine.eval::<INT>("add(1, 2)")?, 3);
+    assert_eq!(engine.eval::<INT>("add(1, 2, 3)")?, -1);
+    assert_eq!(engine.eval::<INT>("cmd_foo(1, true, ())")?, 3);
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<INT>("let x = 41; x.cmd_inc(); x")?, 42);
+    assert_eq!(engine.eval::<INT>("1 + 2")?, 3);
+
+    assert!(matches!(
+        *engine.run("cmd_fail()").expect_err("should error"),
+        EvalAltResult::ErrorRuntime(..)
+    ));
+    assert!(matches!(
+        *engine.run("foo(42)").expect_err("should error"),
+        EvalAltResult::ErrorFunctionNotFound(..)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_native_return_str() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine
+        .register_fn("label", |x: INT| if x > 0 { "positive" } else { "other" })
+        .register_fn("describe", |x: INT| -> std::borrow::Cow<'static, str> {
+            if x == 0 {
+                "zero".into()
+            } else {
+                format!("number {x}").into()
+            }
+        });
+
+    assert_eq!(engine.eval::<String>("label(42)")?, "positive");
+    assert_eq!(engine.eval::<String>("label(-1) + '!'")?, "other!");
+    assert_eq!(engine.eval::<String>("describe(0)")?, "zero");
+    assert_eq!(engine.eval::<String>("describe(42)")?, "number 42");
+    assert_eq!(engine.eval::<String>("type_of(describe(0))")?, "string");
+    assert!(engine.eval::<bool>(r#"label(1) == "positive""#)?);
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_index"))]
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_native_slice_params() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine
+        .register_fn("sum", |x: &[INT]| x.iter().sum::<INT>())
+        .register_fn("count_true", |x: &[bool]| {
+            x.iter().filter(|&&b| b).count() as INT
+        })
+        .register_fn("join", |x: &[ImmutableString], sep: &str| {
+            x.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(sep)
+        })
+        .register_fn("len_plus", |x: &[Dynamic], n: INT| x.len() as INT + n);
+
+    assert_eq!(engine.eval::<INT>("sum([1, 2, 3, 36])")?, 42);
+    assert_eq!(engine.eval::<INT>("sum([])")?, 0);
+    assert_eq!(engine.eval::<INT>("let x = [40, 2]; sum(x) + x.len")?, 44);
+    assert_eq!(engine.eval::<INT>("count_true([true, false, true])")?, 2);
+    assert_eq!(engine.eval::<String>(r#"join(["a", "b"], "-")"#)?, "a-b");
+    assert_eq!(engine.eval::<INT>(r#"len_plus([1, "x", ()], 39)"#)?, 42);
+
+    let err = engine
+        .eval::<INT>(r#"sum([1, 2, "x", 4])"#)
+        .expect_err("should error");
+
+    match *err {
+        EvalAltResult::ErrorMismatchDataType(_, ref actual, ..) => {
+            assert_eq!(actual, "string at index 2")
+        }
+        _ => panic!("wrong error: {err}"),
+    }
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_index"))]
+#[test]
+fn test_native_fixed_array_params() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine
+        .register_fn("dot", |a: [INT; 3], b: [INT; 3]| {
+            a.iter().zip(b.iter()).map(|(x, y)| x * y).sum::<INT>()
+        })
+        .register_fn("first", |x: [ImmutableString; 2]| x[0].clone());
+
+    assert_eq!(engine.eval::<INT>("dot([1, 2, 3], [4, 5, 6])")?, 32);
+    assert_eq!(engine.eval::<String>(r#"first(["a", "b"])"#)?, "a");
+
+    let err = engine
+        .eval::<INT>("dot([1, 2], [4, 5, 6])")
+        .expect_err("should error");
+
+    match *err {
+        EvalAltResult::ErrorMismatchDataType(ref expected, ref actual, ..) => {
+            assert_eq!(expected, "array of length 3");
+            assert_eq!(actual, "array of length 2");
+        }
+        _ => panic!("wrong error: {err}"),
+    }
+
+    let err = engine
+        .eval::<INT>(r#"dot([1, 2, 3], [4, "x", 6])"#)
+        .expect_err("should error");
+
+    match *err {
+        EvalAltResult::ErrorMismatchDataType(_, ref actual, ..) => {
+            assert_eq!(actual, "string at index 1")
+        }
+        _ => panic!("wrong error: {err}"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_native_queue_registration() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.register_fn("load_plugin", |ctx: NativeCallContext, value: INT| {
+        ctx.queue_registration(move |engine| {
+            engine.register_fn("plugin_fn", move || value);
+        });
+    });
+
+    // Registrations do not take effect during the evaluation
+    assert!(engine.run("load_plugin(42); plugin_fn()").is_err());
+    assert!(engine.eval::<INT>("plugin_fn()").is_err());
+
+    assert_eq!(engine.apply_queued_registrations(), 1);
+    assert_eq!(engine.eval::<INT>("plugin_fn()")?, 42);
+
+    // Registrations are applied in order
+    engine.run("load_plugin(1); load_plugin(2)")?;
+    assert_eq!(engine.apply_queued_registrations(), 2);
+    assert_eq!(engine.eval::<INT>("plugin_fn()")?, 2);
+    assert_eq!(engine.apply_queued_registrations(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_native_option_params() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine
+        .register_fn("greet", |name: Option<String>| match name {
+            Some(name) => format!("hello, {name}!"),
+            None => "hello!".to_string(),
+        })
+        .register_fn("add", |x: INT, y: Option<INT>| x + y.unwrap_or(1))
+        .register_fn("add", |x: INT| x + 100)
+        .register_fn("add", |x: INT, y: bool| if y { x * 2 } else { x });
+
+    assert_eq!(engine.eval::<String>("greet(())")?, "hello!");
+    assert_eq!(engine.eval::<String>(r#"greet("world")"#)?, "hello, world!");
+    assert_eq!(engine.eval::<INT>("add(41, ())")?, 42);
+    assert_eq!(engine.eval::<INT>("add(40, 2)")?, 42);
+    assert_eq!(engine.eval::<INT>("let x; add(41, x)")?, 42);
+
+    // Overloads by arity and by more specific types are preferred
+    assert_eq!(engine.eval::<INT>("add(42)")?, 142);
+    assert_eq!(engine.eval::<INT>("add(21, true)")?, 42);
+
+    let err = engine
+        .eval::<INT>(r#"add(1, "x")"#)
+        .expect_err("should error");
+
+    match *err {
+        EvalAltResult::ErrorMismatchDataType(_, ref actual, ..) => assert_eq!(actual, "string"),
+        _ => panic!("wrong error: {err}"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_native_fallible() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.register_fn_fallible(
+        "div",
+        |x: INT, y: INT| -> Result<INT, Box<dyn std::error::Error>> {
+            if y == 0 {
+                Err("division by zero".into())
+            } else {
+                Ok(x / y)
+            }
+        },
+    );
+    engine.register_fn_fallible("parse", |s: &str| s.parse::<INT>());
+
+    assert_eq!(engine.eval::<INT>("div(84, 2)")?, 42);
+    assert_eq!(engine.eval::<INT>(r#"parse("42")"#)?, 42);
+
+    let err = engine
+        .eval::<INT>("let x = 1;\nx + div(x, 0)")
+        .expect_err("should error");
+
+    match *err {
+        EvalAltResult::ErrorRuntime(ref msg, ..) => {
+            assert_eq!(msg.to_string(), "division by zero")
+        }
+        _ => panic!("wrong error: {err}"),
+    }
+    #[cfg(not(feature = "no_position"))]
+    assert_eq!(err.position(), rhai::Position::new(2, 5));
+
+    assert!(matches!(
+        *engine
+            .eval::<INT>(r#"parse("hello")"#)
+            .expect_err("should error"),
+        EvalAltResult::ErrorRuntime(..)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_native_fallible_unit() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.register_fn(
+        "write_file",
+        |path: &str| -> Result<(), Box<EvalAltResult>> {
+            if path.is_empty() {
+                Err("empty file name".into())
+            } else {
+                Ok(())
+            }
+        },
+    );
+
+    assert_eq!(engine.eval::<INT>(r#"write_file("x"); 42"#)?, 42);
+    assert_eq!(engine.eval::<()>(r#"write_file("x")"#)?, ());
+
+    let err = engine
+        .eval::<INT>(r#"write_file("x"); write_file(""); 42"#)
+        .expect_err("should error");
+
+    match *err {
+        EvalAltResult::ErrorRuntime(ref msg, ..) => {
+            assert_eq!(msg.to_string(), "empty file name")
+        }
+        _ => panic!("wrong error: {err}"),
+    }
+
+    assert_eq!(
+        engine.eval::<String>(
+            r#"
+                try {
+                    write_file("");
+                    return "ok";
+                } catch (err) {
+                    return err;
+                }
+            "#
+        )?,
+        "empty file name"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_native_str_param_borrowed() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.register_fn("addr", |s: &str| s.as_ptr() as usize as INT);
+
+    let s = ImmutableString::from("hello, world!");
+    let addr = s.as_ptr() as usize as INT;
+
+    let mut scope = Scope::new();
+    scope.push("s", s);
+
+    // The string is not cloned when passed as a `&str`
+    assert_eq!(engine.eval_with_scope::<INT>(&mut scope, "addr(s)")?, addr);
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval_with_scope::<INT>(&mut scope, "s.addr()")?, addr);
+
+    Ok(())
+}
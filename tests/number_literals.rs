@@ -71,3 +71,79 @@ fn test_binary_literal() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[cfg(not(feature = "only_i32"))]
+#[cfg(not(feature = "only_i64"))]
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_i128_literal() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<i128>("42i128")?, 42);
+    assert_eq!(engine.eval::<i128>("-42i128")?, -42);
+    assert_eq!(engine.eval::<i128>("0xffi128")?, 255);
+    assert_eq!(
+        engine.eval::<i128>("170141183460469231731687303715884105727i128")?,
+        i128::MAX
+    );
+    assert_eq!(
+        engine.eval::<i128>("let x = 10_000_000_000i128; x * x * x")?,
+        1_000_000_000_000_000_000_000_000_000_000
+    );
+    assert_eq!(engine.eval::<i128>("to_i128(40) + 2i128")?, 42);
+    assert_eq!(engine.eval::<INT>("to_int(42i128)")?, 42);
+    assert!(engine.eval::<bool>("2i128 > 1i128")?);
+
+    #[cfg(not(feature = "unchecked"))]
+    assert!(matches!(
+        *engine
+            .eval::<i128>("170141183460469231731687303715884105727i128 + 1i128")
+            .expect_err("should error"),
+        EvalAltResult::ErrorArithmetic(..)
+    ));
+
+    assert!(engine.compile("42i64").is_err());
+    assert!(engine.compile("1.5i128").is_err());
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_float"))]
+#[test]
+fn test_custom_number_parser() -> Result<(), Box<EvalAltResult>> {
+    use rhai::FLOAT;
+
+    let mut engine = Engine::new();
+
+    engine.on_parse_number(|text| match text.replace(',', ".").parse::<FLOAT>() {
+        Ok(x) if text.contains(',') => Some(x.into()),
+        _ => None,
+    });
+
+    assert_eq!(engine.eval::<FLOAT>("3,25 * 2")?, 6.5);
+    assert_eq!(engine.eval::<FLOAT>("-1,5")?, -1.5);
+    assert_eq!(engine.eval::<FLOAT>("1.5")?, 1.5);
+    assert_eq!(
+        engine.eval::<INT>("let x = 0; for i in 1..5 { x += i } x")?,
+        10
+    );
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<String>("3.to_string()")?, "3");
+    #[cfg(not(feature = "no_index"))]
+    assert_eq!(engine.eval::<INT>("[1, 2, 3].len()")?, 3);
+    #[cfg(not(feature = "no_index"))]
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<INT>("[1,2,3].len()")?, 3);
+    #[cfg(not(feature = "no_index"))]
+    assert_eq!(engine.eval::<INT>("let a = [1,2,3]; a[0] + a[2]")?, 4);
+
+    engine.on_parse_number(|text| match text {
+        "42" => Some((0 as INT).into()),
+        _ => Some("hello".into()),
+    });
+
+    assert_eq!(engine.eval::<INT>("42")?, 0);
+    assert!(engine.eval::<INT>("1").is_err());
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 #![cfg(not(feature = "unchecked"))]
-use rhai::{Engine, EvalAltResult, INT};
+use rhai::{Engine, EvalAltResult, ProgressAction, INT};
 
 #[test]
 fn test_max_operations() -> Result<(), Box<EvalAltResult>> {
@@ -168,3 +168,34 @@ fn test_max_operations_progress() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+fn test_max_operations_progress_action() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+    #[cfg(not(feature = "no_optimize"))]
+    engine.set_optimization_level(rhai::OptimizationLevel::None);
+
+    engine.on_progress_action(|count| {
+        if count < 100 {
+            ProgressAction::Continue
+        } else {
+            ProgressAction::Abort((42 as INT).into())
+        }
+    });
+
+    engine.run("for x in 0..50 {}")?;
+
+    assert!(matches!(
+        *engine
+            .run("for x in 0..500 {}")
+            .expect_err("should error"),
+        EvalAltResult::ErrorTerminated(x, ..) if x.as_int()? == 42
+    ));
+
+    // Registering `on_progress` replaces the action callback
+    engine.on_progress(|_| None);
+
+    engine.run("for x in 0..500 {}")?;
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use rhai::{Engine, EvalAltResult, Scope, INT};
+use rhai::{Engine, EvalAltResult, LexError, ParseErrorType, Scope, INT};
 
 #[test]
 fn test_ops() -> Result<(), Box<EvalAltResult>> {
@@ -63,3 +63,34 @@ fn test_ops_precedence() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+fn test_ops_cast() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>("42 as int")?, 42);
+    assert_eq!(engine.eval::<String>(r#"42 as string + "!""#)?, "42!");
+    assert_eq!(engine.eval::<String>("-1 as string")?, "-1");
+    assert_eq!(engine.eval::<INT>("'a' as int")?, 97);
+
+    #[cfg(not(feature = "no_float"))]
+    {
+        assert_eq!(
+            engine.eval::<rhai::FLOAT>("let x = 41; x as float + 1")?,
+            42.0
+        );
+        assert_eq!(engine.eval::<rhai::FLOAT>("2 * 3 as float")?, 6.0);
+        assert_eq!(engine.eval::<INT>("1.9 as int")?, 1);
+    }
+
+    assert!(matches!(
+        *engine.eval::<INT>(r#""hello" as int"#).expect_err("should error"),
+        EvalAltResult::ErrorFunctionNotFound(f, ..) if f.starts_with("to_int")
+    ));
+    assert!(matches!(
+        engine.compile("42 as foo").expect_err("should error").err_type(),
+        ParseErrorType::BadInput(LexError::ImproperSymbol(s, ..)) if s == "foo"
+    ));
+
+    Ok(())
+}
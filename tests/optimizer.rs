@@ -163,3 +163,148 @@ fn test_optimizer_scope() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[cfg(not(feature = "no_function"))]
+#[test]
+fn test_optimizer_unused_functions() -> Result<(), Box<EvalAltResult>> {
+    const SCRIPT: &str = r#"
+        fn api() { 0 }
+        private fn used() { nested() }
+        private fn nested() { 1 }
+        private fn by_name() { 2 }
+        private fn unused() { dead() }
+        private fn dead() { 3 }
+        let f = Fn("by_name");
+        used() + f.call()
+    "#;
+
+    let mut engine = Engine::new();
+
+    engine.set_optimization_level(OptimizationLevel::Simple);
+    let ast = engine.compile(SCRIPT)?;
+    assert_eq!(ast.iter_functions().count(), 6);
+
+    // Private functions are kept by default
+    engine.set_optimization_level(OptimizationLevel::Full);
+    let ast = engine.compile(SCRIPT)?;
+    assert_eq!(ast.iter_functions().count(), 6);
+    assert_eq!(
+        engine.call_fn::<INT>(&mut Scope::new(), &ast, "unused", ())?,
+        3
+    );
+
+    engine.set_optimization_remove_unused_functions(true);
+    let ast = engine.compile(SCRIPT)?;
+    let mut names: Vec<_> = ast.iter_functions().map(|f| f.name).collect();
+    names.sort();
+    assert_eq!(names, ["api", "by_name", "nested", "used"]);
+    assert_eq!(engine.eval_ast::<INT>(&ast)?, 3);
+
+    // Any call to `eval` keeps all functions
+    let ast = engine.compile(format!("{SCRIPT}; eval(\"\")"))?;
+    assert_eq!(ast.iter_functions().count(), 6);
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_function"))]
+#[test]
+fn test_optimizer_exclusions() -> Result<(), Box<EvalAltResult>> {
+    const SCRIPT: &str = "fn foo(x) { const Y = 1; if Y == 1 { x + 40 + 2 } else { 0 } }";
+
+    let mut engine = Engine::new();
+
+    engine.set_optimization_level(OptimizationLevel::None);
+    let unoptimized = format!("{:?}", engine.compile(SCRIPT)?);
+
+    engine.set_optimization_level(OptimizationLevel::Full);
+    let optimized = format!("{:?}", engine.compile(SCRIPT)?);
+    assert_ne!(optimized, unoptimized);
+
+    engine.set_optimize_exclusions(&["foo"]);
+    assert!(engine.is_optimize_excluded("foo"));
+    let ast = engine.compile(SCRIPT)?;
+    assert_eq!(format!("{ast:?}"), unoptimized);
+
+    assert!(!engine.is_optimize_excluded("bar"));
+    let ast = engine.compile(format!(
+        "{SCRIPT} {} foo(1) + bar(2)",
+        SCRIPT.replace("foo", "bar")
+    ))?;
+    assert_eq!(engine.eval_ast::<INT>(&ast)?, 87);
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_index"))]
+#[cfg(not(feature = "no_object"))]
+#[test]
+fn test_optimizer_constant_collections() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+    engine.set_optimization_level(OptimizationLevel::Full);
+
+    assert_eq!(
+        engine.eval::<INT>(
+            r#"
+                let sum = 0;
+                for i in 0..6 {
+                    sum += [10, 20, 30][i % 3] + #{a: 1, b: 2}[if i < 3 { "a" } else { "b" }];
+                }
+                sum
+            "#
+        )?,
+        129
+    );
+    assert_eq!(engine.eval::<INT>("let i = -1; [10, 20, 30][i]")?, 30);
+    assert!(engine.eval::<()>(r#"let k = "x"; #{a: 1}[k]"#).is_ok());
+    assert!(engine.run("let i = 3; [10, 20, 30][i]").is_err());
+
+    // Is the array literal folded into a constant?
+    #[cfg(feature = "internals")]
+    let is_folded = |ast: &rhai::AST| {
+        use rhai::{ASTNode, Array, Expr};
+
+        let mut folded = false;
+        let mut literal = false;
+
+        ast.walk(&mut |path| {
+            match path.last() {
+                Some(ASTNode::Expr(Expr::DynamicConstant(v, ..))) if v.is::<Array>() => {
+                    folded = true
+                }
+                Some(ASTNode::Expr(Expr::Array(..))) => literal = true,
+                _ => (),
+            }
+            true
+        });
+
+        folded && !literal
+    };
+
+    let _ast = engine.compile("let x = [1, 2, 3]; x[1]")?;
+    #[cfg(feature = "internals")]
+    assert!(is_folded(&_ast));
+
+    engine.set_optimization_constant_array_folding(false);
+
+    let _ast = engine.compile("let x = [1, 2, 3]; x[1]")?;
+    #[cfg(feature = "internals")]
+    assert!(!is_folded(&_ast));
+    assert_eq!(engine.eval::<INT>("[10, 20, 30][1] + #{a: 1}.a")?, 21);
+
+    engine.set_optimization_constant_array_folding(true);
+
+    // Constants are copied before mutation
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let total = 0;
+                for i in 0..3 { let x = [1, 2, 3]; x[0] += i; x.push(i); total += x[0] + x.len(); }
+                total
+            "
+        )?,
+        18
+    );
+
+    Ok(())
+}
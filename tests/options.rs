@@ -1,4 +1,4 @@
-use rhai::{Engine, EvalAltResult, Scope, INT};
+use rhai::{Dynamic, Engine, EvalAltResult, Scope, INT};
 
 #[test]
 fn test_options_allow() -> Result<(), Box<EvalAltResult>> {
@@ -23,6 +23,17 @@ fn test_options_allow() -> Result<(), Box<EvalAltResult>> {
         engine.set_allow_anonymous_fn(false);
 
         assert!(engine.compile("let x = || 42;").is_err());
+
+        for script in ["let x = || 42;", "let x = |a| a + 1;", "foo { |a| a + 1 }"] {
+            assert_eq!(
+                *engine.compile(script).expect_err("should error").err_type(),
+                rhai::ParseErrorType::ForbiddenAnonymousFn,
+                "{}",
+                script
+            );
+        }
+
+        engine.compile("fn foo(a) { a + 1 } let x = Fn(\"foo\");")?;
     }
 
     let ast = engine.compile("let x = 0; while x < 10 { x += 1; }")?;
@@ -128,3 +139,71 @@ fn test_options_strict_var() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+fn test_options_default_value_tag() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    assert_eq!(engine.eval::<Dynamic>("42")?.tag(), 0);
+
+    engine.set_default_value_tag(123);
+
+    assert_eq!(engine.eval::<Dynamic>("42")?.tag(), 123);
+    assert_eq!(engine.eval::<Dynamic>("40 + 2")?.tag(), 123);
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<Dynamic>(r#""hello".len()"#)?.tag(), 123);
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(engine.eval::<Dynamic>("let x = 42; x.tag = 1; x")?.tag(), 1);
+
+    let mut scope = Scope::new();
+    scope.push("x", 42 as INT);
+
+    assert_eq!(engine.eval_with_scope::<Dynamic>(&mut scope, "x")?.tag(), 0);
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_position"))]
+#[test]
+fn test_options_newline_as_statement_end() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    let script = "
+        let x = 40
+        let y = 2
+        x + y
+    ";
+
+    assert!(engine.compile(script).is_err());
+
+    engine.set_allow_newline_as_statement_end(true);
+
+    assert_eq!(engine.eval::<INT>(script)?, 42);
+    assert_eq!(engine.eval::<INT>("let x = 40; let y = 2; x + y")?, 42);
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let x = 40 +
+                    1
+                {
+                    let y = x
+                    y + 1
+                }
+            "
+        )?,
+        42
+    );
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let x = 40
+                    + 2
+                x
+            "
+        )?,
+        42
+    );
+    assert!(engine.compile("let x = 40 let y = 2").is_err());
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use rhai::{Engine, EvalAltResult, Scope, INT};
+use rhai::{Engine, EvalAltResult, LogLevel, Scope, INT};
 use std::sync::{Arc, RwLock};
 
 #[cfg(not(feature = "only_i32"))]
@@ -72,6 +72,75 @@ fn test_print_debug() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[cfg(not(feature = "no_index"))]
+#[cfg(not(feature = "no_object"))]
+#[test]
+fn test_print_debug_pretty() -> Result<(), Box<EvalAltResult>> {
+    let logbook = Arc::new(RwLock::new(Vec::<String>::new()));
+
+    let log = logbook.clone();
+    let mut engine = Engine::new();
+
+    engine.on_debug(move |s, ..| log.write().unwrap().push(s.to_string()));
+    engine.set_debug_pretty(true);
+    engine.set_debug_indent(2);
+
+    engine.run(r#"debug([1, #{a: [], b: "x"}])"#)?;
+
+    assert_eq!(
+        logbook.read().unwrap()[0],
+        "[\n  1,\n  #{\n    \"a\": [],\n    \"b\": \"x\",\n  },\n]"
+    );
+
+    // Only `debug` is affected
+    assert_eq!(
+        engine.eval::<String>(r#"let x = [1, [2]]; `${x.to_debug()} ${x}`"#)?,
+        "[1, [2]] [1, [2]]"
+    );
+
+    engine.set_debug_pretty(false);
+    engine.run("debug([1, [2]])")?;
+    assert_eq!(logbook.read().unwrap()[1], "[1, [2]]");
+
+    Ok(())
+}
+
+#[test]
+fn test_print_log() -> Result<(), Box<EvalAltResult>> {
+    let logbook = Arc::new(RwLock::new(Vec::<(LogLevel, String)>::new()));
+
+    let log = logbook.clone();
+    let mut engine = Engine::new();
+
+    engine.on_log(move |level, s| log.write().unwrap().push((level, s.to_string())));
+
+    engine.run(r#"log("info", 40 + 2); log("WARN", "careful"); print("hello")"#)?;
+
+    assert_eq!(
+        *logbook.read().unwrap(),
+        [
+            (LogLevel::Info, "42".to_string()),
+            (LogLevel::Warn, "careful".to_string())
+        ]
+    );
+
+    assert!(engine.run(r#"log("loud", 1)"#).is_err());
+
+    // Logging is not optimized away
+    #[cfg(not(feature = "no_optimize"))]
+    {
+        logbook.write().unwrap().clear();
+        engine.set_optimization_level(rhai::OptimizationLevel::Full);
+        engine.run(r#"log("error", "oops")"#)?;
+        assert_eq!(
+            *logbook.read().unwrap(),
+            [(LogLevel::Error, "oops".to_string())]
+        );
+    }
+
+    Ok(())
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
 struct MyStruct {
     field: INT,
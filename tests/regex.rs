@@ -0,0 +1,45 @@
+#![cfg(feature = "regex")]
+#![cfg(not(feature = "no_object"))]
+use rhai::{Engine, EvalAltResult};
+
+#[test]
+fn test_regex() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert!(engine.eval::<bool>(r#"regex_match("ERROR 2022-10-14", `\d{4}-\d{2}-\d{2}`)"#)?);
+    assert!(!engine.eval::<bool>(r#""hello".regex_match("^WARN")"#)?);
+    assert_eq!(
+        engine.eval::<String>(r#""2022-10-14".regex_replace(`(\d+)-(\d+)-(\d+)`, "$3/$2/$1")"#)?,
+        "14/10/2022"
+    );
+    assert_eq!(
+        engine.eval::<String>(r#""hello".regex_replace(`\d+`, "x")"#)?,
+        "hello"
+    );
+
+    #[cfg(not(feature = "no_index"))]
+    {
+        assert_eq!(
+            engine
+                .eval::<rhai::Dynamic>(r#""a1 b22 c333".regex_find_all(`\d+`)"#)?
+                .into_typed_array::<String>()?,
+            ["1", "22", "333"]
+        );
+        assert_eq!(
+            engine
+                .eval::<rhai::Dynamic>(r#""key = value".regex_captures(`(\w+)\s*=\s*(\w+)`)"#)?
+                .into_typed_array::<String>()?,
+            ["key = value", "key", "value"]
+        );
+        assert!(engine.eval::<()>(r#""key".regex_captures(`\d+`)"#).is_ok());
+    }
+
+    assert!(matches!(
+        *engine
+            .eval::<bool>(r#""hello".regex_match("(")"#)
+            .expect_err("should error"),
+        EvalAltResult::ErrorRuntime(..)
+    ));
+
+    Ok(())
+}
@@ -854,3 +854,48 @@ fn test_serde_scope() {
         "serde::test_serde_scope::TestStruct"
     );
 }
+
+#[test]
+#[cfg(not(feature = "no_index"))]
+#[cfg(not(feature = "no_object"))]
+fn test_serde_json_value() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let value = json!({
+        "a": 42,
+        "b": [true, null, "hello"],
+        "c": { "d": 'x' },
+    });
+
+    let d = Dynamic::from_json_value(&value);
+    let map = d.clone().cast::<Map>();
+
+    assert_eq!(map["a"].as_int().unwrap(), 42);
+    assert!(map["b"].is::<Array>());
+    assert!(map["c"].is::<Map>());
+    assert_eq!(d.to_json_value()?, value);
+
+    #[cfg(not(feature = "no_float"))]
+    assert_eq!(
+        Dynamic::from_json_value(&json!(1.5)).as_float().unwrap(),
+        1.5 as FLOAT
+    );
+
+    let result = engine.eval::<Dynamic>(r#"#{ a: [1, 2, ()], b: 'x', c: blob(2, 1) }"#)?;
+    assert_eq!(
+        result.to_json_value()?,
+        json!({ "a": [1, 2, null], "b": "x", "c": [1, 1] })
+    );
+
+    #[derive(Clone)]
+    struct TestStruct;
+
+    let mut map = Map::new();
+    map.insert("x".into(), Dynamic::from(TestStruct));
+    assert!(Dynamic::from_map(map).to_json_value().is_err());
+
+    #[cfg(not(feature = "no_float"))]
+    assert!(Dynamic::from_float(FLOAT::NAN).to_json_value().is_err());
+
+    Ok(())
+}
@@ -35,6 +35,51 @@ fn test_stack_overflow_fn_calls() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(feature = "no_function"))]
+#[cfg(not(feature = "no_object"))]
+fn test_stack_overflow_closure_calls() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.set_max_call_levels(5);
+
+    let script = "
+        let f = |n, f| if n == 0 { 0 } else { 1 + f.call(n - 1, f) };
+        f.call(12, f)
+    ";
+
+    assert!(matches!(
+        *engine.eval::<INT>(script).expect_err("should error"),
+        EvalAltResult::ErrorStackOverflow(..)
+    ));
+
+    engine.set_max_closure_depth(20);
+
+    assert_eq!(engine.eval::<INT>(script)?, 12);
+    assert!(matches!(
+        *engine
+            .eval::<INT>(&script.replace("12", "30"))
+            .expect_err("should error"),
+        EvalAltResult::ErrorClosureStackOverflow(..)
+    ));
+
+    // Normal functions called from closures are still limited
+    assert!(matches!(
+        *engine
+            .run(
+                "
+                    fn foo(n) { if n == 0 { 0 } else { n + foo(n-1) } }
+                    let f = |n| foo(n);
+                    f.call(10)
+                "
+            )
+            .expect_err("should error"),
+        EvalAltResult::ErrorStackOverflow(..)
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn test_stack_overflow_parsing() -> Result<(), Box<EvalAltResult>> {
     let mut engine = Engine::new();
@@ -241,6 +241,46 @@ fn test_string_substring() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[cfg(not(feature = "no_object"))]
+#[test]
+fn test_string_char_byte_at() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>(r#"let x = "h❤llo"; x.len"#)?, 5);
+    assert_eq!(engine.eval::<INT>(r#"let x = "h❤llo"; x.bytes"#)?, 7);
+    assert_eq!(engine.eval::<bool>(r#"let x = ""; x.is_empty"#)?, true);
+    assert_eq!(
+        engine.eval::<char>(r#"let x = "h❤llo"; x.char_at(1)"#)?,
+        '❤'
+    );
+    assert_eq!(
+        engine.eval::<char>(r#"let x = "h❤llo"; x.char_at(-1)"#)?,
+        'o'
+    );
+    assert_eq!(
+        engine.eval::<INT>(r#"let x = "h❤llo"; x.byte_at(1)"#)?,
+        0xe2
+    );
+    assert_eq!(
+        engine.eval::<INT>(r#"let x = "h❤llo"; x.byte_at(-7)"#)?,
+        'h' as INT
+    );
+    assert!(matches!(
+        *engine
+            .eval::<char>(r#"let x = "h❤llo"; x.char_at(5)"#)
+            .expect_err("should error"),
+        EvalAltResult::ErrorStringBounds(5, 5, ..)
+    ));
+    assert!(matches!(
+        *engine
+            .eval::<INT>(r#"let x = "h❤llo"; x.byte_at(-8)"#)
+            .expect_err("should error"),
+        EvalAltResult::ErrorStringBounds(7, -8, ..)
+    ));
+
+    Ok(())
+}
+
 #[cfg(not(feature = "no_object"))]
 #[test]
 fn test_string_format() -> Result<(), Box<EvalAltResult>> {
@@ -326,6 +366,207 @@ fn test_string_split() -> Result<(), Box<EvalAltResult>> {
         )?,
         2
     );
+    assert_eq!(
+        engine
+            .eval::<rhai::Array>(r#""hello\r\nworld\n\n!\n".lines()"#)?
+            .into_iter()
+            .map(|v| v.into_string().unwrap())
+            .collect::<Vec<_>>(),
+        ["hello", "world", "", "!"]
+    );
+    assert_eq!(engine.eval::<INT>(r#""".lines().len"#)?, 0);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_string_search() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert!(engine.eval::<bool>(r#""hello, world!".contains("world")"#)?);
+    assert!(!engine.eval::<bool>(r#""hello, world!".contains("xyz")"#)?);
+    assert_eq!(
+        engine.eval::<INT>(r#""\u2764 hello, hello!".index_of("hello")"#)?,
+        2
+    );
+    assert_eq!(
+        engine.eval::<INT>(r#""\u2764 hello, hello!".index_of("hello", 3)"#)?,
+        9
+    );
+    assert_eq!(
+        engine.eval::<INT>(r#""\u2764 hello, hello!".rindex_of("hello")"#)?,
+        9
+    );
+    assert_eq!(
+        engine.eval::<INT>(r#""\u2764 hello, hello!".rindex_of('l')"#)?,
+        12
+    );
+    assert_eq!(engine.eval::<INT>(r#""hello".rindex_of("xyz")"#)?, -1);
+    assert_eq!(engine.eval::<INT>(r#""hello".rindex_of('x')"#)?, -1);
+
+    #[cfg(not(feature = "no_index"))]
+    assert_eq!(
+        engine
+            .eval::<rhai::Array>(r#""a--b----c--".split("--")"#)?
+            .into_iter()
+            .map(|v| v.into_string().unwrap())
+            .collect::<Vec<_>>(),
+        ["a", "b", "", "c", ""]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_string_trim() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<String>(r#"let s = "  hello  "; s.trim_start(); s"#)?,
+        "hello  "
+    );
+    assert_eq!(
+        engine.eval::<String>(r#"let s = "  hello  "; s.trim_end(); s"#)?,
+        "  hello"
+    );
+    assert_eq!(
+        engine.eval::<String>(r#"let s = "--==hello=-"; s.trim_matches("=-"); s"#)?,
+        "hello"
+    );
+    assert_eq!(
+        engine.eval::<String>(r#"let s = "**hello*"; s.trim_matches('*'); s"#)?,
+        "hello"
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_string_repeat() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(engine.eval::<String>(r#""ab".repeat(3)"#)?, "ababab");
+    assert_eq!(engine.eval::<String>(r#"repeat("ab", 1)"#)?, "ab");
+    assert_eq!(engine.eval::<String>(r#""ab".repeat(0)"#)?, "");
+    assert_eq!(engine.eval::<String>(r#""ab".repeat(-1)"#)?, "");
+    assert_eq!(engine.eval::<String>(r#""-" * 10"#)?, "----------");
+    assert_eq!(engine.eval::<String>(r#""❤" * 3"#)?, "❤❤❤");
+    assert_eq!(engine.eval::<String>("'=' * 5")?, "=====");
+    assert_eq!(engine.eval::<String>("'='.repeat(2)")?, "==");
+    assert_eq!(engine.eval::<String>("'=' * -5")?, "");
+    assert_eq!(engine.eval::<String>(r#"let x = "ab"; x *= 2; x"#)?, "abab");
+
+    // Sizes over the limit are errors, not panics or huge allocations
+    #[cfg(not(feature = "unchecked"))]
+    {
+        let mut engine = Engine::new();
+        engine.set_max_string_size(100);
+
+        assert_eq!(engine.eval::<String>(r#""ab" * 50"#)?.len(), 100);
+        assert!(matches!(
+            *engine
+                .eval::<String>(r#""ab" * 51"#)
+                .expect_err("should error"),
+            EvalAltResult::ErrorDataTooLarge(..)
+        ));
+        assert!(matches!(
+            *engine
+                .eval::<String>(&format!(r#""ab" * {}"#, INT::MAX))
+                .expect_err("should error"),
+            EvalAltResult::ErrorDataTooLarge(..)
+        ));
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_string_pad() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<String>(r#"let s = "42"; s.pad_start(5, '0'); s"#)?,
+        "00042"
+    );
+    assert_eq!(
+        engine.eval::<String>(r#"let s = "hello"; s.pad_end(8, '.'); s"#)?,
+        "hello..."
+    );
+    assert_eq!(
+        engine.eval::<String>(r#"let s = "hello"; s.pad_start(10, "-="); s"#)?,
+        "-=-=-hello"
+    );
+    assert_eq!(
+        engine.eval::<String>(r#"let s = "hello"; s.pad_end(10, "-="); s"#)?,
+        "hello-=-=-"
+    );
+
+    // Width is measured in characters
+    assert_eq!(
+        engine.eval::<String>(r#"let s = "héllo"; s.pad_start(7, 'ü'); s"#)?,
+        "üühéllo"
+    );
+
+    // Longer strings and empty padding are untouched
+    assert_eq!(
+        engine.eval::<String>(r#"let s = "hello"; s.pad_start(3, '*'); s.pad_end(0, '*'); s"#)?,
+        "hello"
+    );
+    assert_eq!(
+        engine.eval::<String>(r#"let s = "hello"; s.pad_end(10, ""); s"#)?,
+        "hello"
+    );
+
+    assert!(engine
+        .run(r#"let s = "hello"; s.pad_start(-1, '*');"#)
+        .is_err());
+    assert!(engine
+        .run(r#"let s = "hello"; s.pad_end(-1, "*");"#)
+        .is_err());
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_object"))]
+#[cfg(not(feature = "no_index"))]
+#[test]
+fn test_string_format_template() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine.eval::<String>(r#""{0} + {0} = {1}".format([21, 42])"#)?,
+        "21 + 21 = 42"
+    );
+    assert_eq!(
+        engine.eval::<String>(r#""{} is {}".format(["x", true])"#)?,
+        "x is true"
+    );
+    assert_eq!(
+        engine.eval::<String>(r#""{name} is {age}".format(#{name: "Bob", age: 42})"#)?,
+        "Bob is 42"
+    );
+    assert_eq!(
+        engine.eval::<String>(r#""{0} is {name}".format([42], #{name: "answer"})"#)?,
+        "42 is answer"
+    );
+    assert_eq!(
+        engine.eval::<String>(r#""{{{0}}} {{x}}".format([1])"#)?,
+        "{1} {x}"
+    );
+    assert_eq!(
+        engine.eval::<String>(r#""{name} is {age}".format(#{name: "Bob"})"#)?,
+        "Bob is {age}"
+    );
+
+    assert!(engine
+        .eval::<String>(r#""{name} is {age}".format(#{name: "Bob"}, true)"#)
+        .is_err());
+    assert!(engine.eval::<String>(r#""{0".format([1])"#).is_err());
+    assert!(engine.eval::<String>(r#""0}".format([1])"#).is_err());
 
     Ok(())
 }
@@ -416,3 +657,83 @@ Undeniable logic:
 
     Ok(())
 }
+
+#[test]
+fn test_string_interned_identifiers() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let x = engine.intern_identifier("hello");
+    let y = engine.intern_identifier("hello");
+    assert!(x.ptr_eq(&y));
+
+    engine.compile("let foo = 42; foo + 1")?;
+    let n = engine.num_interned_strings();
+    assert!(n > 1);
+
+    engine.compile("let foo = 1; foo * 2")?;
+    assert_eq!(engine.num_interned_strings(), n);
+
+    assert!(engine
+        .intern_identifier("foo")
+        .ptr_eq(&engine.intern_identifier("foo")));
+
+    let script = (0..2000)
+        .map(|i| format!("let v{i} = {i};"))
+        .collect::<String>();
+    engine.compile(script)?;
+    assert!(engine.num_interned_strings() <= 1024);
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_index"))]
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_string_char_indices() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let pairs = engine
+        .eval::<rhai::Array>(r#"let x = "h❤llo"; x.char_indices()"#)?
+        .into_iter()
+        .map(|pair| {
+            let pair = pair.cast::<rhai::Array>();
+            (pair[0].as_int().unwrap(), pair[1].as_char().unwrap())
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(pairs, [(0, 'h'), (1, '❤'), (4, 'l'), (5, 'l'), (6, 'o')]);
+
+    assert_eq!(
+        engine.eval::<INT>(
+            r#"
+                let x = "h❤llo";
+                let pair = x.char_indices()[2];
+                x.byte_at(pair[0])
+            "#
+        )?,
+        'l' as INT
+    );
+    assert_eq!(engine.eval::<INT>(r#""".char_indices().len"#)?, 0);
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_index"))]
+#[cfg(feature = "unicode")]
+#[test]
+#[cfg(not(feature = "no_object"))]
+fn test_string_graphemes() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    assert_eq!(
+        engine
+            .eval::<rhai::Array>(r#""e\u0301👍🏽!".graphemes()"#)?
+            .into_iter()
+            .map(|s| s.into_string().unwrap())
+            .collect::<Vec<_>>(),
+        ["e\u{301}", "👍🏽", "!"]
+    );
+    assert_eq!(engine.eval::<INT>(r#""e\u0301👍🏽!".to_chars().len"#)?, 5);
+
+    Ok(())
+}
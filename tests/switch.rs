@@ -125,6 +125,37 @@ fn test_switch_errors() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[test]
+fn test_switch_strict() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.run("let x = 3; switch x { 1 => 42, 2 => 123 }")?;
+
+    engine.set_strict_switch(true);
+
+    assert!(matches!(
+        engine
+            .compile("let x = 3; switch x { 1 => 42, 2 => 123 }")
+            .expect_err("should error")
+            .err_type(),
+        ParseErrorType::MissingSwitchDefaultCase
+    ));
+    assert!(matches!(
+        engine
+            .compile("let x = 3; let y = switch x { 1 => 42, 2..5 => 123 };")
+            .expect_err("should error")
+            .err_type(),
+        ParseErrorType::MissingSwitchDefaultCase
+    ));
+
+    assert_eq!(
+        engine.eval::<INT>("let x = 3; switch x { 1 => 42, 2 => 123, _ => 0 }")?,
+        0
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_switch_condition() -> Result<(), Box<EvalAltResult>> {
     let engine = Engine::new();
@@ -30,6 +30,41 @@ fn test_tokens_disabled() {
     ));
 }
 
+#[test]
+fn test_tokens_reserved() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    assert_eq!(engine.eval::<INT>("let rule = 40; rule + 2")?, 42);
+
+    engine.reserve_keyword("entity")?.reserve_keyword("rule")?;
+
+    assert!(matches!(
+        engine.compile("let rule = 42;").expect_err("should error").err_type(),
+        ParseErrorType::Reserved(err) if err == "rule"
+    ));
+    assert!(matches!(
+        engine.compile("entity + 1").expect_err("should error").err_type(),
+        ParseErrorType::Reserved(err) if err == "entity"
+    ));
+    #[cfg(not(feature = "no_function"))]
+    assert!(matches!(
+        engine
+            .compile("fn foo(entity) { entity }")
+            .expect_err("should error")
+            .err_type(),
+        ParseErrorType::Reserved(err) if err == "entity"
+    ));
+
+    assert_eq!(engine.eval::<INT>("let entities = 40; entities + 2")?, 42);
+
+    assert!(engine.reserve_keyword("let").is_err());
+    assert!(engine.reserve_keyword("+=").is_err());
+    assert!(engine.reserve_keyword("my-word").is_err());
+    assert!(engine.reserve_keyword("42").is_err());
+
+    Ok(())
+}
+
 #[cfg(not(feature = "no_custom_syntax"))]
 #[test]
 fn test_tokens_custom_operator_identifiers() -> Result<(), Box<EvalAltResult>> {
@@ -85,6 +120,45 @@ fn test_tokens_custom_operator_symbol() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[cfg(not(feature = "no_custom_syntax"))]
+#[test]
+fn test_tokens_custom_operator_assignment() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.register_custom_operator("#", 160).unwrap();
+    engine.register_fn("#", |x: INT, y: INT| (x * y) - (x + y));
+
+    // Expands to `x = x # 4`
+    assert_eq!(engine.eval::<INT>("let x = 3; x #= 4; x")?, 5);
+    #[cfg(not(feature = "no_index"))]
+    assert_eq!(engine.eval::<INT>("let x = [3]; x[0] #= 4; x[0]")?, 5);
+
+    // Not an op-assignment when separated
+    assert!(engine.eval::<INT>("let x = 3; x # = 4; x").is_err());
+
+    // Script-defined custom operator
+    engine.register_custom_operator("foo", 160).unwrap();
+
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                fn foo(x, y) { y - x }
+                let x = 3;
+                x foo= 10;
+                x
+            "
+        )?,
+        7
+    );
+
+    // Registered op-assignment function takes priority
+    engine.register_fn("#=", |x: &mut INT, y: INT| *x = *x * 10 + y);
+    assert_eq!(engine.eval::<INT>("let x = 3; x #= 4; x")?, 34);
+
+    Ok(())
+}
+
 #[test]
 fn test_tokens_unicode_xid_ident() -> Result<(), Box<EvalAltResult>> {
     let engine = Engine::new();
@@ -110,3 +184,44 @@ fn test_tokens_unicode_xid_ident() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+fn test_tokens_script_flags() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    let script = "
+        let x = 1;
+        #if FOO
+            x += 10;
+            #if BAR
+                x += 100;
+            #else
+                x += 1000;
+            #endif
+        #else
+            x += 2;
+        #endif
+        x
+    ";
+
+    assert_eq!(engine.eval::<INT>(script)?, 3);
+
+    engine.set_script_flag("FOO", true);
+    assert_eq!(engine.eval::<INT>(script)?, 1011);
+
+    engine.set_script_flag("BAR", true);
+    assert_eq!(engine.eval::<INT>(script)?, 111);
+
+    engine.set_script_flag("FOO", false);
+    assert_eq!(engine.eval::<INT>(script)?, 3);
+    assert!(engine.script_flag("BAR"));
+    assert!(!engine.script_flag("FOO"));
+
+    assert!(engine.compile("#if FOO 42").is_err());
+    assert!(engine.compile("#if BAR 42").is_err());
+    assert!(engine.compile("42 #endif").is_err());
+    assert!(engine.compile("#if BAR 1 #else 2 #else 3 #endif").is_err());
+    assert!(engine.compile("#if 42 #endif").is_err());
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use rhai::{Engine, EvalAltResult, INT};
+use rhai::{Dynamic, Engine, EvalAltResult, PathSegment, INT};
 
 #[test]
 fn test_type_of() -> Result<(), Box<EvalAltResult>> {
@@ -57,3 +57,198 @@ fn test_type_of() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[test]
+fn test_type_name_of() {
+    #[derive(Clone)]
+    struct TestStruct;
+
+    let mut engine = Engine::new();
+
+    let value = rhai::Dynamic::from(TestStruct);
+
+    assert_eq!(engine.type_name_of(value.type_id()), None);
+
+    engine.register_type_with_name::<TestStruct>("Hello");
+
+    assert_eq!(engine.type_name_of(value.type_id()), Some("Hello"));
+    assert_eq!(value.type_id(), std::any::TypeId::of::<TestStruct>());
+    assert_eq!(engine.type_name_of(std::any::TypeId::of::<INT>()), None);
+}
+
+#[cfg(not(feature = "no_index"))]
+#[cfg(not(feature = "no_object"))]
+#[test]
+fn test_type_calc_hash() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let a = engine.eval::<rhai::Dynamic>(r#"[1, "x", #{a: [true, 'c']}]"#)?;
+    let b = engine.eval::<rhai::Dynamic>(r#"[1, "x", #{a: [true, 'c']}]"#)?;
+    let c = engine.eval::<rhai::Dynamic>(r#"[1, "x", #{a: [true, 'd']}]"#)?;
+
+    assert!(a.calc_hash().is_some());
+    assert_eq!(a.calc_hash(), b.calc_hash());
+    assert_ne!(a.calc_hash(), c.calc_hash());
+
+    assert!(engine
+        .eval::<rhai::Dynamic>("[1, Fn(\"foo\")]")?
+        .calc_hash()
+        .is_none());
+    assert!(engine
+        .eval::<rhai::Dynamic>("#{a: Fn(\"foo\")}")?
+        .calc_hash()
+        .is_none());
+
+    // Shared values hash as the values they hold
+    #[cfg(not(feature = "no_closure"))]
+    assert_eq!(a.clone().into_shared().calc_hash(), b.calc_hash());
+
+    Ok(())
+}
+
+#[test]
+fn test_type_no_clone() -> Result<(), Box<EvalAltResult>> {
+    #[derive(Debug, Clone)]
+    struct Handle {
+        id: INT,
+    }
+
+    let mut engine = Engine::new();
+
+    engine
+        .register_type_with_name::<Handle>("Handle")
+        .register_type_no_clone::<Handle>()
+        .register_fn("open", |id: INT| Handle { id })
+        .register_fn("id", |h: &mut Handle| h.id)
+        .register_fn("set_id", |h: &mut Handle, id: INT| h.id = id)
+        .register_fn("pair", |x: INT, h: Handle| x + h.id)
+        .register_fn("consume", |h: Handle| h.id);
+
+    // Method calls and first arguments do not move the value
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(
+        engine.eval::<INT>("let h = open(42); h.id() + id(h) + h.id()")?,
+        126
+    );
+
+    // Assignments move the value
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(
+        engine.eval::<INT>("let h = open(42); let g = h; g.id()")?,
+        42
+    );
+    assert_eq!(
+        engine.eval::<String>("let h = open(42); let g = h; type_of(h)")?,
+        "()"
+    );
+    #[cfg(not(feature = "no_object"))]
+    assert!(engine.run("let h = open(42); let g = h; h.id()").is_err());
+
+    // Passing by value moves the value
+    assert_eq!(
+        engine.eval::<String>("let h = open(42); pair(1, h); type_of(h)")?,
+        "()"
+    );
+
+    // Passing by value as the first argument moves the value
+    assert_eq!(
+        engine.eval::<String>("let h = open(42); consume(h); type_of(h)")?,
+        "()"
+    );
+    assert_eq!(engine.eval::<INT>("let h = open(42); consume(h)")?, 42);
+    #[cfg(not(feature = "no_object"))]
+    assert_eq!(
+        engine.eval::<String>("let h = open(42); h.consume(); type_of(h)")?,
+        "()"
+    );
+    #[cfg(not(feature = "no_function"))]
+    assert_eq!(
+        engine.eval::<String>("fn take(h) { id(h) } let h = open(42); take(h); type_of(h)")?,
+        "()"
+    );
+
+    // Constants cannot be moved or modified
+    assert_eq!(
+        engine.eval::<INT>("let h = open(1); set_id(h, 2); id(h)")?,
+        2
+    );
+    assert!(engine
+        .run("const h = open(1); set_id(h, 2); id(h)")
+        .is_err());
+    assert!(engine.run("const h = open(42); id(h)").is_err());
+    assert!(engine.run("const h = open(42); consume(h);").is_err());
+    #[cfg(not(feature = "no_object"))]
+    assert!(engine.run("const h = open(42); h.consume();").is_err());
+
+    // Captured values cannot be moved
+    #[cfg(not(feature = "no_function"))]
+    #[cfg(not(feature = "no_closure"))]
+    #[cfg(not(feature = "no_object"))]
+    assert!(engine
+        .run("let h = open(42); let f = || h.id(); consume(h);")
+        .is_err());
+
+    let err = engine
+        .run("const h = open(42); let g = h;")
+        .expect_err("should error");
+    assert!(
+        matches!(*err, EvalAltResult::ErrorRuntime(ref msg, ..)
+            if msg.to_string() == "Value of type 'Handle' cannot be cloned"),
+        "{}",
+        err
+    );
+
+    // Other types are unaffected
+    assert_eq!(engine.eval::<INT>("let x = 42; let y = x; x + y")?, 84);
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_index"))]
+#[cfg(not(feature = "no_object"))]
+#[test]
+fn test_type_walk() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let value = engine.eval::<Dynamic>(r#"#{ a: [1, "x", [true]], b: #{ c: blob(2) }, d: () }"#)?;
+
+    let mut visited = Vec::new();
+
+    value.walk(|path, v| visited.push((path.to_vec(), v.type_name())));
+
+    assert_eq!(
+        visited,
+        [
+            (vec![], "map"),
+            (vec![PathSegment::Key("a".into())], "array"),
+            (
+                vec![PathSegment::Key("a".into()), PathSegment::Index(0)],
+                std::any::type_name::<INT>()
+            ),
+            (
+                vec![PathSegment::Key("a".into()), PathSegment::Index(1)],
+                "string"
+            ),
+            (
+                vec![PathSegment::Key("a".into()), PathSegment::Index(2)],
+                "array"
+            ),
+            (
+                vec![
+                    PathSegment::Key("a".into()),
+                    PathSegment::Index(2),
+                    PathSegment::Index(0)
+                ],
+                "bool"
+            ),
+            (vec![PathSegment::Key("b".into())], "map"),
+            (
+                vec![PathSegment::Key("b".into()), PathSegment::Key("c".into())],
+                "blob"
+            ),
+            (vec![PathSegment::Key("d".into())], "()"),
+        ]
+    );
+
+    Ok(())
+}
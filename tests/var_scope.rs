@@ -153,6 +153,101 @@ fn test_scope_eval() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[test]
+fn test_scope_builder() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let mut scope = Scope::builder()
+        .with_constant("X", 5 as INT)
+        .with_var("y", "hello".to_string())
+        .with_dynamic("z", Dynamic::from(true))
+        .build();
+
+    assert_eq!(scope.len(), 3);
+    assert!(scope.is_constant("X").unwrap());
+    assert!(!scope.is_constant("y").unwrap());
+
+    assert_eq!(
+        engine
+            .eval_with_scope::<String>(&mut scope, r#"y += "!"; if z { `${y}${X}` } else { y }"#)?,
+        "hello!5"
+    );
+    assert_eq!(scope.get_value::<String>("y").unwrap(), "hello!");
+    assert!(engine.run_with_scope(&mut scope, "X = 42").is_err());
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no_closure"))]
+#[test]
+fn test_scope_alias() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let mut scope = Scope::new();
+    scope.push("x", 40 as INT);
+    scope.push_constant("Y", 1 as INT);
+    assert!(scope.push_alias("old_x", "x"));
+    assert!(scope.push_alias("OLD_Y", "Y"));
+    assert!(!scope.push_alias("z", "nothing"));
+
+    assert_eq!(scope.len(), 4);
+    assert!(!scope.contains("z"));
+    assert!(scope.is_constant("OLD_Y").unwrap());
+
+    // Writing through the alias updates the target, and vice versa
+    engine.run_with_scope(&mut scope, "old_x += 1; x += OLD_Y;")?;
+    assert_eq!(scope.get_value::<INT>("x").unwrap(), 42);
+    assert_eq!(scope.get_value::<INT>("old_x").unwrap(), 42);
+
+    scope.set_value("old_x", 0 as INT);
+    assert_eq!(scope.get_value::<INT>("x").unwrap(), 0);
+
+    assert!(engine.run_with_scope(&mut scope, "OLD_Y = 42").is_err());
+
+    // Shadowing the alias breaks the link
+    assert_eq!(
+        engine.eval_with_scope::<INT>(&mut scope, "let old_x = 123; old_x += 1; x")?,
+        0
+    );
+    assert_eq!(scope.get_value::<INT>("old_x").unwrap(), 124);
+
+    Ok(())
+}
+
+#[test]
+fn test_scope_returning() -> Result<(), Box<EvalAltResult>> {
+    let engine = Engine::new();
+
+    let mut scope = Scope::new();
+    scope.push("x", 40 as INT);
+
+    let setup = engine.compile("let y = x + 1;")?;
+    let work = engine.compile("x += y - 39;")?;
+
+    let scope = engine.run_ast_with_scope_returning_scope(scope, &setup)?;
+    assert_eq!(
+        scope
+            .get_value::<INT>("y")
+            .expect("variable y should exist"),
+        41
+    );
+
+    let scope = engine.run_ast_with_scope_returning_scope(scope, &work)?;
+    assert_eq!(
+        scope
+            .get_value::<INT>("x")
+            .expect("variable x should exist"),
+        42
+    );
+    assert_eq!(scope.len(), 2);
+
+    assert!(engine
+        .run_ast_with_scope_returning_scope(scope, &engine.compile("z")?)
+        .is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_var_resolver() -> Result<(), Box<EvalAltResult>> {
     let mut engine = Engine::new();
@@ -215,6 +310,51 @@ fn test_var_resolver() -> Result<(), Box<EvalAltResult>> {
     Ok(())
 }
 
+#[cfg(not(feature = "no_position"))]
+#[test]
+fn test_var_resolver_position() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.on_var(|name, _, context| match name {
+        "LINE" => Ok(Some((context.position().line().unwrap() as INT).into())),
+        _ => Ok(None),
+    });
+
+    assert_eq!(engine.eval::<INT>("let x = 1;\n\nx + LINE")?, 4);
+
+    Ok(())
+}
+
+#[test]
+fn test_var_resolver_ast_metadata() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+
+    engine.on_var(|name, _, context| match name {
+        "VERSION" => Ok(context.ast_metadata("version").cloned()),
+        _ => Ok(None),
+    });
+
+    let mut ast = engine.compile("VERSION * 2")?;
+    ast.set_metadata("version", 21 as INT);
+    assert_eq!(ast.get_metadata("version").unwrap().as_int().unwrap(), 21);
+    assert_eq!(engine.eval_ast::<INT>(&ast)?, 42);
+
+    // Metadata travels with the AST when combined
+    let mut other = engine.compile("VERSION + 1")?;
+    other.set_metadata("author", "John");
+    ast.combine(other);
+    assert_eq!(
+        ast.iter_metadata().map(|(k, ..)| k).collect::<Vec<_>>(),
+        ["author", "version"]
+    );
+    assert_eq!(engine.eval_ast::<INT>(&ast)?, 22);
+
+    ast.remove_metadata("version");
+    assert!(engine.eval_ast::<INT>(&ast).is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_var_def_filter() -> Result<(), Box<EvalAltResult>> {
     let mut engine = Engine::new();
@@ -247,3 +387,37 @@ fn test_var_def_filter() -> Result<(), Box<EvalAltResult>> {
 
     Ok(())
 }
+
+#[cfg(not(feature = "unchecked"))]
+#[test]
+fn test_var_max_variables() -> Result<(), Box<EvalAltResult>> {
+    let mut engine = Engine::new();
+    engine.set_max_variables(3);
+
+    engine.run("let x = 1; const y = 2; let z = 3;")?;
+    engine.run("let x = 1; { let y = 2; let z = 3; } { let y = 4; let z = 5; }")?;
+
+    assert!(matches!(
+        *engine
+            .run("let x = 1; const y = 2; let z = 3; let w = 4;")
+            .expect_err("should error"),
+        EvalAltResult::ErrorTooManyVariables(..)
+    ));
+
+    // Variables in an external scope count toward the limit
+    let mut scope = Scope::new();
+    scope.push("a", 1 as INT).push_constant("b", 2 as INT);
+
+    engine.run_with_scope(&mut scope, "let x = 1;")?;
+    assert!(matches!(
+        *engine
+            .run_with_scope(&mut scope, "let y = 2;")
+            .expect_err("should error"),
+        EvalAltResult::ErrorTooManyVariables(..)
+    ));
+
+    engine.set_max_variables(0);
+    engine.run("let x = 1; const y = 2; let z = 3; let w = 4;")?;
+
+    Ok(())
+}
@@ -46,6 +46,26 @@ fn test_do() -> Result<(), Box<EvalAltResult>> {
         )?,
         6
     );
+    assert_eq!(
+        engine.eval::<INT>(
+            "
+                let x = 0;
+                do { x += 1; } until x >= 5;
+                x
+            ",
+        )?,
+        5
+    );
+
+    // The body always runs at least once
+    assert_eq!(
+        engine.eval::<INT>("let x = 0; do { x += 1; } while false; x")?,
+        1
+    );
+    assert_eq!(
+        engine.eval::<INT>("let x = 0; do { x += 1; } until true; x")?,
+        1
+    );
 
     Ok(())
 }